@@ -312,6 +312,11 @@ async function main() {
         Buffer.alloc(0),
         Buffer.alloc(0),
         gasFeeBn,
+        new anchor.BN(0), // nonce
+        { unordered: {} }, // nonce_mode
+        0, // flags
+        [], // token_legs
+        0, // cea_index
 
         Array.from(signature),
         recoveryId,
@@ -463,6 +468,11 @@ async function main() {
             Buffer.alloc(0),
             Buffer.alloc(0),
             gasFeeBn,
+            new anchor.BN(0), // nonce
+            { unordered: {} }, // nonce_mode
+            0, // flags
+            [], // token_legs
+            0, // cea_index
 
             Array.from(splSig.signature),
             splSig.recoveryId,
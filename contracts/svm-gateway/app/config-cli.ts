@@ -69,6 +69,11 @@ function deriveRateLimitConfigPda(): PublicKey {
     return pda;
 }
 
+function deriveOraclePda(mint: PublicKey): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync([Buffer.from("oracle"), mint.toBuffer()], PROGRAM_ID);
+    return pda;
+}
+
 function deriveTokenRateLimitPda(mint: PublicKey): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
         [Buffer.from(RATE_LIMIT_SEED), mint.toBuffer()],
@@ -388,22 +393,31 @@ program_cli
 
 program_cli
     .command("pyth:set-feed")
-    .description("Set Pyth price feed address")
+    .description("Set the SOL/USD Pyth feed (native SOL oracle registry entry)")
     .requiredOption("--feed <pubkey>", "Pyth price feed public key")
+    .option(
+        "--feed-id <hex>",
+        "Pyth feed id",
+        "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d"
+    )
     .action(async (options) => {
         try {
             console.log("=== SETTING PYTH PRICE FEED ===\n");
 
             const feed = new PublicKey(options.feed);
+            const feedId = Array.from(Buffer.from(options.feedId.replace(/^0x/, ""), "hex"));
             const configPda = deriveConfigPda();
 
             console.log(`Pyth Feed: ${feed.toBase58()}\n`);
 
             const tx = await program.methods
-                .setPythPriceFeed(feed)
+                .setOracle(feed, feedId, new anchor.BN(0), 0, 0)
                 .accountsPartial({
                     config: configPda,
+                    oracle: deriveOraclePda(PublicKey.default),
+                    tokenMint: PublicKey.default,
                     admin: adminKeypair.publicKey,
+                    systemProgram: SystemProgram.programId,
                 })
                 .signers([adminKeypair])
                 .rpc();
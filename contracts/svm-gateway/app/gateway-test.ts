@@ -48,6 +48,11 @@ const EXECUTED_SUB_TX_SEED = "executed_sub_tx";
 const PRICE_ACCOUNT = new PublicKey(
   "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE"
 ); // Pyth SOL/USD price feed
+// Native SOL oracle registry entry; names PRICE_ACCOUNT as the SOL/USD feed
+const [SOL_ORACLE] = PublicKey.findProgramAddressSync(
+  [Buffer.from("oracle"), PublicKey.default.toBuffer()],
+  PROGRAM_ID
+);
 
 // Resolve ALT address from universal-alt.json based on the active program ID.
 function resolveAltAddress(): PublicKey {
@@ -227,6 +232,7 @@ async function getDynamicGasAmount(
     const solPriceResult = await program.methods
       .getSolPrice()
       .accountsPartial({
+        solOracle: SOL_ORACLE,
         priceUpdate: PRICE_ACCOUNT,
       })
       .view();
@@ -459,6 +465,28 @@ async function run() {
     console.log("Gateway already initialized\n");
   }
 
+  // Step 1.25: Register the SOL/USD feed as the native SOL oracle entry (gas route pricing)
+  if (!(await connection.getAccountInfo(SOL_ORACLE))) {
+    const tx = await program.methods
+      .setOracle(
+        PRICE_ACCOUNT,
+        Array.from(Buffer.from("ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d", "hex")),
+        new anchor.BN(0),
+        0,
+        0
+      )
+      .accountsPartial({
+        config: configPda,
+        oracle: SOL_ORACLE,
+        tokenMint: PublicKey.default,
+        admin: admin,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([adminKeypair])
+      .rpc();
+    console.log(`Native SOL oracle registered: ${tx}\n`);
+  }
+
   // Step 1.5: Initialize Rate Limit Config and Token Rate Limits
   console.log("1.5. Setting up Rate Limits...");
   const veryLargeThreshold = new anchor.BN("1000000000000000000000"); // Effectively unlimited
@@ -667,6 +695,7 @@ async function run() {
       userTokenAccount: null,
       gatewayTokenAccount: null,
       user: user,
+      solOracle: SOL_ORACLE,
       priceUpdate: PRICE_ACCOUNT,
       rateLimitConfig: rateLimitConfigPda,
       tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -713,6 +742,7 @@ async function run() {
       userTokenAccount: null,
       gatewayTokenAccount: null,
       user: user,
+      solOracle: SOL_ORACLE,
       priceUpdate: PRICE_ACCOUNT,
       rateLimitConfig: rateLimitConfigPda,
       tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -767,6 +797,7 @@ async function run() {
       userTokenAccount: null,
       gatewayTokenAccount: null,
       user: user,
+      solOracle: SOL_ORACLE,
       priceUpdate: PRICE_ACCOUNT,
       rateLimitConfig: rateLimitConfigPda,
       tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -832,6 +863,7 @@ async function run() {
         userTokenAccount: tokenAccount,
         gatewayTokenAccount: vaultAta.address,
         user: user,
+        solOracle: SOL_ORACLE,
         priceUpdate: PRICE_ACCOUNT,
         rateLimitConfig: rateLimitConfigPda,
         tokenRateLimit: splTokenRateLimitPda,
@@ -904,6 +936,7 @@ async function run() {
       userTokenAccount: null,
       gatewayTokenAccount: null,
       user: user,
+      solOracle: SOL_ORACLE,
       priceUpdate: PRICE_ACCOUNT,
       rateLimitConfig: rateLimitConfigPda,
       tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -993,6 +1026,7 @@ async function run() {
         userTokenAccount: tokenAccount,
         gatewayTokenAccount: vaultAta.address,
         user: user,
+        solOracle: SOL_ORACLE,
         priceUpdate: PRICE_ACCOUNT,
         rateLimitConfig: rateLimitConfigPda,
         tokenRateLimit: splTokenRateLimitPda,
@@ -1098,6 +1132,7 @@ async function run() {
         userTokenAccount: null,
         gatewayTokenAccount: null,
         user: user,
+        solOracle: SOL_ORACLE,
         priceUpdate: PRICE_ACCOUNT,
         rateLimitConfig: rateLimitConfigPda,
         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -1151,6 +1186,7 @@ async function run() {
         userTokenAccount: null,
         gatewayTokenAccount: null,
         user: user,
+        solOracle: SOL_ORACLE,
         priceUpdate: PRICE_ACCOUNT,
         rateLimitConfig: rateLimitConfigPda,
         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -1207,6 +1243,7 @@ async function run() {
           userTokenAccount: tokenAccount,
           gatewayTokenAccount: vaultAta.address,
           user: user,
+          solOracle: SOL_ORACLE,
           priceUpdate: PRICE_ACCOUNT,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: getTokenRateLimitPda(mint),
@@ -1256,6 +1293,7 @@ async function run() {
         userTokenAccount: null,
         gatewayTokenAccount: null,
         user: user,
+        solOracle: SOL_ORACLE,
         priceUpdate: PRICE_ACCOUNT,
         rateLimitConfig: rateLimitConfigPda,
         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -1311,6 +1349,7 @@ async function run() {
         userTokenAccount: null,
         gatewayTokenAccount: null,
         user: user,
+        solOracle: SOL_ORACLE,
         priceUpdate: PRICE_ACCOUNT,
         rateLimitConfig: rateLimitConfigPda,
         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -1364,6 +1403,7 @@ async function run() {
         userTokenAccount: null,
         gatewayTokenAccount: null,
         user: user,
+        solOracle: SOL_ORACLE,
         priceUpdate: PRICE_ACCOUNT,
         rateLimitConfig: rateLimitConfigPda,
         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -1418,6 +1458,7 @@ async function run() {
           userTokenAccount: tokenAccount,
           gatewayTokenAccount: tokenAccount, // ⚠️ ATTACK: User's own account instead of vault ATA
           user: user,
+          solOracle: SOL_ORACLE,
           priceUpdate: PRICE_ACCOUNT,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: getTokenRateLimitPda(mint),
@@ -1472,6 +1513,7 @@ async function run() {
           userTokenAccount: tokenAccount,
           gatewayTokenAccount: tokenAccount, // ⚠️ ATTACK: User's own account instead of vault ATA
           user: user,
+          solOracle: SOL_ORACLE,
           priceUpdate: PRICE_ACCOUNT,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: getTokenRateLimitPda(mint),
@@ -1534,6 +1576,7 @@ async function run() {
           userTokenAccount: tokenAccount,
           gatewayTokenAccount: wrongMintVaultAta, // ⚠️ ATTACK: Vault ATA but for wrong mint
           user: user,
+          solOracle: SOL_ORACLE,
           priceUpdate: PRICE_ACCOUNT,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: getTokenRateLimitPda(mint),
@@ -1607,6 +1650,7 @@ async function run() {
         userTokenAccount: null,
         gatewayTokenAccount: null,
         user: user,
+        solOracle: SOL_ORACLE,
         priceUpdate: PRICE_ACCOUNT,
         rateLimitConfig: rateLimitConfigPda,
         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -1766,6 +1810,11 @@ async function run() {
       Buffer.alloc(0), // writable_flags (empty for withdraw)
       Buffer.from([]), // ix_data (empty for withdraw)
       new anchor.BN(withdrawGasFee), // gas_fee
+      new anchor.BN(0), // nonce
+      { unordered: {} }, // nonce_mode
+      0, // flags
+      [], // token_legs
+      0, // cea_index
       Array.from(signature) as any,
       recoveryId,
       Array.from(messageHash) as any
@@ -1931,6 +1980,11 @@ async function run() {
           Buffer.alloc(0), // writable_flags (empty for withdraw)
           Buffer.from([]), // ix_data (empty for withdraw)
           new anchor.BN(splWithdrawGasFee), // gas_fee
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
           Array.from(signatureSPL) as any,
           recoveryIdSPL,
           Array.from(messageHashSPL) as any
@@ -2141,6 +2195,11 @@ async function run() {
         writableFlags,
         Buffer.from(decoded.ixData),
         new anchor.BN(Number(gasFee)),
+        new anchor.BN(0), // nonce
+        { unordered: {} }, // nonce_mode
+        0, // flags
+        [], // token_legs
+        0, // cea_index
 
         sig.signature,
         sig.recoveryId,
@@ -2324,6 +2383,11 @@ async function run() {
         writableFlagsSpl,
         Buffer.from(decoded.ixData),
         new anchor.BN(Number(gasFee)),
+        new anchor.BN(0), // nonce
+        { unordered: {} }, // nonce_mode
+        0, // flags
+        [], // token_legs
+        0, // cea_index
 
         sig.signature,
         sig.recoveryId,
@@ -2442,6 +2506,7 @@ async function run() {
       amountBytes,
       payloadLenBytes,
       revertRecipientBytes,
+      Buffer.from([0]), // gas_amount = None
     ]);
 
     const txId = anchor.web3.Keypair.generate().publicKey.toBytes();
@@ -2478,6 +2543,11 @@ async function run() {
         Buffer.from([]), // writable_flags: no remaining accounts
         ixData,
         new anchor.BN(Number(gasFee)),
+        new anchor.BN(0), // nonce
+        { unordered: {} }, // nonce_mode
+        0, // flags
+        [], // token_legs
+        0, // cea_index
 
         Array.from(sig.signature),
         sig.recoveryId,
@@ -2630,6 +2700,11 @@ async function run() {
           writableFlags1,
           Buffer.from(securityCounterIx.data),
           new anchor.BN(Number(gasFee1)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           securitySig1.signature,
           securitySig1.recoveryId,
@@ -2719,6 +2794,11 @@ async function run() {
           writableFlags2,
           Buffer.from(securityCounterIx2.data),
           new anchor.BN(Number(gasFee2)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           corruptedSig,
           securitySig2.recoveryId,
@@ -2819,6 +2899,11 @@ async function run() {
           fewerWritableFlags, // Use flags for fewer accounts
           Buffer.from(securityCounterIx4.data),
           new anchor.BN(Number(gasFee4)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           securitySig4.signature,
           securitySig4.recoveryId,
@@ -2968,6 +3053,11 @@ async function run() {
           writableFlags,
           Buffer.from(batchIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           sig.signature,
           sig.recoveryId,
@@ -3001,6 +3091,11 @@ async function run() {
           writableFlags,
           Buffer.from(batchIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           sig.signature,
           sig.recoveryId,
@@ -3269,6 +3364,11 @@ async function run() {
         heavyWritableFlags,
         Buffer.from(batchIx.data),
         new anchor.BN(Number(gasFeeHeavy)),
+        new anchor.BN(0), // nonce
+        { unordered: {} }, // nonce_mode
+        0, // flags
+        [], // token_legs
+        0, // cea_index
 
         heavySig.signature,
         heavySig.recoveryId,
@@ -3401,6 +3501,11 @@ async function run() {
         heavyWritableFlagsSpl,
         Buffer.from(batchIxSpl.data),
         new anchor.BN(Number(gasFeeHeavySpl)),
+        new anchor.BN(0), // nonce
+        { unordered: {} }, // nonce_mode
+        0, // flags
+        [], // token_legs
+        0, // cea_index
 
         heavySigSpl.signature,
         heavySigSpl.recoveryId,
@@ -77,6 +77,10 @@ async function testDeposit(mintAddress: string, amount: number, tokenSymbol?: st
         [Buffer.from(RATE_LIMIT_CONFIG_SEED)],
         PROGRAM_ID
     );
+    const [solOraclePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("oracle"), PublicKey.default.toBuffer()],
+        PROGRAM_ID
+    );
 
     // Helper to get token rate limit PDA
     const getTokenRateLimitPda = (tokenMint: PublicKey): PublicKey => {
@@ -91,13 +95,13 @@ async function testDeposit(mintAddress: string, amount: number, tokenSymbol?: st
     const user = userKeypair.publicKey;
     const mint = new PublicKey(mintAddress);
 
-    // Get price feed from config (or use dummy if not set)
+    // Get the SOL/USD feed from the native SOL oracle entry (or use dummy if not set)
     let priceFeed: PublicKey;
     try {
-        const config = await (program.account as any).config.fetch(configPda);
-        priceFeed = config.pythPriceFeed;
+        const solOracle = await (program.account as any).oracleRegistry.fetch(solOraclePda);
+        priceFeed = solOracle.priceFeed;
     } catch {
-        // If config doesn't exist or price feed not set, use a dummy
+        // If the native SOL oracle is not registered, use a dummy
         priceFeed = Keypair.generate().publicKey;
     }
 
@@ -198,6 +202,7 @@ async function testDeposit(mintAddress: string, amount: number, tokenSymbol?: st
             user: user,
             userTokenAccount: userTokenAccount.address,
             gatewayTokenAccount: vaultAta,
+            solOracle: solOraclePda,
             priceUpdate: priceFeed,
             rateLimitConfig: rateLimitConfigPda,
             tokenRateLimit: splTokenRateLimitPda,
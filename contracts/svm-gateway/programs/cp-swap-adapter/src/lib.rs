@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("64vVgytqf1DgFeDpVLttxdeMqpGGbsmDd1Qk3pjj8gPd");
+
+/// Swap fee in basis points, kept in the pool as extra token reserve.
+pub const FEE_BPS: u128 = 30;
+
+/// Constant-product (x * y = k) SPL → SOL pool implementing the gateway swap adapter interface.
+/// Reference implementation for local tests only; stands in for a real AMM.
+#[program]
+pub mod cp_swap_adapter {
+    use super::*;
+
+    /// Create a pool for `mint` and seed it with token and SOL reserves from `provider`.
+    pub fn initialize_pool(ctx: Context<InitializePool>, token_amount: u64, sol_amount: u64) -> Result<()> {
+        require!(token_amount > 0 && sol_amount > 0, SwapError::InvalidAmount);
+
+        let pool = &mut ctx.accounts.pool;
+        pool.mint = ctx.accounts.mint.key();
+        pool.token_vault = ctx.accounts.token_vault.key();
+        pool.bump = ctx.bumps.pool;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.provider_token_account.to_account_info(),
+                    to: ctx.accounts.token_vault.to_account_info(),
+                    authority: ctx.accounts.provider.to_account_info(),
+                },
+            ),
+            token_amount,
+        )?;
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.provider.to_account_info(),
+                    to: ctx.accounts.pool.to_account_info(),
+                },
+            ),
+            sol_amount,
+        )?;
+        Ok(())
+    }
+
+    /// Gateway swap adapter interface: swap `amount_in` tokens for at least `min_amount_out`
+    /// lamports, paid to `user`. Account order starts with `[user, user_token_account]`.
+    pub fn swap_to_native(ctx: Context<SwapToNative>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        require!(amount_in > 0, SwapError::InvalidAmount);
+
+        let pool_info = ctx.accounts.pool.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(pool_info.data_len());
+        let sol_reserve = pool_info.lamports().saturating_sub(rent_floor) as u128;
+        let token_reserve = ctx.accounts.token_vault.amount as u128;
+
+        let amount_in_after_fee = (amount_in as u128) * (10_000 - FEE_BPS) / 10_000;
+        let amount_out = sol_reserve
+            .checked_mul(amount_in_after_fee)
+            .and_then(|x| x.checked_div(token_reserve.checked_add(amount_in_after_fee)?))
+            .ok_or(SwapError::InvalidAmount)? as u64;
+        require!(amount_out > 0, SwapError::InvalidAmount);
+        require!(amount_out >= min_amount_out, SwapError::SlippageExceeded);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    to: ctx.accounts.token_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount_in,
+        )?;
+
+        // The pool is owned by this program, so its lamports can be debited directly.
+        **pool_info.try_borrow_mut_lamports()? -= amount_out;
+        **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += amount_out;
+
+        emit!(Swapped {
+            user: ctx.accounts.user.key(),
+            mint: ctx.accounts.pool.mint,
+            amount_in,
+            amount_out,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(
+        init,
+        payer = provider,
+        space = 8 + Pool::LEN,
+        seeds = [b"pool", mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        init,
+        payer = provider,
+        seeds = [b"pool_vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = mint, token::authority = provider)]
+    pub provider_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SwapToNative<'info> {
+    /// Token owner; receives the lamports out
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut, token::mint = pool.mint, token::authority = user)]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"pool", pool.mint.as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, address = pool.token_vault)]
+    pub token_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[account]
+pub struct Pool {
+    pub mint: Pubkey,
+    pub token_vault: Pubkey,
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const LEN: usize = 32 + 32 + 1; // mint + token_vault + bump
+}
+
+#[event]
+pub struct Swapped {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+#[error_code]
+pub enum SwapError {
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Swap output below minimum")]
+    SlippageExceeded,
+}
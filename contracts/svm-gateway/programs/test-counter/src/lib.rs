@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Token, TokenAccount};
+use universal_gateway::gateway_cpi::{self, GatewayDeposit};
+use universal_gateway::UniversalTxRequest;
 
 declare_id!("4mpHkerNsaJPp35fyT5bkoXxuEBczGq6HUKTtrzFcptx");
 
@@ -340,6 +342,62 @@ pub mod test_counter {
 
         Ok(())
     }
+
+    /// Bridge through the gateway via CPI with the `bridge_authority` PDA as the logical sender
+    /// (exercises `universal_gateway::gateway_cpi`). `payer` funds the native legs and fees.
+    pub fn bridge_deposit(
+        ctx: Context<BridgeDeposit>,
+        req: UniversalTxRequest,
+        native_amount: u64,
+    ) -> Result<()> {
+        let bump = ctx.bumps.bridge_authority;
+        let bridge_seeds: &[&[u8]] = &[b"bridge_authority", &[bump]];
+
+        gateway_cpi::send_universal_tx(
+            GatewayDeposit {
+                gateway_program: ctx.accounts.gateway_program.to_account_info(),
+                config: ctx.accounts.config.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                user_token_account: ctx
+                    .accounts
+                    .user_token_account
+                    .as_ref()
+                    .map(|a| a.to_account_info()),
+                gateway_token_account: ctx
+                    .accounts
+                    .gateway_token_account
+                    .as_ref()
+                    .map(|a| a.to_account_info()),
+                payer: ctx.accounts.payer.to_account_info(),
+                sender: ctx.accounts.bridge_authority.to_account_info(),
+                sol_oracle: ctx
+                    .accounts
+                    .sol_oracle
+                    .as_ref()
+                    .map(|a| a.to_account_info()),
+                price_update: ctx
+                    .accounts
+                    .price_update
+                    .as_ref()
+                    .map(|a| a.to_account_info()),
+                rate_limit_config: ctx.accounts.rate_limit_config.to_account_info(),
+                token_rate_limit: ctx.accounts.token_rate_limit.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                oracle: None,
+                oracle_price_update: None,
+                price_sources: None,
+                secondary_price_update_1: None,
+                secondary_price_update_2: None,
+                fee_token_account: None,
+                fee_exemption: None,
+            },
+            req,
+            native_amount,
+            &[bridge_seeds],
+        )
+    }
 }
 
 #[derive(Accounts)]
@@ -568,6 +626,56 @@ pub struct BatchOperation<'info> {
     pub authority: UncheckedAccount<'info>,
 }
 
+#[derive(Accounts)]
+pub struct BridgeDeposit<'info> {
+    /// CHECK: Signs the gateway deposit as the logical sender via invoke_signed
+    #[account(seeds = [b"bridge_authority"], bump)]
+    pub bridge_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Gateway config PDA (validated by the gateway)
+    #[account(mut)]
+    pub config: UncheckedAccount<'info>,
+
+    /// CHECK: Gateway vault PDA (validated by the gateway)
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: Gateway fee vault PDA (validated by the gateway)
+    #[account(mut)]
+    pub fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: bridge_authority's token account (SPL routes only)
+    #[account(mut)]
+    pub user_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Gateway vault ATA (SPL routes only)
+    #[account(mut)]
+    pub gateway_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Gateway native SOL oracle registry entry (gas routes only)
+    pub sol_oracle: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: SOL/USD price account (gas routes only)
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Gateway rate limit config PDA (validated by the gateway)
+    pub rate_limit_config: UncheckedAccount<'info>,
+
+    /// CHECK: Gateway per-token rate limit PDA (validated by the gateway)
+    #[account(mut)]
+    pub token_rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: Universal gateway program
+    #[account(address = universal_gateway::ID)]
+    pub gateway_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
 #[account]
 pub struct Counter {
     pub value: u64,
@@ -90,4 +90,40 @@ pub enum GatewayError {
 
     #[msg("Fee vault has insufficient balance to reimburse relayer")]
     InsufficientFeePool,
+
+    #[msg("Outbound nonce out of order")]
+    InvalidNonce,
+
+    #[msg("Token account is frozen")]
+    TokenAccountFrozen,
+
+    #[msg("Payload exceeds maximum size")]
+    PayloadTooLarge,
+
+    #[msg("Token account has not delegated to the gateway")]
+    InvalidDelegate,
+
+    #[msg("Swap output below minimum")]
+    SlippageExceeded,
+
+    #[msg("Epoch USD cap exceeded")]
+    EpochUsdCapExceeded,
+
+    #[msg("Oracle price is older than the configured max age")]
+    StalePrice,
+
+    #[msg("Oracle price confidence interval exceeds the threshold")]
+    LowPriceConfidence,
+
+    #[msg("Oracle price is not positive")]
+    NonPositivePrice,
+
+    #[msg("Oracle price update is below the required verification level")]
+    InsufficientPriceVerification,
+
+    #[msg("Oracle price deviates too far from the last accepted price")]
+    PriceDeviationExceeded,
+
+    #[msg("Payload not allowed on this route")]
+    InvalidPayload,
 }
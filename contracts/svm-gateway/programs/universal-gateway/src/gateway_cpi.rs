@@ -0,0 +1,134 @@
+//! Typed helper for programs that bridge through the gateway via CPI (`cpi` feature).
+//!
+//! The calling program signs as the logical sender (usually one of its PDAs) and a separate
+//! payer funds the native SOL legs. Example:
+//!
+//! ```ignore
+//! universal_gateway::gateway_cpi::send_universal_tx(
+//!     GatewayDeposit { /* accounts */ },
+//!     req,
+//!     native_amount,
+//!     &[&[b"bridge_authority", &[bump]]],
+//! )?;
+//! ```
+
+use crate::state::{
+    UniversalTxRequest, CONFIG_SEED, FEE_EXEMPTION_SEED, FEE_VAULT_SEED, ORACLE_SEED,
+    PRICE_SOURCES_SEED, RATE_LIMIT_CONFIG_SEED, RATE_LIMIT_SEED, VAULT_SEED,
+};
+use anchor_lang::prelude::*;
+
+/// Accounts for a CPI deposit. Mirrors `SendUniversalTx` with an explicit `sender`.
+pub struct GatewayDeposit<'info> {
+    pub gateway_program: AccountInfo<'info>,
+    /// Writable: the instant route records the last accepted SOL/USD price.
+    pub config: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub fee_vault: AccountInfo<'info>,
+    /// Sender-owned source token account (SPL and wSOL routes only).
+    pub user_token_account: Option<AccountInfo<'info>>,
+    /// Vault ATA for the mint (SPL routes only).
+    pub gateway_token_account: Option<AccountInfo<'info>>,
+    /// Funds native SOL (amount, gas and protocol fee). Must be a system-owned signer.
+    pub payer: AccountInfo<'info>,
+    /// Logical sender recorded in `UniversalTx.sender`; signs via `signer_seeds`.
+    pub sender: AccountInfo<'info>,
+    /// Native SOL `OracleRegistry` entry ([`oracle_pda`] of `Pubkey::default()`) and the
+    /// SOL/USD price account it names; gas routes and USD protocol fees only.
+    pub sol_oracle: Option<AccountInfo<'info>>,
+    pub price_update: Option<AccountInfo<'info>>,
+    pub rate_limit_config: AccountInfo<'info>,
+    pub token_rate_limit: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    /// Token's `OracleRegistry` and its price account; needed when the token sets FUNDS USD bounds.
+    pub oracle: Option<AccountInfo<'info>>,
+    pub oracle_price_update: Option<AccountInfo<'info>>,
+    /// `PriceSources` PDA and its secondary SOL/USD feeds; needed on gas routes when
+    /// `config.price_sources_enabled`.
+    pub price_sources: Option<AccountInfo<'info>>,
+    pub secondary_price_update_1: Option<AccountInfo<'info>>,
+    pub secondary_price_update_2: Option<AccountInfo<'info>>,
+    /// Fee vault's token account for `req.token` (writable); required when the token
+    /// charges a bps FUNDS fee.
+    pub fee_token_account: Option<AccountInfo<'info>>,
+    /// The logical sender's `FeeExemption` PDA ([`fee_exemption_pda`]), if any.
+    pub fee_exemption: Option<AccountInfo<'info>>,
+}
+
+/// Invoke `send_universal_tx_cpi` with `sender` signing via `signer_seeds`.
+pub fn send_universal_tx<'info>(
+    accounts: GatewayDeposit<'info>,
+    req: UniversalTxRequest,
+    native_amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = crate::cpi::accounts::SendUniversalTx {
+        config: accounts.config,
+        vault: accounts.vault,
+        fee_vault: accounts.fee_vault,
+        user_token_account: accounts.user_token_account,
+        gateway_token_account: accounts.gateway_token_account,
+        user: accounts.payer,
+        sol_oracle: accounts.sol_oracle,
+        price_update: accounts.price_update,
+        rate_limit_config: accounts.rate_limit_config,
+        token_rate_limit: accounts.token_rate_limit,
+        token_program: accounts.token_program,
+        system_program: accounts.system_program,
+        sender: Some(accounts.sender),
+        depositor: None,
+        deposit_delegate: None,
+        gas_token: None,
+        gas_token_price_update: None,
+        oracle: accounts.oracle,
+        oracle_price_update: accounts.oracle_price_update,
+        price_sources: accounts.price_sources,
+        secondary_price_update_1: accounts.secondary_price_update_1,
+        secondary_price_update_2: accounts.secondary_price_update_2,
+        fee_token_account: accounts.fee_token_account,
+        fee_exemption: accounts.fee_exemption,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(accounts.gateway_program, cpi_accounts, signer_seeds);
+    crate::cpi::send_universal_tx_cpi(cpi_ctx, req, native_amount)
+}
+
+/// Gateway config PDA.
+pub fn config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED], &crate::ID).0
+}
+
+/// Bridge vault PDA (native SOL custody and SPL vault ATA owner).
+pub fn vault_pda() -> Pubkey {
+    Pubkey::find_program_address(&[VAULT_SEED], &crate::ID).0
+}
+
+/// Protocol fee vault PDA.
+pub fn fee_vault_pda() -> Pubkey {
+    Pubkey::find_program_address(&[FEE_VAULT_SEED], &crate::ID).0
+}
+
+/// Global rate limit config PDA.
+pub fn rate_limit_config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[RATE_LIMIT_CONFIG_SEED], &crate::ID).0
+}
+
+/// Oracle registry PDA for a mint. Use `Pubkey::default()` for native SOL.
+pub fn oracle_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ORACLE_SEED, mint.as_ref()], &crate::ID).0
+}
+
+/// SOL/USD price sources PDA.
+pub fn price_sources_pda() -> Pubkey {
+    Pubkey::find_program_address(&[PRICE_SOURCES_SEED], &crate::ID).0
+}
+
+/// The gateway's `FeeExemption` PDA for a logical sender.
+pub fn fee_exemption_pda(sender: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[FEE_EXEMPTION_SEED, sender.as_ref()], &crate::ID).0
+}
+
+/// Per-token rate limit PDA. Use `Pubkey::default()` for native SOL.
+pub fn token_rate_limit_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[RATE_LIMIT_SEED, mint.as_ref()], &crate::ID).0
+}
@@ -1,5 +1,8 @@
+use crate::utils::{parse_token_account, pda_spl_transfer};
 use crate::{errors::*, state::*};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::{spl_token, Mint, Token};
 
 #[derive(Accounts)]
 pub struct AdminAction<'info> {
@@ -126,19 +129,236 @@ pub fn set_protocol_fee(ctx: Context<FeeVaultAdminAction>, fee_lamports: u64) ->
     Ok(())
 }
 
-// Pyth oracle configuration functions
-pub fn set_pyth_price_feed(ctx: Context<AdminAction>, price_feed: Pubkey) -> Result<()> {
-    require!(price_feed != Pubkey::default(), GatewayError::ZeroAddress);
-    ctx.accounts.config.pyth_price_feed = price_feed;
+/// USD-priced protocol fee (8 decimals; 0 falls back to the flat lamport fee), clamped to
+/// `[min_fee_lamports, max_fee_lamports]` after conversion (`max_fee_lamports == 0` = no cap).
+pub fn set_protocol_fee_usd(
+    ctx: Context<FeeVaultAdminAction>,
+    fee_usd: u64,
+    min_fee_lamports: u64,
+    max_fee_lamports: u64,
+) -> Result<()> {
+    require!(
+        max_fee_lamports == 0 || min_fee_lamports <= max_fee_lamports,
+        GatewayError::InvalidInput
+    );
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    fee_vault.bump = ctx.bumps.fee_vault;
+    fee_vault.protocol_fee_usd = fee_usd;
+    fee_vault.min_fee_lamports = min_fee_lamports;
+    fee_vault.max_fee_lamports = max_fee_lamports;
+    emit!(ProtocolFeeUsdUpdated {
+        fee_usd,
+        min_fee_lamports,
+        max_fee_lamports,
+    });
+    Ok(())
+}
+
+/// Per-route lamport fees (gas / funds / payload add-on). While `enabled`, they replace the
+/// flat and USD-priced fee; per-token overrides still take precedence on FUNDS deposits.
+pub fn set_route_fees(
+    ctx: Context<FeeVaultAdminAction>,
+    enabled: bool,
+    gas_fee_lamports: u64,
+    funds_fee_lamports: u64,
+    payload_fee_lamports: u64,
+) -> Result<()> {
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    fee_vault.bump = ctx.bumps.fee_vault;
+    fee_vault.route_fees_enabled = enabled;
+    fee_vault.gas_fee_lamports = gas_fee_lamports;
+    fee_vault.funds_fee_lamports = funds_fee_lamports;
+    fee_vault.payload_fee_lamports = payload_fee_lamports;
+    emit!(RouteFeesUpdated {
+        enabled,
+        gas_fee_lamports,
+        funds_fee_lamports,
+        payload_fee_lamports,
+    });
+    Ok(())
+}
+
+/// Batch fee mode: `fee_per_batch = true` charges the protocol fee once per
+/// `send_universal_tx_batch`; `false` charges it once per request in the batch.
+pub fn set_batch_fee_mode(ctx: Context<FeeVaultAdminAction>, fee_per_batch: bool) -> Result<()> {
+    ctx.accounts.fee_vault.bump = ctx.bumps.fee_vault;
+    ctx.accounts.fee_vault.fee_per_batch = fee_per_batch;
+    emit!(BatchFeeModeUpdated { fee_per_batch });
     Ok(())
 }
 
+/// Fee vault withdrawal (intentionally no `!config.paused` guard, like the fee setters).
+/// Pass `fee_token_account` and `recipient_token_account` to withdraw SPL bps fees;
+/// omit both to withdraw lamports to `recipient`.
+#[derive(Accounts)]
+pub struct WithdrawProtocolFees<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ GatewayError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, seeds = [FEE_VAULT_SEED], bump = fee_vault.bump)]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: Treasury receiving lamports, or owning `recipient_token_account`.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Fee-vault-owned token account holding bps fees; validated in the handler.
+    #[account(mut)]
+    pub fee_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient-owned token account for the same mint; validated in the handler.
+    #[account(mut)]
+    pub recipient_token_account: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Withdraw `amount` of collected fees to the admin-chosen `recipient`.
+/// Lamports keep the fee vault rent-exempt; the rest is shared with relayer reimbursements.
+pub fn withdraw_protocol_fees(ctx: Context<WithdrawProtocolFees>, amount: u64) -> Result<()> {
+    require!(amount > 0, GatewayError::InvalidAmount);
+    let recipient = ctx.accounts.recipient.key();
+    require!(recipient != Pubkey::default(), GatewayError::ZeroAddress);
+    let fee_vault_info = ctx.accounts.fee_vault.to_account_info();
+
+    let token = match (
+        ctx.accounts.fee_token_account.as_ref(),
+        ctx.accounts.recipient_token_account.as_ref(),
+    ) {
+        (Some(fee_token_account), Some(recipient_token_account)) => {
+            require!(ctx.accounts.token_program.is_some(), GatewayError::InvalidAccount);
+            let source = parse_token_account(&fee_token_account.to_account_info())?;
+            let destination = parse_token_account(&recipient_token_account.to_account_info())?;
+            require!(source.owner == fee_vault_info.key(), GatewayError::InvalidOwner);
+            require!(destination.owner == recipient, GatewayError::InvalidOwner);
+            require!(destination.mint == source.mint, GatewayError::InvalidMint);
+            require!(source.amount >= amount, GatewayError::InsufficientBalance);
+
+            let fee_vault_seeds: &[&[u8]] = &[FEE_VAULT_SEED, &[ctx.accounts.fee_vault.bump]];
+            pda_spl_transfer(
+                &fee_token_account.to_account_info(),
+                &recipient_token_account.to_account_info(),
+                &fee_vault_info,
+                amount,
+                fee_vault_seeds,
+            )?;
+            source.mint
+        }
+        (None, None) => {
+            let min_balance = Rent::get()?.minimum_balance(FeeVault::LEN);
+            let available = fee_vault_info
+                .lamports()
+                .checked_sub(min_balance)
+                .ok_or(error!(GatewayError::InsufficientFeePool))?;
+            require!(available >= amount, GatewayError::InsufficientFeePool);
+
+            **fee_vault_info.try_borrow_mut_lamports()? -= amount;
+            **ctx.accounts.recipient.try_borrow_mut_lamports()? += amount;
+            Pubkey::default()
+        }
+        _ => return err!(GatewayError::InvalidAccount),
+    };
+
+    emit!(ProtocolFeesWithdrawn {
+        token,
+        recipient,
+        amount,
+    });
+    Ok(())
+}
+
+// Pyth oracle configuration functions
 pub fn set_pyth_confidence_threshold(ctx: Context<AdminAction>, threshold: u64) -> Result<()> {
     require!(threshold > 0, GatewayError::InvalidAmount);
     ctx.accounts.config.pyth_confidence_threshold = threshold;
     Ok(())
 }
 
+/// Set the SOL/USD (and gas-token feed) freshness and verification policy.
+/// `max_age_seconds == 0` restores the 1 hour default; `min_verification_signatures == 0`
+/// requires fully verified updates.
+pub fn set_pyth_price_policy(
+    ctx: Context<AdminAction>,
+    max_age_seconds: u64,
+    min_verification_signatures: u8,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.pyth_max_age_seconds = max_age_seconds;
+    config.pyth_min_verification_signatures = min_verification_signatures;
+
+    emit!(PythPricePolicyUpdated {
+        max_age_seconds,
+        min_verification_signatures,
+    });
+
+    Ok(())
+}
+
+/// Set the relative confidence bound (bps of price, 0 disables; at most 10_000) and whether
+/// USD cap enforcement uses the EMA price instead of the spot price.
+pub fn set_pyth_price_mode(
+    ctx: Context<AdminAction>,
+    confidence_bps: u16,
+    use_ema: bool,
+) -> Result<()> {
+    require!(confidence_bps <= BPS_DENOMINATOR, GatewayError::InvalidInput);
+    let config = &mut ctx.accounts.config;
+    config.pyth_confidence_bps = confidence_bps;
+    config.pyth_use_ema = use_ema;
+
+    emit!(PythPriceModeUpdated {
+        confidence_bps,
+        use_ema,
+    });
+
+    Ok(())
+}
+
+/// Configure the SOL/USD deviation circuit breaker (0 bps disables) and clear the stored reference
+/// price, so the next accepted price becomes the new reference.
+pub fn set_price_deviation_guard(
+    ctx: Context<AdminAction>,
+    max_deviation_bps: u16,
+    window_seconds: u64,
+) -> Result<()> {
+    require!(max_deviation_bps <= BPS_DENOMINATOR, GatewayError::InvalidInput);
+    require!(
+        max_deviation_bps == 0 || window_seconds > 0,
+        GatewayError::InvalidInput
+    );
+    let config = &mut ctx.accounts.config;
+    config.max_price_deviation_bps = max_deviation_bps;
+    config.price_deviation_window_seconds = window_seconds;
+    config.last_sol_price_usd = 0;
+    config.last_sol_publish_time = 0;
+
+    emit!(PriceDeviationGuardUpdated {
+        max_deviation_bps,
+        window_seconds,
+    });
+
+    Ok(())
+}
+
+/// Allowlist the swap adapter program used for SPL → SOL gas swaps (Pubkey::default() disables).
+pub fn set_swap_adapter(ctx: Context<AdminAction>, swap_adapter: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let old = config.swap_adapter;
+    config.swap_adapter = swap_adapter;
+
+    emit!(SwapAdapterUpdated {
+        old,
+        new: swap_adapter,
+    });
+    Ok(())
+}
+
 // =========================
 // RATE LIMITING ADMIN FUNCTIONS
 // =========================
@@ -197,6 +417,18 @@ pub fn update_epoch_duration(
     Ok(())
 }
 
+/// Set the global all-token USD cap per epoch (8 decimals). 0 disables.
+/// Applies only while epoch-based rate limiting is enabled (`epoch_duration_sec > 0`).
+pub fn set_epoch_usd_cap(ctx: Context<RateLimitConfigAction>, epoch_usd_cap: u128) -> Result<()> {
+    let rate_limit_config = &mut ctx.accounts.rate_limit_config;
+    rate_limit_config.epoch_usd_cap = epoch_usd_cap;
+    rate_limit_config.bump = ctx.bumps.rate_limit_config;
+
+    emit!(EpochUsdCapUpdated { epoch_usd_cap });
+
+    Ok(())
+}
+
 /// Set token-specific rate limit threshold (matching EVM setTokenToLimitThreshold)
 #[derive(Accounts)]
 pub struct TokenRateLimitAction<'info> {
@@ -246,3 +478,357 @@ pub fn set_token_rate_limit(
 
     Ok(())
 }
+
+/// Switch a token's epoch rate limit between natural units and USD (8 decimals).
+/// Epoch usage is reset because its unit changes; re-set `limit_threshold` in the new unit.
+pub fn set_token_rate_limit_usd_mode(ctx: Context<TokenRateLimitAction>, usd_mode: bool) -> Result<()> {
+    let token_rate_limit = &mut ctx.accounts.token_rate_limit;
+    token_rate_limit.token_mint = ctx.accounts.token_mint.key();
+    token_rate_limit.usd_mode = usd_mode;
+    token_rate_limit.epoch_usage = EpochUsage { epoch: 0, used: 0 };
+
+    emit!(TokenRateLimitModeUpdated {
+        token_mint: ctx.accounts.token_mint.key(),
+        usd_mode,
+    });
+
+    Ok(())
+}
+
+/// Set per-deposit USD bounds for a token's FUNDS routes (8 decimals; both 0 disables).
+/// Requires an `OracleRegistry` entry for the mint to price deposits.
+pub fn set_token_usd_caps(
+    ctx: Context<TokenRateLimitAction>,
+    min_funds_usd: u128,
+    max_funds_usd: u128,
+) -> Result<()> {
+    require!(
+        max_funds_usd == 0 || min_funds_usd <= max_funds_usd,
+        GatewayError::InvalidCapRange
+    );
+    let token_rate_limit = &mut ctx.accounts.token_rate_limit;
+    token_rate_limit.token_mint = ctx.accounts.token_mint.key();
+    token_rate_limit.min_funds_usd = min_funds_usd;
+    token_rate_limit.max_funds_usd = max_funds_usd;
+
+    emit!(TokenUsdCapsUpdated {
+        token_mint: ctx.accounts.token_mint.key(),
+        min_funds_usd,
+        max_funds_usd,
+    });
+
+    Ok(())
+}
+
+/// Per-token FUNDS fees: `funds_fee_override` replaces the protocol fee with
+/// `funds_fee_lamports` for this token, and `funds_fee_bps` (< 10_000, SPL only) takes a share
+/// of the deposited tokens into the fee vault's token account.
+pub fn set_token_fee(
+    ctx: Context<TokenRateLimitAction>,
+    funds_fee_override: bool,
+    funds_fee_lamports: u64,
+    funds_fee_bps: u16,
+) -> Result<()> {
+    require!(funds_fee_bps < BPS_DENOMINATOR, GatewayError::InvalidInput);
+    let token_mint = ctx.accounts.token_mint.key();
+    // Native SOL FUNDS are bridged in lamports; bps fees are collected in SPL tokens only.
+    require!(
+        funds_fee_bps == 0 || token_mint != Pubkey::default(),
+        GatewayError::InvalidMint
+    );
+
+    let token_rate_limit = &mut ctx.accounts.token_rate_limit;
+    token_rate_limit.token_mint = token_mint;
+    token_rate_limit.funds_fee_override = funds_fee_override;
+    token_rate_limit.funds_fee_lamports = funds_fee_lamports;
+    token_rate_limit.funds_fee_bps = funds_fee_bps;
+
+    emit!(TokenFeeUpdated {
+        token_mint,
+        funds_fee_override,
+        funds_fee_lamports,
+        funds_fee_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FeeExemptionAction<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ GatewayError::Paused,
+        constraint = config.admin == admin.key() @ GatewayError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FeeExemption::LEN,
+        seeds = [FEE_EXEMPTION_SEED, sender.key().as_ref()],
+        bump
+    )]
+    pub fee_exemption: Account<'info, FeeExemption>,
+
+    /// CHECK: Depositor identity (`UniversalTx.sender`), e.g. a partner program's PDA
+    pub sender: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Add (`exempt = true`) or remove a depositor identity from the protocol fee allowlist.
+pub fn set_fee_exemption(ctx: Context<FeeExemptionAction>, exempt: bool) -> Result<()> {
+    let sender = ctx.accounts.sender.key();
+    require!(sender != Pubkey::default(), GatewayError::ZeroAddress);
+
+    let fee_exemption = &mut ctx.accounts.fee_exemption;
+    fee_exemption.sender = sender;
+    fee_exemption.exempt = exempt;
+    fee_exemption.bump = ctx.bumps.fee_exemption;
+
+    emit!(FeeExemptionUpdated { sender, exempt });
+
+    Ok(())
+}
+
+// =========================
+// ORACLE REGISTRY ADMIN FUNCTIONS
+// =========================
+
+/// Register (or update) the Pyth feed for a mint. Use `Pubkey::default()` for native SOL.
+#[derive(Accounts)]
+pub struct OracleAction<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ GatewayError::Paused,
+        constraint = config.admin == admin.key() @ GatewayError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = OracleRegistry::LEN,
+        seeds = [ORACLE_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub oracle: Account<'info, OracleRegistry>,
+
+    /// CHECK: Token mint (SPL mint account), or `Pubkey::default()` for native SOL
+    pub token_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Register the asset/USD feed for a mint: `price_feed` account, `feed_id`, max price age
+/// (0 = default), confidence bound in bps of the price (0 = disabled) and minimum verification (0 = full,
+/// n > 0 = partial with at least n signatures). Decimals are read from the mint.
+pub fn set_oracle(
+    ctx: Context<OracleAction>,
+    price_feed: Pubkey,
+    feed_id: [u8; 32],
+    max_age_seconds: u64,
+    confidence_bps: u16,
+    min_verification_signatures: u8,
+) -> Result<()> {
+    require!(price_feed != Pubkey::default(), GatewayError::ZeroAddress);
+    require!(confidence_bps <= BPS_DENOMINATOR, GatewayError::InvalidInput);
+
+    let mint = ctx.accounts.token_mint.key();
+    let decimals = if mint == Pubkey::default() {
+        9
+    } else {
+        let mint_info = ctx.accounts.token_mint.to_account_info();
+        require!(mint_info.owner == &spl_token::ID, GatewayError::InvalidMint);
+        let data = mint_info.try_borrow_data()?;
+        spl_token::state::Mint::unpack(&data)
+            .map_err(|_| error!(GatewayError::InvalidMint))?
+            .decimals
+    };
+
+    let oracle = &mut ctx.accounts.oracle;
+    oracle.mint = mint;
+    oracle.decimals = decimals;
+    oracle.price_feed = price_feed;
+    oracle.feed_id = feed_id;
+    oracle.max_age_seconds = max_age_seconds;
+    oracle.confidence_bps = confidence_bps;
+    oracle.bump = ctx.bumps.oracle;
+    oracle.min_verification_signatures = min_verification_signatures;
+
+    emit!(OracleUpdated {
+        mint,
+        price_feed,
+        feed_id,
+        max_age_seconds,
+        confidence_bps,
+        min_verification_signatures,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PriceSourcesAction<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ GatewayError::Paused,
+        constraint = config.admin == admin.key() @ GatewayError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PriceSources::LEN,
+        seeds = [PRICE_SOURCES_SEED],
+        bump
+    )]
+    pub price_sources: Account<'info, PriceSources>,
+
+    /// Native SOL `OracleRegistry` entry; its `price_feed` is the primary SOL/USD feed.
+    #[account(constraint = sol_oracle.mint == Pubkey::default() @ GatewayError::InvalidAccount)]
+    pub sol_oracle: Account<'info, OracleRegistry>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Configure the secondary SOL/USD feeds (`Pubkey::default()` = unused slot) and how they are
+/// aggregated with the native SOL oracle's `price_feed`. `enabled` switches instant-route pricing to
+/// `PriceSources`; while enabled every deposit on that route must pass the account.
+pub fn set_price_sources(
+    ctx: Context<PriceSourcesAction>,
+    secondary_feeds: [Pubkey; MAX_SECONDARY_PRICE_FEEDS],
+    aggregation: PriceAggregation,
+    enabled: bool,
+) -> Result<()> {
+    let primary = ctx.accounts.sol_oracle.price_feed;
+    for (i, feed) in secondary_feeds.iter().enumerate() {
+        if *feed == Pubkey::default() {
+            continue;
+        }
+        require!(*feed != primary, GatewayError::InvalidInput);
+        require!(
+            !secondary_feeds[..i].contains(feed),
+            GatewayError::InvalidInput
+        );
+    }
+
+    let price_sources = &mut ctx.accounts.price_sources;
+    price_sources.secondary_feeds = secondary_feeds;
+    price_sources.aggregation = aggregation;
+    price_sources.bump = ctx.bumps.price_sources;
+    ctx.accounts.config.price_sources_enabled = enabled;
+
+    emit!(PriceSourcesUpdated {
+        secondary_feeds,
+        aggregation,
+        enabled,
+    });
+
+    Ok(())
+}
+
+/// Push a fallback SOL/USD price (USD per SOL, 8 decimals) used only when no feed is fresh,
+/// until `expires_at`. `price_usd == 0` clears it.
+pub fn set_fallback_price(
+    ctx: Context<PriceSourcesAction>,
+    price_usd: u64,
+    expires_at: i64,
+) -> Result<()> {
+    if price_usd > 0 {
+        let now = Clock::get()?.unix_timestamp;
+        require!(expires_at > now, GatewayError::InvalidInput);
+    }
+
+    let price_sources = &mut ctx.accounts.price_sources;
+    price_sources.fallback_price_usd = price_usd;
+    price_sources.fallback_expires_at = if price_usd > 0 { expires_at } else { 0 };
+    price_sources.bump = ctx.bumps.price_sources;
+
+    emit!(FallbackPriceUpdated {
+        price_usd,
+        expires_at: price_sources.fallback_expires_at,
+    });
+
+    Ok(())
+}
+
+/// Allowlist (or update) an SPL token accepted as Push gas on the instant route
+#[derive(Accounts)]
+pub struct GasTokenAction<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ GatewayError::Paused,
+        constraint = config.admin == admin.key() @ GatewayError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = GasToken::LEN,
+        seeds = [GAS_TOKEN_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub gas_token: Account<'info, GasToken>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Configure a gas token: `peg_usd` (8 decimals) values it at a fixed peg; with `peg_usd == 0`
+/// it is priced through the Pyth `price_feed` account for `feed_id`. `enabled = false` delists it.
+pub fn set_gas_token(
+    ctx: Context<GasTokenAction>,
+    peg_usd: u64,
+    price_feed: Pubkey,
+    feed_id: [u8; 32],
+    enabled: bool,
+) -> Result<()> {
+    let mint = ctx.accounts.token_mint.key();
+    // Native SOL already pays gas natively; wSOL is unwrapped before routing.
+    require!(mint != spl_token::native_mint::ID, GatewayError::InvalidMint);
+    if enabled {
+        require!(
+            peg_usd > 0 || price_feed != Pubkey::default(),
+            GatewayError::InvalidInput
+        );
+    }
+
+    let gas_token = &mut ctx.accounts.gas_token;
+    gas_token.mint = mint;
+    gas_token.decimals = ctx.accounts.token_mint.decimals;
+    gas_token.peg_usd = peg_usd;
+    gas_token.price_feed = price_feed;
+    gas_token.feed_id = feed_id;
+    gas_token.enabled = enabled;
+    gas_token.bump = ctx.bumps.gas_token;
+
+    emit!(GasTokenUpdated {
+        mint,
+        peg_usd,
+        price_feed,
+        feed_id,
+        enabled,
+    });
+
+    Ok(())
+}
@@ -3,7 +3,13 @@ use crate::state::*;
 use crate::utils::*;
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use anchor_spl::token::{self, spl_token, Token, Transfer};
+use anchor_lang::solana_program::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+    program_option::COption,
+};
+use anchor_spl::token::{self, spl_token, CloseAccount, Token, Transfer};
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 // =========================
 //           DEPOSITS
@@ -14,6 +20,16 @@ use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 ///         `native_amount` mirrors `msg.value` on EVM chains - represents total native SOL sent.
 ///         Routes to GAS (instant) or FUNDS (standard) handlers based on derived tx type.
 pub fn send_universal_tx(
+    ctx: Context<SendUniversalTx>,
+    req: UniversalTxRequest,
+    native_amount: u64,
+) -> Result<()> {
+    // The delegate pull is only reachable through send_universal_tx_sponsored.
+    require!(!ctx.accounts.is_sponsored(), GatewayError::InvalidAccount);
+    process_universal_tx(ctx, req, native_amount)
+}
+
+fn process_universal_tx(
     mut ctx: Context<SendUniversalTx>,
     req: UniversalTxRequest,
     native_amount: u64,
@@ -26,14 +42,561 @@ pub fn send_universal_tx(
     );
 
     // Collect protocol fee first so all downstream routing sees post-fee native amount.
-    let adjusted_native_amount = collect_protocol_fee(&mut ctx, native_amount)?;
+    let fee_route = FeeRoute::of(&req, &ctx.accounts.token_rate_limit);
+    let adjusted_native_amount = collect_protocol_fee(&mut ctx, native_amount, fee_route)?;
+
+    // wSOL deposits are unwrapped and routed as native SOL (shared native rate limit + token mapping).
+    let (req, adjusted_native_amount) = unwrap_wsol_deposit(&ctx, req, adjusted_native_amount)?;
 
     let tx_type = fetch_tx_type(&req, adjusted_native_amount)?;
     route_universal_tx(&mut ctx, req, adjusted_native_amount, tx_type)
 }
 
-fn collect_protocol_fee(ctx: &mut Context<SendUniversalTx>, native_amount: u64) -> Result<u64> {
-    let fee_lamports = ctx.accounts.fee_vault.protocol_fee_lamports;
+/// @notice CPI-composable entrypoint: another program deposits on behalf of its own identity.
+/// @dev    `sender` (typically a PDA signing via invoke_signed) is the logical sender recorded in
+///         `UniversalTx.sender` and the authority of the source token account. `user` only funds
+///         native SOL (amount, gas and protocol fee). Routing is identical to `send_universal_tx`.
+pub fn send_universal_tx_cpi(
+    ctx: Context<SendUniversalTx>,
+    req: UniversalTxRequest,
+    native_amount: u64,
+) -> Result<()> {
+    require!(ctx.accounts.sender.is_some(), GatewayError::InvalidAccount);
+    send_universal_tx(ctx, req, native_amount)
+}
+
+/// @notice Sponsored deposit: `user` (relayer / dApp) pays the protocol fee and transaction fees;
+///         the depositor's SPL tokens are pulled through the delegate approval
+///         `user_token_account` granted to the `deposit_delegate` PDA. As an SPL FUNDS deposit,
+///         `native_amount` must equal the protocol fee (no gas top-up).
+/// @dev    The depositor does not sign, so the request is pinned to the depositor: FUNDS only
+///         (no payload, which would execute as the depositor), crediting the depositor's own UEA
+///         (`recipient == [0u8; 20]`) and reverting to the depositor.
+pub fn send_universal_tx_sponsored(
+    ctx: Context<SendUniversalTx>,
+    req: UniversalTxRequest,
+    native_amount: u64,
+) -> Result<()> {
+    let depositor = ctx
+        .accounts
+        .depositor
+        .as_ref()
+        .ok_or(error!(GatewayError::InvalidAccount))?
+        .key();
+    require!(ctx.accounts.deposit_delegate.is_some(), GatewayError::InvalidAccount);
+    require!(ctx.accounts.sender.is_none(), GatewayError::InvalidAccount);
+
+    require!(req.amount > 0, GatewayError::InvalidAmount);
+    require!(
+        req.token != Pubkey::default() && req.token != spl_token::native_mint::ID,
+        GatewayError::InvalidMint
+    );
+    require!(req.recipient == [0u8; 20], GatewayError::InvalidRecipient);
+    require!(req.revert_recipient == depositor, GatewayError::InvalidRecipient);
+    // UniversalTx.sender is the depositor, so a payload would impersonate them.
+    require!(req.payload.is_empty(), GatewayError::InvalidPayload);
+
+    process_universal_tx(ctx, req, native_amount)
+}
+
+/// @notice Swap-to-gas (EVM `swapToNative` parity): a user holding only SPL tokens funds Push gas.
+/// @dev    CPIs `swap_to_native(amount_in, min_amount_out)` on `config.swap_adapter` with
+///         remaining_accounts = `[adapter_program, user, user_token_account, ...adapter accounts]`.
+///         The lamports the user received (re-checked against `min_amount_out`) are added to
+///         `native_amount` and routed as GAS / GAS_AND_PAYLOAD, so the protocol fee, USD caps and
+///         block USD cap apply unchanged.
+pub fn send_universal_tx_swap_gas<'info>(
+    ctx: Context<'_, '_, '_, 'info, SendUniversalTx<'info>>,
+    req: UniversalTxRequest,
+    amount_in: u64,
+    min_amount_out: u64,
+    native_amount: u64,
+) -> Result<()> {
+    require!(!ctx.accounts.config.paused, GatewayError::Paused);
+    require!(!ctx.accounts.is_sponsored(), GatewayError::InvalidAccount);
+    require!(ctx.accounts.sender.is_none(), GatewayError::InvalidAccount);
+
+    let swap_adapter = ctx.accounts.config.swap_adapter;
+    require!(swap_adapter != Pubkey::default(), GatewayError::NotSupported);
+    require!(amount_in > 0 && min_amount_out > 0, GatewayError::InvalidAmount);
+    // Swapped lamports fund the gas leg only.
+    require!(req.amount == 0, GatewayError::InvalidAmount);
+
+    let (adapter_program, adapter_accounts) = ctx
+        .remaining_accounts
+        .split_first()
+        .ok_or(error!(GatewayError::AccountListLengthMismatch))?;
+    require!(
+        adapter_program.key() == swap_adapter && adapter_program.executable,
+        GatewayError::InvalidProgram
+    );
+    require!(adapter_accounts.len() >= 2, GatewayError::AccountListLengthMismatch);
+    require!(
+        adapter_accounts[0].key() == ctx.accounts.user.key(),
+        GatewayError::AccountPubkeyMismatch
+    );
+
+    let mut data = hash(SWAP_TO_NATIVE_IX_NAME).to_bytes()[..8].to_vec();
+    data.extend_from_slice(&amount_in.to_le_bytes());
+    data.extend_from_slice(&min_amount_out.to_le_bytes());
+    let swap_ix = Instruction {
+        program_id: swap_adapter,
+        accounts: adapter_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data,
+    };
+
+    let lamports_before = ctx.accounts.user.lamports();
+    invoke(&swap_ix, ctx.remaining_accounts)?;
+    let received = ctx
+        .accounts
+        .user
+        .lamports()
+        .checked_sub(lamports_before)
+        .ok_or(error!(GatewayError::SlippageExceeded))?;
+    require!(received >= min_amount_out, GatewayError::SlippageExceeded);
+
+    let native_amount = native_amount
+        .checked_add(received)
+        .ok_or(error!(GatewayError::InvalidAmount))?;
+    process_universal_tx(ctx, req, native_amount)
+}
+
+/// @notice Gas-in-token (instant route): pays Push gas with an allowlisted stable SPL token.
+/// @dev    `req.token` must have an enabled `GasToken` entry; `req.amount` token units are valued
+///         at the configured peg or through the token's own Pyth feed, checked against the same
+///         USD caps and block USD cap as SOL gas, and locked in the vault ATA. `native_amount`
+///         covers the protocol fee only. Emits `UniversalTx` with `tx_type: Gas` (or
+///         `GasAndPayload` with a payload) and `token` set to the mint.
+pub fn send_universal_tx_gas_token(
+    mut ctx: Context<SendUniversalTx>,
+    req: UniversalTxRequest,
+    native_amount: u64,
+) -> Result<()> {
+    require!(!ctx.accounts.config.paused, GatewayError::Paused);
+    require!(!ctx.accounts.is_sponsored(), GatewayError::InvalidAccount);
+    require!(
+        ctx.accounts.user.lamports() >= native_amount,
+        GatewayError::InsufficientBalance
+    );
+
+    // Copied out: collect_protocol_fee below borrows ctx mutably.
+    let gas_token: GasToken = ctx
+        .accounts
+        .gas_token
+        .as_deref()
+        .cloned()
+        .ok_or(error!(GatewayError::InvalidAccount))?;
+    require!(
+        gas_token.enabled && gas_token.mint == req.token,
+        GatewayError::NotSupported
+    );
+    require!(req.amount > 0, GatewayError::InvalidAmount);
+    require!(
+        req.revert_recipient != Pubkey::default(),
+        GatewayError::InvalidRecipient
+    );
+
+    // Native SOL pays the protocol fee only; token gas is the whole gas leg.
+    let fee_route = FeeRoute {
+        funds: false,
+        payload: !req.payload.is_empty(),
+        token_fee: None,
+    };
+    let adjusted_native_amount = collect_protocol_fee(&mut ctx, native_amount, fee_route)?;
+    require!(adjusted_native_amount == 0, GatewayError::InvalidAmount);
+
+    let usd_amount = calculate_gas_token_usd_amount(
+        &ctx.accounts.config,
+        &gas_token,
+        req.amount,
+        ctx.accounts.gas_token_price_update.as_ref(),
+    )?;
+    check_usd_cap_bounds(&ctx.accounts.config, usd_amount)?;
+    check_block_usd_cap(&mut ctx.accounts.rate_limit_config, usd_amount)?;
+
+    deposit_spl_to_vault(&ctx, req.token, req.amount)?;
+
+    let tx_type = if req.payload.is_empty() {
+        TxType::Gas
+    } else {
+        TxType::GasAndPayload
+    };
+    emit!(UniversalTx {
+        sender: ctx.accounts.sender_info().key(),
+        recipient: [0u8; 20],
+        token: req.token,
+        amount: req.amount,
+        payload: req.payload,
+        revert_recipient: req.revert_recipient,
+        tx_type,
+        signature_data: req.signature_data,
+        from_cea: false,
+    });
+
+    Ok(())
+}
+
+/// @notice Batched deposits: routes each request exactly like `send_universal_tx`.
+/// @dev    remaining_accounts carry the per-request accounts, in request order:
+///         `[token_rate_limit, oracle, oracle_price_update]` for native SOL, plus
+///         `[user_token_account, gateway_token_account]` for any other token (wSOL ignores the
+///         gateway account). Each request is priced through its own oracle slot.
+///         The fixed `token_rate_limit` / oracle / token accounts are not used for routing.
+///         The protocol fee is charged per request, or once (from `native_amounts[0]`) when
+///         `fee_vault.fee_per_batch` is set.
+pub fn send_universal_tx_batch<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, SendUniversalTx<'info>>,
+    reqs: Vec<UniversalTxRequest>,
+    native_amounts: Vec<u64>,
+) -> Result<()> {
+    require!(!ctx.accounts.config.paused, GatewayError::Paused);
+    require!(!ctx.accounts.is_sponsored(), GatewayError::InvalidAccount);
+    require!(
+        !reqs.is_empty() && reqs.len() <= MAX_BATCH_REQUESTS,
+        GatewayError::InvalidInput
+    );
+    require!(reqs.len() == native_amounts.len(), GatewayError::InvalidInput);
+    // Unwrapping closes the sender's wSOL account, so a second wSOL request could not reuse it.
+    require!(
+        reqs.iter()
+            .filter(|req| req.token == spl_token::native_mint::ID && req.amount > 0)
+            .count()
+            <= 1,
+        GatewayError::InvalidInput
+    );
+
+    let total_native = native_amounts
+        .iter()
+        .try_fold(0u64, |acc, amount| acc.checked_add(*amount))
+        .ok_or(error!(GatewayError::InvalidAmount))?;
+    require!(
+        ctx.accounts.user.lamports() >= total_native,
+        GatewayError::InsufficientBalance
+    );
+
+    let fee_per_batch = ctx.accounts.fee_vault.fee_per_batch;
+    let remaining_accounts = ctx.remaining_accounts;
+    let mut cursor = 0;
+    let mut bps_fee_mint = None;
+
+    for (index, (req, native_amount)) in reqs.into_iter().zip(native_amounts).enumerate() {
+        let accounts_len = if req.token == Pubkey::default() {
+            BATCH_NATIVE_ACCOUNTS
+        } else {
+            BATCH_SPL_ACCOUNTS
+        };
+        let accounts = remaining_accounts
+            .get(cursor..cursor + accounts_len)
+            .ok_or(error!(GatewayError::AccountListLengthMismatch))?;
+        cursor += accounts_len;
+        load_batch_accounts(&mut ctx, accounts)?;
+        if req.amount > 0 {
+            claim_fee_token_account(&ctx, &mut bps_fee_mint, req.token)?;
+        }
+
+        let adjusted_native_amount = if index == 0 || !fee_per_batch {
+            let fee_route = FeeRoute::of(&req, &ctx.accounts.token_rate_limit);
+            collect_protocol_fee(&mut ctx, native_amount, fee_route)?
+        } else {
+            native_amount
+        };
+        let (req, adjusted_native_amount) =
+            unwrap_wsol_deposit(&ctx, req, adjusted_native_amount)?;
+
+        let tx_type = fetch_tx_type(&req, adjusted_native_amount)?;
+        route_universal_tx(&mut ctx, req, adjusted_native_amount, tx_type)?;
+    }
+
+    require!(
+        cursor == remaining_accounts.len(),
+        GatewayError::AccountListLengthMismatch
+    );
+    Ok(())
+}
+
+/// @notice Multi-token deposit bundle: locks SOL and/or SPL legs into the vault atomically and
+///         emits a single `UniversalTxBundle`, so Push Chain receives all legs or none.
+/// @dev    remaining_accounts carry the per-leg accounts in leg order, with the same layout as
+///         `send_universal_tx_batch`. `native_amount` covers the protocol fee and the SOL leg;
+///         with a payload, any excess is a gas top-up (FUNDS_AND_PAYLOAD batching).
+///         SPL legs pay their token's bps fee; the event carries the net leg amounts.
+pub fn send_universal_tx_bundle<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, SendUniversalTx<'info>>,
+    mut req: UniversalTxBundleRequest,
+    native_amount: u64,
+) -> Result<()> {
+    require!(!ctx.accounts.config.paused, GatewayError::Paused);
+    require!(!ctx.accounts.is_sponsored(), GatewayError::InvalidAccount);
+    require!(
+        ctx.accounts.user.lamports() >= native_amount,
+        GatewayError::InsufficientBalance
+    );
+    require!(
+        req.revert_recipient != Pubkey::default(),
+        GatewayError::InvalidRecipient
+    );
+    require!(
+        !req.legs.is_empty() && req.legs.len() <= MAX_BUNDLE_LEGS,
+        GatewayError::InvalidInput
+    );
+    for (index, leg) in req.legs.iter().enumerate() {
+        require!(leg.amount > 0, GatewayError::InvalidAmount);
+        // wSOL is bundled as a native SOL leg.
+        require!(leg.mint != spl_token::native_mint::ID, GatewayError::InvalidMint);
+        require!(
+            req.legs[..index].iter().all(|prev| prev.mint != leg.mint),
+            GatewayError::InvalidInput
+        );
+    }
+
+    let fee_quote = bundle_fee_quote(&ctx, &req)?;
+    let adjusted_native_amount = transfer_protocol_fee(&mut ctx, native_amount, fee_quote)?;
+    let native_leg = req
+        .legs
+        .iter()
+        .find(|leg| leg.mint == Pubkey::default())
+        .map_or(0, |leg| leg.amount);
+    require!(adjusted_native_amount >= native_leg, GatewayError::InvalidAmount);
+
+    let tx_type = if req.payload.is_empty() {
+        TxType::Funds
+    } else {
+        TxType::FundsAndPayload
+    };
+    let gas_amount = adjusted_native_amount - native_leg;
+    if gas_amount > 0 {
+        require!(tx_type == TxType::FundsAndPayload, GatewayError::InvalidAmount);
+        send_tx_with_gas_route(
+            &mut ctx,
+            TxType::Gas,
+            gas_amount,
+            &[],
+            &req.revert_recipient,
+            &req.signature_data,
+        )?;
+    }
+
+    let remaining_accounts = ctx.remaining_accounts;
+    let mut cursor = 0;
+    let mut bps_fee_mint = None;
+    let sender = ctx.accounts.sender_info().key();
+    for leg in req.legs.iter_mut() {
+        let accounts_len = if leg.mint == Pubkey::default() {
+            BATCH_NATIVE_ACCOUNTS
+        } else {
+            BATCH_SPL_ACCOUNTS
+        };
+        let accounts = remaining_accounts
+            .get(cursor..cursor + accounts_len)
+            .ok_or(error!(GatewayError::AccountListLengthMismatch))?;
+        cursor += accounts_len;
+        load_batch_accounts(&mut ctx, accounts)?;
+
+        // SPL legs pay their token's bps fee like a single FUNDS deposit; the event carries
+        // the net amount.
+        let mut fee_amount = 0;
+        if leg.mint != Pubkey::default() {
+            claim_fee_token_account(&ctx, &mut bps_fee_mint, leg.mint)?;
+            fee_amount = funds_fee_amount(&ctx, leg.amount)?;
+            leg.amount -= fee_amount;
+            require!(leg.amount > 0, GatewayError::InvalidAmount);
+        }
+
+        let usd_amount = funds_usd_amount(&ctx, &leg.mint, leg.amount)?;
+        validate_token_and_consume_rate_limit(
+            &mut ctx.accounts.token_rate_limit,
+            leg.mint,
+            leg.amount as u128,
+            usd_amount,
+            &mut ctx.accounts.rate_limit_config,
+        )?;
+        check_funds_usd_caps(&ctx.accounts.token_rate_limit, usd_amount)?;
+        if leg.mint == Pubkey::default() {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            );
+            system_program::transfer(cpi_ctx, leg.amount)?;
+        } else {
+            deposit_spl_to_vault(&ctx, leg.mint, leg.amount)?;
+        }
+
+        if fee_amount > 0 {
+            collect_funds_fee(&ctx, leg.mint, fee_amount)?;
+            emit!(FundsFeeCollected {
+                payer: sender,
+                token: leg.mint,
+                amount: fee_amount,
+                net_amount: leg.amount,
+            });
+        }
+    }
+    require!(
+        cursor == remaining_accounts.len(),
+        GatewayError::AccountListLengthMismatch
+    );
+
+    let recipient = if tx_type == TxType::Funds { req.recipient } else { [0u8; 20] };
+    emit!(UniversalTxBundle {
+        sender,
+        recipient,
+        legs: req.legs,
+        payload: req.payload,
+        revert_recipient: req.revert_recipient,
+        tx_type,
+        signature_data: req.signature_data,
+    });
+
+    Ok(())
+}
+
+/// Swap one batch request's (or bundle leg's) accounts into the shared context, including its
+/// oracle slot, so every request is priced through its own token's `OracleRegistry`.
+/// The outgoing token rate limit is persisted first, so a later request for the same token
+/// reloads its updated epoch usage. Anchor persists the last one on instruction exit.
+fn load_batch_accounts<'info>(
+    ctx: &mut Context<'_, '_, 'info, 'info, SendUniversalTx<'info>>,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    ctx.accounts.token_rate_limit.exit(&crate::ID)?;
+
+    require!(accounts[0].is_writable, GatewayError::InvalidAccount);
+    ctx.accounts.token_rate_limit = Account::try_from(&accounts[0])?;
+    ctx.accounts.oracle = optional_slot_account(&accounts[1])?;
+    ctx.accounts.oracle_price_update = optional_slot_account(&accounts[2])?;
+
+    if accounts.len() == BATCH_SPL_ACCOUNTS {
+        ctx.accounts.user_token_account = Some(UncheckedAccount::try_from(&accounts[3]));
+        ctx.accounts.gateway_token_account = Some(UncheckedAccount::try_from(&accounts[4]));
+    }
+    Ok(())
+}
+
+/// Which per-route fees a deposit pays (`set_route_fees`, `set_token_fee`).
+#[derive(Clone, Copy)]
+struct FeeRoute {
+    funds: bool,
+    payload: bool,
+    /// The deposited token's FUNDS fee override (`funds_fee_lamports`), if it sets one.
+    token_fee: Option<u64>,
+}
+
+impl FeeRoute {
+    /// `token_rate_limit` must be the deposited token's; the funds route checks its mint.
+    fn of(req: &UniversalTxRequest, token_rate_limit: &TokenRateLimit) -> Self {
+        let funds = req.amount > 0;
+        Self {
+            funds,
+            payload: !req.payload.is_empty(),
+            token_fee: (funds && token_rate_limit.funds_fee_override)
+                .then_some(token_rate_limit.funds_fee_lamports),
+        }
+    }
+}
+
+/// A bundle pays the largest fee any of its legs would pay as a single FUNDS deposit, so
+/// bundling never avoids a token's fee override. Reads each leg's `token_rate_limit` from
+/// remaining_accounts; the leg loop later checks its mint.
+fn bundle_fee_quote<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, SendUniversalTx<'info>>,
+    req: &UniversalTxBundleRequest,
+) -> Result<(u64, u128)> {
+    let mut any_default = false;
+    let mut max_token_fee = None;
+    let mut cursor = 0;
+    for leg in &req.legs {
+        let account = ctx
+            .remaining_accounts
+            .get(cursor)
+            .ok_or(error!(GatewayError::AccountListLengthMismatch))?;
+        let token_rate_limit: Account<TokenRateLimit> = Account::try_from(account)?;
+        if token_rate_limit.funds_fee_override {
+            max_token_fee = max_token_fee.max(Some(token_rate_limit.funds_fee_lamports));
+        } else {
+            any_default = true;
+        }
+        cursor += if leg.mint == Pubkey::default() {
+            BATCH_NATIVE_ACCOUNTS
+        } else {
+            BATCH_SPL_ACCOUNTS
+        };
+    }
+
+    let payload = !req.payload.is_empty();
+    let mut quote = (0, 0);
+    for token_fee in [any_default.then_some(None), max_token_fee.map(Some)]
+        .into_iter()
+        .flatten()
+    {
+        let leg_quote = protocol_fee_quote(
+            ctx,
+            FeeRoute {
+                funds: true,
+                payload,
+                token_fee,
+            },
+        )?;
+        if leg_quote.0 > quote.0 {
+            quote = leg_quote;
+        }
+    }
+    Ok(quote)
+}
+
+/// Batches and bundles share one `fee_token_account`, so at most one mint per instruction may
+/// charge a bps FUNDS fee. Call after the mint's `token_rate_limit` is loaded.
+fn claim_fee_token_account(
+    ctx: &Context<SendUniversalTx>,
+    bps_fee_mint: &mut Option<Pubkey>,
+    mint: Pubkey,
+) -> Result<()> {
+    if ctx.accounts.token_rate_limit.funds_fee_bps == 0 || is_fee_exempt(ctx) {
+        return Ok(());
+    }
+    require!(
+        !matches!(*bps_fee_mint, Some(fee_mint) if fee_mint != mint),
+        GatewayError::InvalidInput
+    );
+    *bps_fee_mint = Some(mint);
+    Ok(())
+}
+
+/// Whether `fee_exemption` was passed and exempts the deposit's sender.
+/// Only the admin creates `FeeExemption` accounts, so matching `sender` is sufficient.
+fn is_fee_exempt(ctx: &Context<SendUniversalTx>) -> bool {
+    ctx.accounts
+        .fee_exemption
+        .as_ref()
+        .is_some_and(|exemption| {
+            exemption.exempt && exemption.sender == ctx.accounts.sender_info().key()
+        })
+}
+
+fn collect_protocol_fee(
+    ctx: &mut Context<SendUniversalTx>,
+    native_amount: u64,
+    route: FeeRoute,
+) -> Result<u64> {
+    let quote = protocol_fee_quote(ctx, route)?;
+    transfer_protocol_fee(ctx, native_amount, quote)
+}
+
+/// Move a quoted protocol fee from `user` to the fee vault; returns the remaining native amount.
+fn transfer_protocol_fee(
+    ctx: &mut Context<SendUniversalTx>,
+    native_amount: u64,
+    (fee_lamports, fee_usd): (u64, u128),
+) -> Result<u64> {
     if fee_lamports == 0 {
         return Ok(native_amount);
     }
@@ -60,11 +623,126 @@ fn collect_protocol_fee(ctx: &mut Context<SendUniversalTx>, native_amount: u64)
         amount_lamports: fee_lamports,
         native_amount_before: native_amount,
         native_amount_after: adjusted_native_amount,
+        amount_usd: fee_usd,
     });
 
     Ok(adjusted_native_amount)
 }
 
+/// Protocol fee in lamports and its USD value (8 decimals, 0 unless USD-priced).
+/// Precedence: fee exemption, then the token's FUNDS override, then route fees, then the USD
+/// fee, then the flat fee. A USD fee is priced like the gas route (`resolve_sol_price` +
+/// confidence bounds), then clamped to the fee vault's bounds. It needs the SOL/USD price
+/// accounts on every route, unless a `max_fee_lamports` ceiling is set and charged instead.
+fn protocol_fee_quote(ctx: &Context<SendUniversalTx>, route: FeeRoute) -> Result<(u64, u128)> {
+    if is_fee_exempt(ctx) {
+        return Ok((0, 0));
+    }
+
+    let fee_vault = &ctx.accounts.fee_vault;
+    let route_fee = if route.token_fee.is_some() {
+        route.token_fee
+    } else if fee_vault.route_fees_enabled {
+        Some(if route.funds {
+            fee_vault.funds_fee_lamports
+        } else {
+            fee_vault.gas_fee_lamports
+        })
+    } else {
+        None
+    };
+    if let Some(base_fee) = route_fee {
+        let payload_fee = if route.payload && fee_vault.route_fees_enabled {
+            fee_vault.payload_fee_lamports
+        } else {
+            0
+        };
+        let fee_lamports = base_fee
+            .checked_add(payload_fee)
+            .ok_or(error!(GatewayError::InvalidAmount))?;
+        return Ok((fee_lamports, 0));
+    }
+
+    if fee_vault.protocol_fee_usd == 0 {
+        return Ok((fee_vault.protocol_fee_lamports, 0));
+    }
+    // Without price_update, charge the lamport ceiling so omitting the price never pays less.
+    // With no ceiling set, resolve_sol_price rejects the missing account.
+    if ctx.accounts.price_update.is_none() && fee_vault.max_fee_lamports > 0 {
+        return Ok((fee_vault.max_fee_lamports, 0));
+    }
+
+    let price_data = resolve_sol_price(
+        &ctx.accounts.config,
+        ctx.accounts.sol_oracle.as_ref(),
+        ctx.accounts.price_update.as_ref(),
+        ctx.accounts.price_sources.as_ref(),
+        [
+            ctx.accounts.secondary_price_update_1.as_ref(),
+            ctx.accounts.secondary_price_update_2.as_ref(),
+        ],
+    )?;
+    check_price_confidence(&ctx.accounts.config, &price_data)?;
+
+    let mut fee_lamports =
+        usd_to_lamports(fee_vault.protocol_fee_usd, &price_data)?.max(fee_vault.min_fee_lamports);
+    if fee_vault.max_fee_lamports > 0 {
+        fee_lamports = fee_lamports.min(fee_vault.max_fee_lamports);
+    }
+    let fee_usd = calculate_usd_amount(fee_lamports, &price_data)?;
+    Ok((fee_lamports, fee_usd))
+}
+
+/// Unwrap a wSOL (`NATIVE_MINT`) deposit into native SOL before routing.
+/// Closes the user's wSOL account back to the user (SPL native accounts may close with a balance),
+/// then rewrites the request as native: `token = Pubkey::default()` and `req.amount` is added to
+/// the native amount, exactly as if the user had sent the lamports directly.
+/// The sender must be the payer: closing moves the whole balance, surplus and rent included.
+fn unwrap_wsol_deposit(
+    ctx: &Context<SendUniversalTx>,
+    mut req: UniversalTxRequest,
+    native_amount: u64,
+) -> Result<(UniversalTxRequest, u64)> {
+    // GAS routes carry no funds leg; the token field is not used there.
+    if req.token != spl_token::native_mint::ID || req.amount == 0 {
+        return Ok((req, native_amount));
+    }
+
+    let user_token_account = ctx
+        .accounts
+        .user_token_account
+        .as_ref()
+        .ok_or_else(|| error!(GatewayError::InvalidAccount))?;
+    let user_token_info = user_token_account.to_account_info();
+    require!(user_token_info.owner == &spl_token::ID, GatewayError::InvalidOwner);
+
+    let sender = ctx.accounts.sender_info();
+    let parsed_user = parse_token_account(&user_token_info)?;
+    require!(parsed_user.owner == sender.key(), GatewayError::InvalidOwner);
+    require!(parsed_user.mint == spl_token::native_mint::ID, GatewayError::InvalidMint);
+    require!(parsed_user.amount >= req.amount, GatewayError::InsufficientBalance);
+    // Otherwise a third-party payer would receive the sender's surplus wSOL and the rent.
+    require!(sender.key() == ctx.accounts.user.key(), GatewayError::InvalidAccount);
+
+    // Unwrapped lamports land with the payer (the sender), which then funds the native route.
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: user_token_info,
+            destination: ctx.accounts.user.to_account_info(),
+            authority: sender,
+        },
+    );
+    token::close_account(cpi_ctx)?;
+
+    let native_amount = native_amount
+        .checked_add(req.amount)
+        .ok_or(error!(GatewayError::InvalidAmount))?;
+    req.token = Pubkey::default();
+
+    Ok((req, native_amount))
+}
+
 /// @notice Internal router: dispatches to GAS or FUNDS handlers based on derived tx_type.
 /// @dev    Route 1: GAS | GAS_AND_PAYLOAD → Instant route (fee abstraction)
 ///         Route 2: FUNDS | FUNDS_AND_PAYLOAD → Standard route (bridge deposits)
@@ -167,7 +845,7 @@ fn send_tx_with_gas_route(
         );
 
         emit!(UniversalTx {
-            sender: ctx.accounts.user.key(),
+            sender: ctx.accounts.sender_info().key(),
             recipient: [0u8; 20],
             token: Pubkey::default(),
             amount: 0,
@@ -183,8 +861,18 @@ fn send_tx_with_gas_route(
 
     // Performs rate-limit checks and handle deposit
     // USD caps: min $1, max $10 (enforced via Pyth oracle)
-    check_usd_caps(&ctx.accounts.config, gas_amount, &ctx.accounts.price_update)?;
-    let price_data = calculate_sol_price(&ctx.accounts.price_update)?;
+    let price_data = resolve_sol_price(
+        &ctx.accounts.config,
+        ctx.accounts.sol_oracle.as_ref(),
+        ctx.accounts.price_update.as_ref(),
+        ctx.accounts.price_sources.as_ref(),
+        [
+            ctx.accounts.secondary_price_update_1.as_ref(),
+            ctx.accounts.secondary_price_update_2.as_ref(),
+        ],
+    )?;
+    check_usd_caps(&ctx.accounts.config, gas_amount, &price_data)?;
+    check_price_deviation(&mut ctx.accounts.config, &price_data)?;
     let usd_amount = calculate_usd_amount(gas_amount, &price_data)?;
     // Block-based USD cap: per-slot limit (disabled if block_usd_cap == 0)
     check_block_usd_cap(&mut ctx.accounts.rate_limit_config, usd_amount)?;
@@ -201,7 +889,7 @@ fn send_tx_with_gas_route(
 
     // Emit UniversalTx event (recipient as Pubkey::default() → UEA)
     emit!(UniversalTx {
-        sender: ctx.accounts.user.key(),
+        sender: ctx.accounts.sender_info().key(),
         recipient: [0u8; 20],
         token: Pubkey::default(),
         amount: gas_amount,
@@ -234,13 +922,14 @@ fn send_tx_with_funds_route(
         require!(!req.payload.is_empty(), GatewayError::InvalidInput);
     }
 
-    if req.token == Pubkey::default() {
+    let amount = if req.token == Pubkey::default() {
         handle_native_funds_route(ctx, &req, native_amount, tx_type)?;
+        req.amount
     } else {
-        handle_spl_funds_route(ctx, &req, native_amount, tx_type)?;
-    }
+        handle_spl_funds_route(ctx, &req, native_amount, tx_type)?
+    };
 
-    emit_funds_route_event(ctx, req, tx_type);
+    emit_funds_route_event(ctx, UniversalTxRequest { amount, ..req }, tx_type);
     Ok(())
 }
 
@@ -263,12 +952,15 @@ fn handle_native_funds_route(
         send_tx_with_gas_route(ctx, TxType::Gas, gas_amount, &[], &req.revert_recipient, &req.signature_data)?;
     }
 
+    let usd_amount = funds_usd_amount(ctx, &Pubkey::default(), req.amount)?;
     validate_token_and_consume_rate_limit(
         &mut ctx.accounts.token_rate_limit,
         Pubkey::default(),
         req.amount as u128,
-        &ctx.accounts.rate_limit_config,
+        usd_amount,
+        &mut ctx.accounts.rate_limit_config,
     )?;
+    check_funds_usd_caps(&ctx.accounts.token_rate_limit, usd_amount)?;
     let cpi_ctx = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         system_program::Transfer {
@@ -282,25 +974,98 @@ fn handle_native_funds_route(
 /// SPL token path for FUNDS and FUNDS_AND_PAYLOAD.
 /// FUNDS:           native_amount must be zero (no gas batching).
 /// FUNDS_AND_PAYLOAD: native_amount is optional gas top-up.
+/// Returns the bridged amount: `req.amount` minus the token's bps fee, if any.
 fn handle_spl_funds_route(
     ctx: &mut Context<SendUniversalTx>,
     req: &UniversalTxRequest,
     native_amount: u64,
     tx_type: TxType,
-) -> Result<()> {
+) -> Result<u64> {
     if tx_type == TxType::Funds {
         require!(native_amount == 0, GatewayError::InvalidAmount);
     } else if native_amount > 0 {
         send_tx_with_gas_route(ctx, TxType::Gas, native_amount, &[], &req.revert_recipient, &req.signature_data)?;
     }
 
+    let fee_amount = funds_fee_amount(ctx, req.amount)?;
+    let amount = req.amount - fee_amount;
+    require!(amount > 0, GatewayError::InvalidAmount);
+
+    let usd_amount = funds_usd_amount(ctx, &req.token, amount)?;
     validate_token_and_consume_rate_limit(
         &mut ctx.accounts.token_rate_limit,
         req.token,
-        req.amount as u128,
-        &ctx.accounts.rate_limit_config,
+        amount as u128,
+        usd_amount,
+        &mut ctx.accounts.rate_limit_config,
     )?;
-    deposit_spl_to_vault(ctx, req.token, req.amount)
+    check_funds_usd_caps(&ctx.accounts.token_rate_limit, usd_amount)?;
+    deposit_spl_to_vault(ctx, req.token, amount)?;
+
+    if fee_amount > 0 {
+        collect_funds_fee(ctx, req.token, fee_amount)?;
+        emit!(FundsFeeCollected {
+            payer: ctx.accounts.sender_info().key(),
+            token: req.token,
+            amount: fee_amount,
+            net_amount: amount,
+        });
+    }
+    Ok(amount)
+}
+
+/// The token's bps fee on an SPL FUNDS amount (rounded down); 0 when exempt.
+fn funds_fee_amount(ctx: &Context<SendUniversalTx>, amount: u64) -> Result<u64> {
+    let fee_bps = ctx.accounts.token_rate_limit.funds_fee_bps;
+    if fee_bps == 0 || is_fee_exempt(ctx) {
+        return Ok(0);
+    }
+    let fee = (amount as u128) * (fee_bps as u128) / (BPS_DENOMINATOR as u128);
+    u64::try_from(fee).map_err(|_| error!(GatewayError::InvalidAmount))
+}
+
+/// Transfer the bps fee from the sender's token account to the fee vault's token account.
+/// SECURITY: `fee_token_account` must be owned by the fee vault and hold `token`.
+fn collect_funds_fee(ctx: &Context<SendUniversalTx>, token: Pubkey, amount: u64) -> Result<()> {
+    let fee_token_account = ctx
+        .accounts
+        .fee_token_account
+        .as_ref()
+        .ok_or_else(|| error!(GatewayError::InvalidAccount))?;
+    let parsed = parse_token_account(&fee_token_account.to_account_info())?;
+    require!(parsed.owner == ctx.accounts.fee_vault.key(), GatewayError::InvalidOwner);
+    require!(parsed.mint == token, GatewayError::InvalidMint);
+
+    transfer_spl_from_sender(ctx, token, amount, fee_token_account.to_account_info())
+}
+
+/// USD value of a FUNDS deposit via the optional `oracle` / `oracle_price_update` accounts.
+fn funds_usd_amount(ctx: &Context<SendUniversalTx>, token: &Pubkey, amount: u64) -> Result<Option<u128>> {
+    oracle_usd_amount(
+        token,
+        amount,
+        ctx.accounts.oracle.as_ref(),
+        ctx.accounts.oracle_price_update.as_ref(),
+    )
+}
+
+/// Per-token USD bounds on FUNDS deposits, priced through the token's `OracleRegistry`.
+/// No-op unless `token_rate_limit` sets `min_funds_usd` or `max_funds_usd`.
+fn check_funds_usd_caps(token_rate_limit: &TokenRateLimit, usd_amount: Option<u128>) -> Result<()> {
+    if token_rate_limit.min_funds_usd == 0 && token_rate_limit.max_funds_usd == 0 {
+        return Ok(());
+    }
+    let usd_amount = usd_amount.ok_or(error!(GatewayError::InvalidAccount))?;
+
+    require!(
+        usd_amount >= token_rate_limit.min_funds_usd,
+        GatewayError::BelowMinCap
+    );
+    require!(
+        token_rate_limit.max_funds_usd == 0 || usd_amount <= token_rate_limit.max_funds_usd,
+        GatewayError::AboveMaxCap
+    );
+    Ok(())
 }
 
 /// Emit the UniversalTx event for FUNDS / FUNDS_AND_PAYLOAD routes.
@@ -308,7 +1073,7 @@ fn handle_spl_funds_route(
 fn emit_funds_route_event(ctx: &Context<SendUniversalTx>, req: UniversalTxRequest, tx_type: TxType) {
     let recipient = if tx_type == TxType::Funds { req.recipient } else { [0u8; 20] };
     emit!(UniversalTx {
-        sender: ctx.accounts.user.key(),
+        sender: ctx.accounts.sender_info().key(),
         recipient,
         token: req.token,
         amount: req.amount,
@@ -323,38 +1088,80 @@ fn emit_funds_route_event(ctx: &Context<SendUniversalTx>, req: UniversalTxReques
 /// Transfer SPL tokens from user's token account to the vault's ATA.
 /// SECURITY: validates vault ownership and mint before transferring.
 fn deposit_spl_to_vault(ctx: &Context<SendUniversalTx>, token: Pubkey, amount: u64) -> Result<()> {
-    let user_token_account = ctx
+    let gateway_token_account = ctx
         .accounts
-        .user_token_account
+        .gateway_token_account
         .as_ref()
         .ok_or_else(|| error!(GatewayError::InvalidAccount))?;
-    let gateway_token_account = ctx
+
+    // SECURITY: Validate gateway_token_account is the vault's ATA for this token.
+    // This prevents users from providing their own token account and stealing funds.
+    let parsed = parse_token_account(&gateway_token_account.to_account_info())?;
+    require!(parsed.owner == ctx.accounts.vault.key(), GatewayError::InvalidOwner);
+    require!(parsed.mint == token, GatewayError::InvalidMint);
+
+    transfer_spl_from_sender(ctx, token, amount, gateway_token_account.to_account_info())
+}
+
+/// Transfer SPL tokens from the sender's `user_token_account` to an already validated
+/// `destination`, signing as the sender or, for sponsored deposits, as the delegate PDA.
+fn transfer_spl_from_sender<'info>(
+    ctx: &Context<SendUniversalTx<'info>>,
+    token: Pubkey,
+    amount: u64,
+    destination: AccountInfo<'info>,
+) -> Result<()> {
+    let user_token_account = ctx
         .accounts
-        .gateway_token_account
+        .user_token_account
         .as_ref()
         .ok_or_else(|| error!(GatewayError::InvalidAccount))?;
 
     let user_token_info = user_token_account.to_account_info();
     require!(user_token_info.owner == &spl_token::ID, GatewayError::InvalidOwner);
 
-    // Validate source: authority must be the signer, mint must match requested token.
+    // Validate source: owner must be the (signing or sponsored) sender, mint must match requested token.
     // Without this, a malicious user could pass someone else's token account.
+    let sender = ctx.accounts.sender_info();
     let parsed_user = parse_token_account(&user_token_info)?;
-    require!(parsed_user.owner == ctx.accounts.user.key(), GatewayError::InvalidOwner);
+    require!(parsed_user.owner == sender.key(), GatewayError::InvalidOwner);
     require!(parsed_user.mint == token, GatewayError::InvalidMint);
 
-    // SECURITY: Validate gateway_token_account is the vault's ATA for this token.
-    // This prevents users from providing their own token account and stealing funds.
-    let parsed = parse_token_account(&gateway_token_account.to_account_info())?;
-    require!(parsed.owner == ctx.accounts.vault.key(), GatewayError::InvalidOwner);
-    require!(parsed.mint == token, GatewayError::InvalidMint);
+    // Sponsored deposits: the depositor never signs; the gateway PDA spends its delegate approval.
+    if let Some(deposit_delegate) = ctx.accounts.deposit_delegate.as_ref() {
+        require!(
+            parsed_user.delegate == COption::Some(deposit_delegate.key()),
+            GatewayError::InvalidDelegate
+        );
+        require!(
+            parsed_user.delegated_amount >= amount,
+            GatewayError::InsufficientBalance
+        );
+
+        let delegate_bump = [ctx
+            .bumps
+            .deposit_delegate
+            .ok_or(error!(GatewayError::InvalidAccount))?];
+        let delegate_seeds: &[&[u8]] = &[DEPOSIT_DELEGATE_SEED, &delegate_bump];
+        let signer: &[&[&[u8]]] = &[delegate_seeds];
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: user_token_info,
+                to: destination.clone(),
+                authority: deposit_delegate.to_account_info(),
+            },
+            signer,
+        );
+        return token::transfer(cpi_ctx, amount);
+    }
 
     let cpi_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
         Transfer {
             from: user_token_info,
-            to: gateway_token_account.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
+            to: destination,
+            authority: sender,
         },
     );
     token::transfer(cpi_ctx, amount)
@@ -366,7 +1173,9 @@ fn deposit_spl_to_vault(ctx: &Context<SendUniversalTx>, token: Pubkey, amount: u
 
 #[derive(Accounts)]
 pub struct SendUniversalTx<'info> {
+    /// Mutable so the instant route can record the last accepted SOL/USD price
     #[account(
+        mut,
         seeds = [CONFIG_SEED],
         bump = config.bump,
     )]
@@ -388,7 +1197,8 @@ pub struct SendUniversalTx<'info> {
     )]
     pub fee_vault: Account<'info, FeeVault>,
 
-    /// Only required for SPL token routes; can be omitted (pass null) on native SOL routes.
+    /// Only required for SPL token routes (and wSOL deposits, which are unwrapped and routed
+    /// as native SOL); can be omitted (pass null) on native SOL routes.
     #[account(mut)]
     pub user_token_account: Option<UncheckedAccount<'info>>,
 
@@ -399,8 +1209,13 @@ pub struct SendUniversalTx<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
-    #[account(constraint = price_update.key() == config.pyth_price_feed @ GatewayError::InvalidAccount)]
-    pub price_update: Account<'info, PriceUpdateV2>,
+    /// Native SOL `OracleRegistry` entry (`mint == Pubkey::default()`); names the SOL/USD feed
+    /// and its policy. Only read with `price_update`; omit (pass null) otherwise.
+    pub sol_oracle: Option<Account<'info, OracleRegistry>>,
+
+    /// SOL/USD price (`sol_oracle.price_feed`); only read on gas routes and USD protocol fees.
+    /// FUNDS routes without either can omit it (pass null).
+    pub price_update: Option<Account<'info, PriceUpdateV2>>,
 
     /// Rate limit config - REQUIRED for universal entrypoint
     #[account(
@@ -418,4 +1233,68 @@ pub struct SendUniversalTx<'info> {
     pub token_program: Program<'info, Token>,
 
     pub system_program: Program<'info, System>,
+
+    /// Logical sender for CPI deposits (`send_universal_tx_cpi`), e.g. a program PDA signing via
+    /// invoke_signed. When present it is recorded as `UniversalTx.sender` and authorizes SPL
+    /// transfers, while `user` only pays. Omit (pass null) for direct deposits.
+    pub sender: Option<Signer<'info>>,
+
+    /// CHECK: Depositor for sponsored deposits (`send_universal_tx_sponsored`); identity only,
+    /// must own `user_token_account`. Recorded as `UniversalTx.sender`. Omit otherwise.
+    pub depositor: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Gateway PDA the depositor approved as SPL delegate; signs the token pull.
+    /// Sponsored deposits only.
+    #[account(seeds = [DEPOSIT_DELEGATE_SEED], bump)]
+    pub deposit_delegate: Option<UncheckedAccount<'info>>,
+
+    /// Gas token allowlist entry for `req.token` (`send_universal_tx_gas_token` only).
+    /// Only the admin creates `GasToken` accounts; the handler checks `mint == req.token`.
+    pub gas_token: Option<Account<'info, GasToken>>,
+
+    /// Token/USD price for feed-priced gas tokens; must match `gas_token.price_feed`.
+    /// Omit for pegged gas tokens.
+    pub gas_token_price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    /// Oracle registry entry for the deposited token; required on FUNDS routes when the
+    /// token's `TokenRateLimit` sets USD bounds or USD mode, or the global epoch USD cap is
+    /// set. The mint is checked in `price_for`.
+    pub oracle: Option<Account<'info, OracleRegistry>>,
+
+    /// Price account named by `oracle.price_feed`.
+    pub oracle_price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    /// SOL/USD source list; required on the instant route when `config.price_sources_enabled`.
+    /// Only the admin creates `PriceSources` accounts.
+    pub price_sources: Option<Account<'info, PriceSources>>,
+
+    /// Secondary SOL/USD feeds, matching `price_sources.secondary_feeds` by position.
+    pub secondary_price_update_1: Option<Account<'info, PriceUpdateV2>>,
+    pub secondary_price_update_2: Option<Account<'info, PriceUpdateV2>>,
+
+    /// CHECK: Fee vault's token account for `req.token`; receives the token's bps FUNDS fee
+    /// (`set_token_fee`). Owner and mint are checked in `collect_funds_fee`. Omit otherwise.
+    #[account(mut)]
+    pub fee_token_account: Option<UncheckedAccount<'info>>,
+
+    /// Sender's fee exemption (`set_fee_exemption`); waives the protocol and bps fees.
+    /// Only the admin creates `FeeExemption` accounts; the handler checks `sender`.
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+}
+
+impl<'info> SendUniversalTx<'info> {
+    /// `sender` when present (CPI deposits), then `depositor` (sponsored deposits),
+    /// otherwise `user`.
+    pub fn sender_info(&self) -> AccountInfo<'info> {
+        match (&self.sender, &self.depositor) {
+            (Some(sender), _) => sender.to_account_info(),
+            (None, Some(depositor)) => depositor.to_account_info(),
+            (None, None) => self.user.to_account_info(),
+        }
+    }
+
+    /// Whether any sponsored-deposit account was supplied.
+    pub fn is_sponsored(&self) -> bool {
+        self.depositor.is_some() || self.deposit_delegate.is_some()
+    }
 }
@@ -1,12 +1,14 @@
 use crate::errors::GatewayError;
 use crate::instructions::tss::validate_message;
-use crate::instructions::withdraw::{internal_withdraw, send_universal_tx_to_uea};
+use crate::instructions::withdraw::{internal_withdraw, send_universal_tx_to_uea, sweep_cea_to_vault};
 use crate::state::{
-    Config, ExecutedSubTx, GatewayAccountMeta, RateLimitConfig, TokenRateLimit, TssPda,
-    UniversalTxFinalized, CEA_SEED, EXECUTED_SUB_TX_SEED, RATE_LIMIT_CONFIG_SEED, TSS_SEED,
-    VAULT_SEED,
+    cea_index_seed, CeaState, Config, ExecutedSubTx, GatewayAccountMeta, NonceMode,
+    OracleRegistry, PriceSources, RateLimitConfig, TokenLeg, TokenRateLimit, TssPda,
+    UniversalTxFinalized, CEA_CREDIT_ACCOUNTS, CEA_SEED, CEA_STATE_SEED, EXECUTED_SUB_TX_SEED,
+    FINALIZE_FLAGS_SUPPORTED, FINALIZE_FLAG_SWEEP_CEA, FINALIZE_FLAG_TOKEN_ACCOUNT_RECIPIENT,
+    FINALIZE_FLAG_WRAP_NATIVE, RATE_LIMIT_CONFIG_SEED, TOKEN_LEG_ACCOUNTS, TSS_SEED, VAULT_SEED,
 };
-use crate::utils::{encode_u64_be, parse_token_account, pda_spl_transfer, pda_system_transfer, serialize_gateway_accounts, serialize_ix_data, validate_remaining_accounts};
+use crate::utils::{create_ata_if_missing, encode_u64_be, parse_token_account, pda_spl_transfer, pda_system_transfer, serialize_gateway_accounts, serialize_ix_data, serialize_token_legs, validate_remaining_accounts};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
     instruction::{AccountMeta as SolanaAccountMeta, Instruction},
@@ -14,18 +16,21 @@ use anchor_lang::solana_program::{
 };
 use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
 use anchor_spl::token::{spl_token, Mint, Token, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 // =========================
 //  UNIFIED FINALIZE_UNIVERSAL_TX
 // =========================
 
 #[derive(Accounts)]
-#[instruction(instruction_id: u8, sub_tx_id: [u8; 32], universal_tx_id: [u8; 32], amount: u64, push_account: [u8; 20], writable_flags: Vec<u8>, ix_data: Vec<u8>, gas_fee: u64, signature: [u8; 64], recovery_id: u8, message_hash: [u8; 32])]
+#[instruction(instruction_id: u8, sub_tx_id: [u8; 32], universal_tx_id: [u8; 32], amount: u64, push_account: [u8; 20], writable_flags: Vec<u8>, ix_data: Vec<u8>, gas_fee: u64, nonce: u64, nonce_mode: NonceMode, flags: u8, token_legs: Vec<TokenLeg>, cea_index: u16, signature: [u8; 64], recovery_id: u8, message_hash: [u8; 32])]
 pub struct FinalizeUniversalTx<'info> {
     #[account(mut)]
     pub caller: Signer<'info>,
 
+    /// Mutable so a CEA gas leg can record the last accepted SOL/USD price
     #[account(
+        mut,
         seeds = [b"config"],
         bump,
     )]
@@ -42,9 +47,10 @@ pub struct FinalizeUniversalTx<'info> {
     /// CEA (Chain Executor Account) - persistent identity per Push Chain user
     /// This PDA represents the user on Solana and can sign for target programs
     /// Auto-created by Solana on first transfer, persists across transactions
+    /// `cea_index` selects an independent sub-account; index 0 is the legacy `[CEA_SEED, push_account]` PDA
     #[account(
         mut,
-        seeds = [CEA_SEED, push_account.as_ref()],
+        seeds = [CEA_SEED, push_account.as_ref(), &cea_index_seed(cea_index)[..]],
         bump,
     )]
     pub cea_authority: SystemAccount<'info>,
@@ -67,12 +73,26 @@ pub struct FinalizeUniversalTx<'info> {
     )]
     pub executed_sub_tx: Account<'info, ExecutedSubTx>,
 
+    /// Per-CEA outbound nonce tracker (push account + `cea_index`); Strict / Loose messages only.
+    /// Omit (pass null) for Unordered messages. Relayer pays for this account creation on first
+    /// use and gets reimbursed via gas_fee
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = CeaState::LEN,
+        seeds = [CEA_STATE_SEED, push_account.as_ref(), &cea_index_seed(cea_index)[..]],
+        bump
+    )]
+    pub cea_state: Option<Account<'info, CeaState>>,
+
     pub system_program: Program<'info, System>,
     /// CHECK: Target program for execute mode
     /// Pass system program id for withdraw, it's ignored
     pub destination_program: UncheckedAccount<'info>,
 
-    // --- Optional SPL accounts
+    // --- Optional SPL accounts (token_program / rent / associated_token_program / mint
+    //     are also used by native withdraws with FINALIZE_FLAG_WRAP_NATIVE;
+    //     token_program / rent / associated_token_program by token legs)
     /// CHECK: Recipient wallet for withdraw mode
     #[account(mut)]
     pub recipient: Option<UncheckedAccount<'info>>,
@@ -93,12 +113,15 @@ pub struct FinalizeUniversalTx<'info> {
 
     pub associated_token_program: Option<Program<'info, AssociatedToken>>,
 
-    // --- Optional recipient ATA (required for SPL withdraw mode) ---
+    // --- Optional recipient ATA (required for SPL withdraw mode, unless the token-account flag is set) ---
+    /// CHECK: Recipient ATA (created if missing via manual CPI, validated in internal_withdraw)
     #[account(mut)]
-    pub recipient_ata: Option<Account<'info, TokenAccount>>,
+    pub recipient_ata: Option<UncheckedAccount<'info>>,
 
     // --- Optional rate limit accounts (CEA withdrawal path only) ---
+    /// Mutable so a CEA gas leg can consume the block USD cap
     #[account(
+        mut,
         seeds = [RATE_LIMIT_CONFIG_SEED],
         bump,
     )]
@@ -107,6 +130,26 @@ pub struct FinalizeUniversalTx<'info> {
     /// Token-specific rate limit state (CEA withdrawal path only)
     #[account(mut)]
     pub token_rate_limit: Option<Account<'info, TokenRateLimit>>,
+
+    /// Native SOL `OracleRegistry` entry naming the SOL/USD feed (CEA gas leg only)
+    pub sol_oracle: Option<Account<'info, OracleRegistry>>,
+
+    /// Pyth SOL/USD price update at `sol_oracle.price_feed` (CEA withdrawal path with a gas leg only)
+    pub price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    /// Oracle registry entry for the withdrawn token (CEA withdrawal path; required when its
+    /// rate limit is in USD mode or the global epoch USD cap is set)
+    pub oracle: Option<Account<'info, OracleRegistry>>,
+
+    /// Price account named by `oracle.price_feed`
+    pub oracle_price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    /// SOL/USD source list; required with a CEA gas leg when `config.price_sources_enabled`
+    pub price_sources: Option<Account<'info, PriceSources>>,
+
+    /// Secondary SOL/USD feeds, matching `price_sources.secondary_feeds` by position
+    pub secondary_price_update_1: Option<Account<'info, PriceUpdateV2>>,
+    pub secondary_price_update_2: Option<Account<'info, PriceUpdateV2>>,
 }
 
 struct FinalizeRequestContext {
@@ -114,10 +157,25 @@ struct FinalizeRequestContext {
     is_native: bool,
     token: Pubkey,
     target: Pubkey,
+    flags: u8,
+    /// Number of leading remaining_accounts consumed by token legs
+    leg_accounts_len: usize,
+    /// Number of trailing remaining_accounts carrying the sweep's rate-limit slots
+    sweep_accounts_len: usize,
 }
 
-pub fn finalize_universal_tx(
-    mut ctx: Context<FinalizeUniversalTx>,
+impl FinalizeRequestContext {
+    /// remaining_accounts forwarded to the target: between the token legs and the sweep slots.
+    fn cpi_accounts<'a, 'info>(
+        &self,
+        remaining: &'a [AccountInfo<'info>],
+    ) -> &'a [AccountInfo<'info>] {
+        &remaining[self.leg_accounts_len..remaining.len() - self.sweep_accounts_len]
+    }
+}
+
+pub fn finalize_universal_tx<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, FinalizeUniversalTx<'info>>,
     instruction_id: u8,
     sub_tx_id: [u8; 32],
     universal_tx_id: [u8; 32],
@@ -126,6 +184,11 @@ pub fn finalize_universal_tx(
     writable_flags: Vec<u8>,
     ix_data: Vec<u8>,
     gas_fee: u64,
+    nonce: u64,
+    nonce_mode: NonceMode,
+    flags: u8,
+    token_legs: Vec<TokenLeg>,
+    cea_index: u16,
     signature: [u8; 64],
     recovery_id: u8,
     message_hash: [u8; 32],
@@ -139,6 +202,8 @@ pub fn finalize_universal_tx(
         push_account,
         &writable_flags,
         &ix_data,
+        flags,
+        &token_legs,
     )?;
 
     let execute_accounts = verify_finalize_tss(
@@ -150,18 +215,34 @@ pub fn finalize_universal_tx(
         &writable_flags,
         &ix_data,
         gas_fee,
+        nonce,
+        nonce_mode,
+        &token_legs,
+        cea_index,
         amount,
         &message_hash,
         &signature,
         recovery_id,
     )?;
 
+    let cea_state_bump = ctx.bumps.cea_state;
+    advance_outbound_nonce(
+        ctx.accounts.cea_state.as_mut(),
+        push_account,
+        cea_index,
+        nonce,
+        nonce_mode,
+        cea_state_bump,
+    )?;
+
     let vault_bump = [ctx.accounts.config.vault_bump];
     let vault_seeds = [VAULT_SEED, &vault_bump[..]];
     let cea_bump = [ctx.bumps.cea_authority];
-    let cea_seeds = [CEA_SEED, push_account.as_ref(), &cea_bump[..]];
+    let cea_index_bytes = cea_index_seed(cea_index);
+    let cea_seeds = [CEA_SEED, push_account.as_ref(), &cea_index_bytes[..], &cea_bump[..]];
 
     stage_assets_to_cea(&ctx, &request, amount, gas_fee, &vault_seeds)?;
+    stage_token_legs_to_cea(&ctx, &token_legs, &vault_seeds)?;
     dispatch_finalize_action(
         &mut ctx,
         &request,
@@ -169,6 +250,7 @@ pub fn finalize_universal_tx(
         amount,
         push_account,
         &ix_data,
+        &token_legs,
         &cea_seeds,
     )?;
 
@@ -181,6 +263,8 @@ pub fn finalize_universal_tx(
         token: request.token,
         amount,
         payload: ix_data,
+        token_legs,
+        cea_index,
     });
 
     Ok(())
@@ -191,15 +275,33 @@ pub fn finalize_universal_tx(
 // ============================================
 
 /// Enforce SPL/SOL account presence based on token type
-fn validate_account_presence(ctx: &Context<FinalizeUniversalTx>, is_native: bool) -> Result<()> {
-    if is_native {
+/// Wrapped native delivery needs the token programs and the native mint, but no vault/CEA ATAs.
+/// Token legs need the token programs even when the primary asset is SOL.
+fn validate_account_presence(
+    ctx: &Context<FinalizeUniversalTx>,
+    is_native: bool,
+    wrap_native: bool,
+    has_token_legs: bool,
+) -> Result<()> {
+    if wrap_native {
+        let mint = ctx.accounts.mint.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+        require!(mint.key() == spl_token::native_mint::ID, GatewayError::InvalidMint);
+        require!(
+            ctx.accounts.vault_ata.is_none()
+                && ctx.accounts.cea_ata.is_none()
+                && ctx.accounts.token_program.is_some()
+                && ctx.accounts.rent.is_some()
+                && ctx.accounts.associated_token_program.is_some(),
+            GatewayError::InvalidAccount
+        );
+    } else if is_native {
         require!(
             ctx.accounts.vault_ata.is_none()
                 && ctx.accounts.cea_ata.is_none()
                 && ctx.accounts.mint.is_none()
-                && ctx.accounts.token_program.is_none()
-                && ctx.accounts.rent.is_none()
-                && ctx.accounts.associated_token_program.is_none(),
+                && ctx.accounts.token_program.is_some() == has_token_legs
+                && ctx.accounts.rent.is_some() == has_token_legs
+                && ctx.accounts.associated_token_program.is_some() == has_token_legs,
             GatewayError::InvalidAccount
         );
     } else {
@@ -224,6 +326,8 @@ fn validate_finalize_request(
     push_account: [u8; 20],
     writable_flags: &[u8],
     ix_data: &[u8],
+    flags: u8,
+    token_legs: &[TokenLeg],
 ) -> Result<FinalizeRequestContext> {
     let is_withdraw = match instruction_id {
         1 => true,
@@ -231,9 +335,24 @@ fn validate_finalize_request(
         _ => return Err(error!(GatewayError::InvalidInstruction)),
     };
 
-    let is_native = ctx.accounts.mint.is_none();
-    let token = ctx.accounts.mint.as_ref().map_or(Pubkey::default(), |m| m.key());
-    validate_account_presence(ctx, is_native)?;
+    // Reject unknown option bits so the signed flags byte has a single meaning.
+    require!(
+        flags & !FINALIZE_FLAGS_SUPPORTED == 0,
+        GatewayError::InvalidInput
+    );
+    let to_token_account = flags & FINALIZE_FLAG_TOKEN_ACCOUNT_RECIPIENT != 0;
+    let wrap_native = flags & FINALIZE_FLAG_WRAP_NATIVE != 0;
+    let sweep_cea = flags & FINALIZE_FLAG_SWEEP_CEA != 0;
+
+    // Wrapped delivery is still a native (vault lamports) withdraw; the mint account is only
+    // passed along to create the recipient's wSOL ATA.
+    let is_native = ctx.accounts.mint.is_none() || wrap_native;
+    let token = if is_native {
+        Pubkey::default()
+    } else {
+        ctx.accounts.mint.as_ref().map_or(Pubkey::default(), |m| m.key())
+    };
+    validate_account_presence(ctx, is_native, wrap_native, !token_legs.is_empty())?;
 
     let target = if is_withdraw {
         let recipient = ctx
@@ -252,8 +371,24 @@ fn validate_finalize_request(
         require!(push_account != [0u8; 20], GatewayError::InvalidInput);
         require!(writable_flags.is_empty(), GatewayError::InvalidInput);
         require!(ix_data.is_empty(), GatewayError::InvalidInput);
+        require!(token_legs.is_empty(), GatewayError::InvalidInput);
+        require!(!sweep_cea, GatewayError::InvalidInput);
 
-        if !is_native {
+        if wrap_native {
+            // recipient is the wallet; recipient_ata is its wSOL ATA
+            require!(!to_token_account, GatewayError::InvalidInput);
+            require!(
+                ctx.accounts.recipient_ata.is_some(),
+                GatewayError::InvalidAccount
+            );
+        } else if to_token_account {
+            // recipient is the signed destination token account; no separate ATA
+            require!(!is_native, GatewayError::InvalidInput);
+            require!(
+                ctx.accounts.recipient_ata.is_none(),
+                GatewayError::InvalidAccount
+            );
+        } else if !is_native {
             require!(
                 ctx.accounts.recipient_ata.is_some(),
                 GatewayError::InvalidAccount
@@ -265,12 +400,33 @@ fn validate_finalize_request(
             GatewayError::InvalidInput
         );
     } else {
+        require!(!to_token_account && !wrap_native, GatewayError::InvalidInput);
         require!(
             ctx.accounts.recipient_ata.is_none(),
             GatewayError::InvalidInput
         );
 
-        let accounts_count = ctx.remaining_accounts.len();
+        // Legs and sweeps only apply around CPI targets; the CEA→UEA path forwards a single asset.
+        if sweep_cea {
+            require!(target != *ctx.program_id, GatewayError::InvalidInput);
+        }
+        if !token_legs.is_empty() {
+            require!(target != *ctx.program_id, GatewayError::InvalidInput);
+            require!(
+                token_legs.iter().all(|leg| leg.amount > 0),
+                GatewayError::InvalidAmount
+            );
+        }
+
+        // Leg accounts lead remaining_accounts and sweep slots trail them; writable_flags cover
+        // only the CPI accounts in between.
+        let leg_accounts_len = token_legs.len() * TOKEN_LEG_ACCOUNTS;
+        let reserved_len = leg_accounts_len + sweep_accounts_len(ctx, flags, token_legs);
+        require!(
+            ctx.remaining_accounts.len() >= reserved_len,
+            GatewayError::InvalidAccount
+        );
+        let accounts_count = ctx.remaining_accounts.len() - reserved_len;
         let expected_writable_flags_len = (accounts_count + 7) / 8;
         require!(
             writable_flags.len() == expected_writable_flags_len,
@@ -283,9 +439,25 @@ fn validate_finalize_request(
         is_native,
         token,
         target,
+        flags,
+        leg_accounts_len: token_legs.len() * TOKEN_LEG_ACCOUNTS,
+        sweep_accounts_len: sweep_accounts_len(ctx, flags, token_legs),
     })
 }
 
+/// One `CEA_CREDIT_ACCOUNTS` slot per asset a sweep may credit: native SOL, the primary mint
+/// (when passed) and each token leg, in that order.
+fn sweep_accounts_len(
+    ctx: &Context<FinalizeUniversalTx>,
+    flags: u8,
+    token_legs: &[TokenLeg],
+) -> usize {
+    if flags & FINALIZE_FLAG_SWEEP_CEA == 0 {
+        return 0;
+    }
+    (1 + usize::from(ctx.accounts.mint.is_some()) + token_legs.len()) * CEA_CREDIT_ACCOUNTS
+}
+
 // ============================================
 //    TSS VALIDATION HELPERS (PHASE 2)
 // ============================================
@@ -299,6 +471,10 @@ fn verify_finalize_tss(
     writable_flags: &[u8],
     ix_data: &[u8],
     gas_fee: u64,
+    nonce: u64,
+    nonce_mode: NonceMode,
+    token_legs: &[TokenLeg],
+    cea_index: u16,
     amount: u64,
     message_hash: &[u8; 32],
     signature: &[u8; 64],
@@ -313,6 +489,10 @@ fn verify_finalize_tss(
             request.token,
             request.target,
             gas_fee,
+            nonce,
+            nonce_mode,
+            request.flags,
+            cea_index,
             amount,
             message_hash,
             signature,
@@ -323,7 +503,7 @@ fn verify_finalize_tss(
 
     let accounts = build_and_validate_tss_execute(
         &mut ctx.accounts.tss_pda,
-        ctx.remaining_accounts,
+        request.cpi_accounts(ctx.remaining_accounts),
         universal_tx_id,
         sub_tx_id,
         push_account,
@@ -332,6 +512,11 @@ fn verify_finalize_tss(
         writable_flags,
         ix_data,
         gas_fee,
+        nonce,
+        nonce_mode,
+        request.flags,
+        cea_index,
+        token_legs,
         amount,
         message_hash,
         signature,
@@ -374,17 +559,18 @@ fn stage_assets_to_cea(
     )
 }
 
-fn dispatch_finalize_action(
-    ctx: &mut Context<FinalizeUniversalTx>,
+fn dispatch_finalize_action<'info>(
+    ctx: &mut Context<'_, '_, 'info, 'info, FinalizeUniversalTx<'info>>,
     request: &FinalizeRequestContext,
     execute_accounts: Option<Vec<GatewayAccountMeta>>,
     amount: u64,
     push_account: [u8; 20],
     ix_data: &[u8],
+    token_legs: &[TokenLeg],
     cea_seeds: &[&[u8]],
 ) -> Result<()> {
     if request.is_withdraw {
-        internal_withdraw(ctx, amount, request.token, cea_seeds)?;
+        internal_withdraw(ctx, amount, request.token, request.flags, cea_seeds)?;
         return Ok(());
     }
 
@@ -413,7 +599,18 @@ fn dispatch_finalize_action(
         data: ix_data.to_vec(),
     };
 
-    invoke_signed(&cpi_ix, ctx.remaining_accounts, &[cea_seeds])?;
+    invoke_signed(
+        &cpi_ix,
+        request.cpi_accounts(ctx.remaining_accounts),
+        &[cea_seeds],
+    )?;
+
+    if request.flags & FINALIZE_FLAG_SWEEP_CEA != 0 {
+        let remaining_accounts = ctx.remaining_accounts;
+        let credit_accounts =
+            &remaining_accounts[remaining_accounts.len() - request.sweep_accounts_len..];
+        sweep_cea_to_vault(ctx, push_account, token_legs, credit_accounts, cea_seeds)?;
+    }
     Ok(())
 }
 
@@ -439,7 +636,11 @@ fn reconstruct_accounts_from_flags<'info>(
 /// 3. push_account (20 bytes)
 /// 4. token (32 bytes)
 /// 5. gas_fee (u64 BE)
-/// 6. target (32 bytes) - withdraw specific
+/// 6. nonce (u64 BE)
+/// 7. nonce_mode (1 byte)
+/// 8. flags (1 byte)
+/// 9. cea_index (u16 BE)
+/// 10. target (32 bytes) - withdraw specific (token account when FINALIZE_FLAG_TOKEN_ACCOUNT_RECIPIENT)
 fn build_and_validate_tss_withdraw(
     tss_pda: &mut Account<TssPda>,
     universal_tx_id: [u8; 32],
@@ -448,18 +649,30 @@ fn build_and_validate_tss_withdraw(
     token: Pubkey,
     target: Pubkey,
     gas_fee: u64,
+    nonce: u64,
+    nonce_mode: NonceMode,
+    flags: u8,
+    cea_index: u16,
     amount: u64,
     message_hash: &[u8; 32],
     signature: &[u8; 64],
     recovery_id: u8,
 ) -> Result<()> {
     let gas_fee_buf = encode_u64_be(gas_fee);
-    let additional: [&[u8]; 6] = [
+    let nonce_buf = encode_u64_be(nonce);
+    let nonce_mode_buf = [nonce_mode as u8];
+    let flags_buf = [flags];
+    let cea_index_buf = cea_index.to_be_bytes();
+    let additional: [&[u8]; 10] = [
         &sub_tx_id,
         &universal_tx_id,
         &push_account,
         &token.to_bytes(),
         &gas_fee_buf,
+        &nonce_buf,
+        &nonce_mode_buf,
+        &flags_buf,
+        &cea_index_buf,
         &target.to_bytes(),
     ];
     validate_message(tss_pda, 1, Some(amount), &additional, message_hash, signature, recovery_id)
@@ -473,9 +686,14 @@ fn build_and_validate_tss_withdraw(
 /// 3. push_account (20 bytes)
 /// 4. token (32 bytes)
 /// 5. gas_fee (u64 BE)
-/// 6. target_program (32 bytes) - execute specific
-/// 7. accounts_buf (variable) - execute specific
-/// 8. ix_data_buf (variable) - execute specific
+/// 6. nonce (u64 BE)
+/// 7. nonce_mode (1 byte)
+/// 8. flags (1 byte)
+/// 9. cea_index (u16 BE)
+/// 10. target_program (32 bytes) - execute specific
+/// 11. accounts_buf (variable) - execute specific
+/// 12. ix_data_buf (variable) - execute specific
+/// 13. token_legs_buf (variable) - execute specific
 fn build_and_validate_tss_execute<'info>(
     tss_pda: &mut Account<TssPda>,
     remaining_accounts: &[AccountInfo<'info>],
@@ -487,6 +705,11 @@ fn build_and_validate_tss_execute<'info>(
     writable_flags: &[u8],
     ix_data: &[u8],
     gas_fee: u64,
+    nonce: u64,
+    nonce_mode: NonceMode,
+    flags: u8,
+    cea_index: u16,
+    token_legs: &[TokenLeg],
     amount: u64,
     message_hash: &[u8; 32],
     signature: &[u8; 64],
@@ -497,22 +720,74 @@ fn build_and_validate_tss_execute<'info>(
 
     let accounts_buf = serialize_gateway_accounts(&accounts);
     let ix_data_buf = serialize_ix_data(ix_data);
+    let token_legs_buf = serialize_token_legs(token_legs);
     let gas_fee_buf = encode_u64_be(gas_fee);
-    let additional: [&[u8]; 8] = [
+    let nonce_buf = encode_u64_be(nonce);
+    let nonce_mode_buf = [nonce_mode as u8];
+    let flags_buf = [flags];
+    let cea_index_buf = cea_index.to_be_bytes();
+    let additional: [&[u8]; 13] = [
         &sub_tx_id,
         &universal_tx_id,
         &push_account,
         &token.to_bytes(),
         &gas_fee_buf,
+        &nonce_buf,
+        &nonce_mode_buf,
+        &flags_buf,
+        &cea_index_buf,
         &target.to_bytes(),
         &accounts_buf,
         &ix_data_buf,
+        &token_legs_buf,
     ];
 
     validate_message(tss_pda, 2, Some(amount), &additional, message_hash, signature, recovery_id)?;
     Ok(accounts)
 }
 
+// ============================================
+//    OUTBOUND ORDERING
+// ============================================
+
+/// Enforce per-CEA outbound ordering and record the consumed nonce.
+/// Strict: nonce == last + 1. Loose: nonce > last. Unordered: no check, and `cea_state` must be
+/// omitted so no state account is created.
+fn advance_outbound_nonce(
+    cea_state: Option<&mut Account<CeaState>>,
+    push_account: [u8; 20],
+    cea_index: u16,
+    nonce: u64,
+    nonce_mode: NonceMode,
+    bump: Option<u8>,
+) -> Result<()> {
+    let cea_state = match (nonce_mode, cea_state) {
+        (NonceMode::Unordered, None) => return Ok(()),
+        (NonceMode::Unordered, Some(_)) | (_, None) => return err!(GatewayError::InvalidAccount),
+        (_, Some(cea_state)) => cea_state,
+    };
+
+    // First use: bind the freshly created state account to its CEA.
+    if cea_state.push_account == [0u8; 20] {
+        cea_state.push_account = push_account;
+        cea_state.cea_index = cea_index;
+        cea_state.bump = bump.ok_or(error!(GatewayError::InvalidAccount))?;
+    }
+
+    if nonce_mode == NonceMode::Strict {
+        let expected = cea_state
+            .last_nonce
+            .checked_add(1)
+            .ok_or(error!(GatewayError::InvalidNonce))?;
+        require!(nonce == expected, GatewayError::InvalidNonce);
+    } else {
+        require!(nonce > cea_state.last_nonce, GatewayError::InvalidNonce);
+    }
+
+    cea_state.last_nonce = nonce;
+    Ok(())
+}
+
 // ============================================
 //    SPL ACCOUNT HELPERS (PHASE 3)
 // ============================================
@@ -546,30 +821,16 @@ fn process_spl_vault_to_cea_transfer<'info>(
     );
 
     // Create CEA ATA if it doesn't exist
-    let cea_ata_info = cea_ata.to_account_info();
-    if cea_ata_info.data_is_empty() {
-        let create_ata_ix =
-            spl_associated_token_account::instruction::create_associated_token_account(
-                &ctx.accounts.caller.key(),
-                &ctx.accounts.cea_authority.key(),
-                &mint.key(),
-                &spl_token::ID,
-            );
-        invoke_signed(
-            &create_ata_ix,
-            &[
-                ctx.accounts.caller.to_account_info(),
-                cea_ata.to_account_info(),
-                ctx.accounts.cea_authority.to_account_info(),
-                mint.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-                token_program.to_account_info(),
-                ata_program.to_account_info(),
-                rent.to_account_info(),
-            ],
-            &[],
-        )?;
-    }
+    create_ata_if_missing(
+        &ctx.accounts.caller.to_account_info(),
+        &cea_ata.to_account_info(),
+        &ctx.accounts.cea_authority.to_account_info(),
+        &mint.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &token_program.to_account_info(),
+        &ata_program.to_account_info(),
+        &rent.to_account_info(),
+    )?;
 
     // Validate existing CEA ATA: mint + owner
     let parsed_cea_ata = parse_token_account(&cea_ata.to_account_info())?;
@@ -589,3 +850,60 @@ fn process_spl_vault_to_cea_transfer<'info>(
 
     Ok(())
 }
+
+/// Move each signed token leg from its vault ATA into the CEA ATA (created if missing).
+/// Leg accounts are the first `TOKEN_LEG_ACCOUNTS * legs.len()` remaining accounts.
+fn stage_token_legs_to_cea<'info>(
+    ctx: &Context<'_, '_, '_, 'info, FinalizeUniversalTx<'info>>,
+    token_legs: &[TokenLeg],
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    if token_legs.is_empty() {
+        return Ok(());
+    }
+
+    // Guaranteed Some by validate_account_presence when legs are present
+    let token_program = ctx.accounts.token_program.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+    let rent = ctx.accounts.rent.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+    let ata_program = ctx.accounts.associated_token_program.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+    let vault_key = ctx.accounts.vault_sol.key();
+    let cea_key = ctx.accounts.cea_authority.key();
+
+    let leg_accounts = ctx.remaining_accounts[..token_legs.len() * TOKEN_LEG_ACCOUNTS]
+        .chunks_exact(TOKEN_LEG_ACCOUNTS);
+    for (leg, accounts) in token_legs.iter().zip(leg_accounts) {
+        let (mint, vault_ata, cea_ata) = (&accounts[0], &accounts[1], &accounts[2]);
+
+        require!(mint.key() == leg.mint, GatewayError::InvalidMint);
+        require!(*mint.owner == spl_token::ID, GatewayError::InvalidMint);
+
+        let parsed_vault_ata = parse_token_account(vault_ata)?;
+        require!(parsed_vault_ata.mint == leg.mint, GatewayError::InvalidMint);
+        require!(parsed_vault_ata.owner == vault_key, GatewayError::InvalidOwner);
+
+        let expected_cea_ata =
+            spl_associated_token_account::get_associated_token_address(&cea_key, &leg.mint);
+        require!(cea_ata.key() == expected_cea_ata, GatewayError::InvalidAccount);
+
+        create_ata_if_missing(
+            &ctx.accounts.caller.to_account_info(),
+            cea_ata,
+            &ctx.accounts.cea_authority.to_account_info(),
+            mint,
+            &ctx.accounts.system_program.to_account_info(),
+            &token_program.to_account_info(),
+            &ata_program.to_account_info(),
+            &rent.to_account_info(),
+        )?;
+
+        pda_spl_transfer(
+            vault_ata,
+            cea_ata,
+            &ctx.accounts.vault_sol.to_account_info(),
+            leg.amount,
+            vault_seeds,
+        )?;
+    }
+
+    Ok(())
+}
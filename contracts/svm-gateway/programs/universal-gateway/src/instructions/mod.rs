@@ -4,6 +4,7 @@ pub mod execute;
 pub mod initialize;
 pub mod rescue;
 pub mod revert;
+pub mod sweep;
 pub mod tss;
 pub mod withdraw;
 
@@ -13,5 +14,6 @@ pub use execute::*;
 pub use initialize::*;
 pub use rescue::*;
 pub use revert::*;
+pub use sweep::*;
 pub use tss::*;
 pub use withdraw::*;
@@ -0,0 +1,200 @@
+use crate::instructions::tss::validate_message;
+use crate::instructions::withdraw::{consume_cea_credit_limits, emit_cea_credit};
+use crate::utils::{encode_u64_be, parse_token_account, pda_spl_transfer, pda_system_transfer, transfer_gas_fee_to_caller};
+use crate::{errors::*, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token};
+
+// =========================
+//   TSS CEA SWEEP FUNCTION
+// =========================
+// Recovers dust left in a CEA: lamports held by the CEA PDA and the signed list of CEA token
+// accounts. Token balances go to the matching vault ATA, the token accounts are closed with
+// their rent sent to the vault, and the remaining CEA lamports are moved to the vault.
+// Token balances and CEA lamports are credited to the UEA via `UniversalTx` (from_cea: true),
+// consuming rate limits like any other credit. The rent is not credited: finalize reimburses
+// ATA creation from the vault through gas_fee, so the rent stays there.
+//
+// TSS message format (instruction_id = 5, no amount field):
+//   [sub_tx_id, universal_tx_id, push_account, cea_index, gas_fee, token_accounts_buf]
+//   token_accounts_buf = [u32 BE count][pubkey(32)] × N
+//
+// remaining_accounts: [cea_token_account, vault_ata, token_rate_limit, oracle, oracle_price_update]
+// per signed token account, in order, then one native SOL slot
+// [token_rate_limit, oracle, oracle_price_update] (`CEA_CREDIT_ACCOUNTS`).
+
+/// Number of remaining accounts consumed by each swept CEA token account.
+pub const SWEEP_TOKEN_ACCOUNTS: usize = 2 + CEA_CREDIT_ACCOUNTS;
+
+#[derive(Accounts)]
+#[instruction(sub_tx_id: [u8; 32], universal_tx_id: [u8; 32], push_account: [u8; 20], cea_index: u16)]
+pub struct SweepCea<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ GatewayError::Paused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Vault SOL PDA — receives the swept lamports and the closed accounts' rent.
+    #[account(mut, seeds = [VAULT_SEED], bump = config.vault_bump)]
+    pub vault_sol: SystemAccount<'info>,
+
+    /// CEA being swept — signs the token transfers and closes via invoke_signed.
+    #[account(
+        mut,
+        seeds = [CEA_SEED, push_account.as_ref(), &cea_index_seed(cea_index)[..]],
+        bump,
+    )]
+    pub cea_authority: SystemAccount<'info>,
+
+    #[account(mut, seeds = [TSS_SEED], bump = tss_pda.bump)]
+    pub tss_pda: Account<'info, TssPda>,
+
+    /// Epoch USD cap consumed by the credits.
+    #[account(
+        mut,
+        seeds = [RATE_LIMIT_CONFIG_SEED],
+        bump,
+    )]
+    pub rate_limit_config: Account<'info, RateLimitConfig>,
+
+    /// Replay protection (shared namespace with finalize / revert / rescue).
+    #[account(
+        init,
+        payer = caller,
+        space = ExecutedSubTx::LEN,
+        seeds = [EXECUTED_SUB_TX_SEED, &sub_tx_id],
+        bump
+    )]
+    pub executed_sub_tx: Account<'info, ExecutedSubTx>,
+
+    /// The caller/relayer — pays transaction fees, receives gas_fee reimbursement from the vault.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn sweep_cea<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SweepCea<'info>>,
+    sub_tx_id: [u8; 32],
+    universal_tx_id: [u8; 32],
+    push_account: [u8; 20],
+    cea_index: u16,
+    token_accounts: Vec<Pubkey>,
+    gas_fee: u64,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+) -> Result<()> {
+    require!(push_account != [0u8; 20], GatewayError::InvalidInput);
+    require!(
+        ctx.remaining_accounts.len()
+            == token_accounts.len() * SWEEP_TOKEN_ACCOUNTS + CEA_CREDIT_ACCOUNTS,
+        GatewayError::AccountListLengthMismatch
+    );
+
+    // TSS message: instruction_id=5 || [sub_tx_id, universal_tx_id, push_account, cea_index, gas_fee, token_accounts_buf]
+    let cea_index_buf = cea_index.to_be_bytes();
+    let gas_fee_buf = encode_u64_be(gas_fee);
+    let mut token_accounts_buf = Vec::with_capacity(4 + token_accounts.len() * 32);
+    token_accounts_buf.extend_from_slice(&(token_accounts.len() as u32).to_be_bytes());
+    for account in &token_accounts {
+        token_accounts_buf.extend_from_slice(&account.to_bytes());
+    }
+    let additional: [&[u8]; 6] = [
+        &sub_tx_id,
+        &universal_tx_id,
+        &push_account,
+        &cea_index_buf,
+        &gas_fee_buf,
+        &token_accounts_buf,
+    ];
+    validate_message(&mut ctx.accounts.tss_pda, 5, None, &additional, &message_hash, &signature, recovery_id)?;
+
+    let cea_bump = [ctx.bumps.cea_authority];
+    let cea_index_bytes = cea_index_seed(cea_index);
+    let cea_seeds: &[&[u8]] = &[CEA_SEED, push_account.as_ref(), &cea_index_bytes[..], &cea_bump[..]];
+    let cea = ctx.accounts.cea_authority.to_account_info();
+    let vault = ctx.accounts.vault_sol.to_account_info();
+    let vault_key = vault.key();
+    let remaining_accounts = ctx.remaining_accounts;
+    let (token_slots, native_slot) =
+        remaining_accounts.split_at(token_accounts.len() * SWEEP_TOKEN_ACCOUNTS);
+
+    for (signed, accounts) in token_accounts
+        .iter()
+        .zip(token_slots.chunks_exact(SWEEP_TOKEN_ACCOUNTS))
+    {
+        let (cea_token_account, vault_ata) = (&accounts[0], &accounts[1]);
+        require!(cea_token_account.key() == *signed, GatewayError::AccountPubkeyMismatch);
+
+        let parsed = parse_token_account(cea_token_account)?;
+        require!(parsed.owner == cea.key(), GatewayError::InvalidOwner);
+        let parsed_vault_ata = parse_token_account(vault_ata)?;
+        require!(parsed_vault_ata.mint == parsed.mint, GatewayError::InvalidMint);
+        require!(parsed_vault_ata.owner == vault_key, GatewayError::InvalidOwner);
+
+        if parsed.amount > 0 {
+            pda_spl_transfer(cea_token_account, vault_ata, &cea, parsed.amount, cea_seeds)?;
+            consume_cea_credit_limits(
+                &mut ctx.accounts.rate_limit_config,
+                &accounts[2..],
+                parsed.mint,
+                parsed.amount,
+            )?;
+            emit_cea_credit(&cea, push_account, parsed.mint, parsed.amount);
+        }
+
+        // Rent of the closed account lands in the vault and is not credited.
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: cea_token_account.clone(),
+                destination: vault.clone(),
+                authority: cea.clone(),
+            },
+            &[cea_seeds],
+        ))?;
+    }
+
+    let cea_lamports = cea.lamports();
+    pda_system_transfer(
+        &cea,
+        &vault,
+        &ctx.accounts.system_program.to_account_info(),
+        cea_lamports,
+        cea_seeds,
+    )?;
+    if cea_lamports > 0 {
+        consume_cea_credit_limits(
+            &mut ctx.accounts.rate_limit_config,
+            native_slot,
+            Pubkey::default(),
+            cea_lamports,
+        )?;
+        emit_cea_credit(&cea, push_account, Pubkey::default(), cea_lamports);
+    }
+
+    transfer_gas_fee_to_caller(
+        &vault,
+        &ctx.accounts.caller.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        gas_fee,
+        ctx.accounts.config.vault_bump,
+    )?;
+
+    emit!(CeaSwept {
+        sub_tx_id,
+        universal_tx_id,
+        push_account,
+        token_accounts,
+        lamports: cea_lamports,
+        gas_fee,
+    });
+
+    Ok(())
+}
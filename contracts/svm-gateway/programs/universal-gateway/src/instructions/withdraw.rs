@@ -1,20 +1,33 @@
 use crate::errors::GatewayError;
 use crate::instructions::execute::FinalizeUniversalTx;
-use crate::state::{TxType, UniversalTx};
+use crate::state::{
+    OracleRegistry, RateLimitConfig, TokenLeg, TokenRateLimit, TxType, UniversalTx,
+    CEA_CREDIT_ACCOUNTS, FINALIZE_FLAG_TOKEN_ACCOUNT_RECIPIENT, FINALIZE_FLAG_WRAP_NATIVE,
+    MAX_CEA_PAYLOAD_LEN, TOKEN_LEG_ACCOUNTS,
+};
 use crate::utils::{
-    parse_token_account, pda_spl_transfer, pda_system_transfer, validate_token_and_consume_rate_limit,
+    calculate_usd_amount, check_block_usd_cap, check_price_deviation, check_usd_caps,
+    create_ata_if_missing, optional_slot_account, oracle_usd_amount, parse_token_account,
+    pda_spl_transfer, pda_system_transfer, resolve_sol_price, validate_token_and_consume_rate_limit,
 };
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::{hash::hash, program::invoke};
 use anchor_spl::associated_token::spl_associated_token_account;
+use anchor_spl::token::spl_token;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 /// Transfer funds from CEA to recipient (withdraw mode).
 /// SOL: system transfer CEA -> recipient.
-/// SPL: token transfer CEA ATA -> recipient ATA.
+/// SOL with FINALIZE_FLAG_WRAP_NATIVE: system transfer CEA -> recipient wSOL ATA + sync_native.
+/// SPL: token transfer CEA ATA -> recipient ATA (created if missing, rent paid by the relayer
+///      and covered by the signed gas_fee).
+/// SPL with FINALIZE_FLAG_TOKEN_ACCOUNT_RECIPIENT: token transfer CEA ATA -> `recipient` as an
+///      arbitrary token account signed by the TSS (custodians, exchanges, program-owned vaults).
 pub fn internal_withdraw(
     ctx: &Context<FinalizeUniversalTx>,
     amount: u64,
     token: Pubkey,
+    flags: u8,
     cea_seeds: &[&[u8]],
 ) -> Result<()> {
     let recipient = ctx
@@ -30,7 +43,9 @@ pub fn internal_withdraw(
         return Ok(());
     }
 
-    if is_native {
+    if is_native && flags & FINALIZE_FLAG_WRAP_NATIVE != 0 {
+        withdraw_native_as_wsol(ctx, amount, cea_seeds)?;
+    } else if is_native {
         pda_system_transfer(
             &ctx.accounts.cea_authority.to_account_info(),
             &recipient.to_account_info(),
@@ -38,6 +53,8 @@ pub fn internal_withdraw(
             amount,
             cea_seeds,
         )?;
+    } else if flags & FINALIZE_FLAG_TOKEN_ACCOUNT_RECIPIENT != 0 {
+        withdraw_to_token_account(ctx, amount, cea_seeds)?;
     } else {
         let cea_ata = ctx
             .accounts
@@ -59,6 +76,27 @@ pub fn internal_withdraw(
             spl_associated_token_account::get_associated_token_address(&target, &token_mint.key());
         require!(recipient_ata.key() == expected_recipient_ata, GatewayError::InvalidAccount);
 
+        // Account presence is enforced by validate_account_presence for SPL routes.
+        let token_program = ctx.accounts.token_program.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+        let rent = ctx.accounts.rent.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+        let ata_program = ctx.accounts.associated_token_program.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+
+        create_ata_if_missing(
+            &ctx.accounts.caller.to_account_info(),
+            &recipient_ata.to_account_info(),
+            &recipient.to_account_info(),
+            &token_mint.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &token_program.to_account_info(),
+            &ata_program.to_account_info(),
+            &rent.to_account_info(),
+        )?;
+
+        // Validate existing recipient ATA: mint + owner
+        let parsed_recipient_ata = parse_token_account(&recipient_ata.to_account_info())?;
+        require!(parsed_recipient_ata.mint == token_mint.key(), GatewayError::InvalidMint);
+        require!(parsed_recipient_ata.owner == target, GatewayError::InvalidOwner);
+
         pda_spl_transfer(
             &cea_ata.to_account_info(),
             &recipient_ata.to_account_info(),
@@ -71,18 +109,153 @@ pub fn internal_withdraw(
     Ok(())
 }
 
+/// Native withdraw delivered as wSOL: lamports go CEA -> recipient's wSOL ATA, then sync_native.
+/// Works for recipients that cannot hold plain lamports (below rent-exempt minimum, program-owned data accounts).
+fn withdraw_native_as_wsol(
+    ctx: &Context<FinalizeUniversalTx>,
+    amount: u64,
+    cea_seeds: &[&[u8]],
+) -> Result<()> {
+    let recipient = ctx
+        .accounts
+        .recipient
+        .as_ref()
+        .ok_or(error!(GatewayError::InvalidAccount))?;
+    let recipient_ata = ctx
+        .accounts
+        .recipient_ata
+        .as_ref()
+        .ok_or(error!(GatewayError::InvalidAccount))?;
+    let native_mint = ctx.accounts.mint.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+    let token_program = ctx.accounts.token_program.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+    let rent = ctx.accounts.rent.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+    let ata_program = ctx.accounts.associated_token_program.as_ref().ok_or(error!(GatewayError::InvalidAccount))?;
+
+    let expected_recipient_ata = spl_associated_token_account::get_associated_token_address(
+        &recipient.key(),
+        &spl_token::native_mint::ID,
+    );
+    require!(recipient_ata.key() == expected_recipient_ata, GatewayError::InvalidAccount);
+
+    create_ata_if_missing(
+        &ctx.accounts.caller.to_account_info(),
+        &recipient_ata.to_account_info(),
+        &recipient.to_account_info(),
+        &native_mint.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &token_program.to_account_info(),
+        &ata_program.to_account_info(),
+        &rent.to_account_info(),
+    )?;
+
+    let parsed_recipient_ata = parse_token_account(&recipient_ata.to_account_info())?;
+    require!(parsed_recipient_ata.mint == spl_token::native_mint::ID, GatewayError::InvalidMint);
+    require!(parsed_recipient_ata.owner == recipient.key(), GatewayError::InvalidOwner);
+    require!(!parsed_recipient_ata.is_frozen(), GatewayError::TokenAccountFrozen);
+
+    pda_system_transfer(
+        &ctx.accounts.cea_authority.to_account_info(),
+        &recipient_ata.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+        cea_seeds,
+    )?;
+
+    let sync_ix = spl_token::instruction::sync_native(&spl_token::ID, &recipient_ata.key())?;
+    invoke(&sync_ix, &[recipient_ata.to_account_info(), token_program.to_account_info()])?;
+
+    Ok(())
+}
+
+/// SPL withdraw to a TSS-signed destination token account (no ATA derivation).
+/// The signed target is the token account itself; only its mint and frozen state are checked.
+fn withdraw_to_token_account(
+    ctx: &Context<FinalizeUniversalTx>,
+    amount: u64,
+    cea_seeds: &[&[u8]],
+) -> Result<()> {
+    let destination = ctx
+        .accounts
+        .recipient
+        .as_ref()
+        .ok_or(error!(GatewayError::InvalidAccount))?;
+    let cea_ata = ctx
+        .accounts
+        .cea_ata
+        .as_ref()
+        .ok_or(error!(GatewayError::InvalidAccount))?;
+    let token_mint = ctx
+        .accounts
+        .mint
+        .as_ref()
+        .ok_or(error!(GatewayError::InvalidAccount))?;
+
+    let destination_info = destination.to_account_info();
+    require!(destination_info.owner == &spl_token::ID, GatewayError::InvalidOwner);
+
+    let parsed_destination = parse_token_account(&destination_info)?;
+    require!(parsed_destination.mint == token_mint.key(), GatewayError::InvalidMint);
+    require!(!parsed_destination.is_frozen(), GatewayError::TokenAccountFrozen);
+
+    pda_spl_transfer(
+        &cea_ata.to_account_info(),
+        &destination_info,
+        &ctx.accounts.cea_authority.to_account_info(),
+        amount,
+        cea_seeds,
+    )
+}
+
 /// Args for the CEA -> UEA inbound route (target_program == gateway itself).
 /// Layout: [8-byte discriminator][borsh(SendUniversalTxToUEAArgs)].
+/// The TSS signs these bytes, so `decode` also accepts the original layout
+/// (`SendUniversalTxToUEAArgsV1`, without `gas_amount`).
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct SendUniversalTxToUEAArgs {
     pub token: Pubkey,
     pub amount: u64,
     pub payload: Vec<u8>,
     pub revert_recipient: Pubkey,
+    /// Optional native SOL (lamports) taken from the CEA to top up UEA gas.
+    /// Subject to the same USD caps and block USD cap as the inbound GAS route.
+    pub gas_amount: Option<u64>,
+}
+
+/// Original CEA -> UEA args layout, before the gas leg was added.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SendUniversalTxToUEAArgsV1 {
+    pub token: Pubkey,
+    pub amount: u64,
+    pub payload: Vec<u8>,
+    pub revert_recipient: Pubkey,
+}
+
+impl SendUniversalTxToUEAArgs {
+    /// Decode either layout. Both must consume the whole buffer, so a V1 buffer never parses
+    /// as the current layout and vice versa. V1 decodes with `gas_amount: None`.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        if let Ok(args) = Self::try_from_slice(data) {
+            return Ok(args);
+        }
+        let legacy = SendUniversalTxToUEAArgsV1::try_from_slice(data)
+            .map_err(|_| error!(GatewayError::InvalidInput))?;
+        Ok(Self {
+            token: legacy.token,
+            amount: legacy.amount,
+            payload: legacy.payload,
+            revert_recipient: legacy.revert_recipient,
+            gas_amount: None,
+        })
+    }
 }
 
 /// CEA -> UEA inbound route: mirrors the inbound FUNDS deposit flow.
 /// Called when target_program == gateway itself.
+/// `args.amount == 0` sends a payload-only message (GAS_AND_PAYLOAD semantics): no funds move,
+/// no rate limit is consumed, and the payload is capped at MAX_CEA_PAYLOAD_LEN.
+/// `args.gas_amount` adds a native gas leg, emitted as its own `UniversalTx` (TxType::Gas)
+/// before the funds / payload event. With a gas leg, amount == 0 and an empty payload is a
+/// pure gas top-up.
 pub fn send_universal_tx_to_uea(
     ctx: &mut Context<FinalizeUniversalTx>,
     push_account: [u8; 20],
@@ -101,13 +274,40 @@ pub fn send_universal_tx_to_uea(
     let expected = hash(b"global:send_universal_tx_to_uea").to_bytes();
     require!(discr == &expected[..8], GatewayError::InvalidInput);
 
-    let args = SendUniversalTxToUEAArgs::try_from_slice(&ix_data[8..])
-        .map_err(|_| error!(GatewayError::InvalidInput))?;
+    let args = SendUniversalTxToUEAArgs::decode(&ix_data[8..])?;
 
     require!(args.token == token, GatewayError::InvalidMint);
-    require!(args.amount > 0, GatewayError::InvalidAmount);
     require!(args.revert_recipient != Pubkey::default(), GatewayError::InvalidRecipient);
 
+    if let Some(gas_amount) = args.gas_amount {
+        send_cea_gas_to_uea(ctx, push_account, gas_amount, &args.revert_recipient, cea_seeds)?;
+    }
+
+    if args.amount == 0 {
+        if args.payload.is_empty() {
+            require!(args.gas_amount.is_some(), GatewayError::InvalidAmount);
+            return Ok(());
+        }
+        require!(
+            args.payload.len() <= MAX_CEA_PAYLOAD_LEN,
+            GatewayError::PayloadTooLarge
+        );
+
+        emit!(UniversalTx {
+            sender: ctx.accounts.cea_authority.key(),
+            recipient: push_account,
+            token,
+            amount: 0,
+            payload: args.payload,
+            revert_recipient: args.revert_recipient,
+            tx_type: TxType::GasAndPayload,
+            signature_data: vec![],
+            from_cea: true,
+        });
+
+        return Ok(());
+    }
+
     if token == Pubkey::default() {
         require!(
             args.amount <= ctx.accounts.cea_authority.lamports(),
@@ -129,10 +329,16 @@ pub fn send_universal_tx_to_uea(
 
     let withdraw_amount = args.amount;
 
+    let usd_amount = oracle_usd_amount(
+        &token,
+        withdraw_amount,
+        ctx.accounts.oracle.as_ref(),
+        ctx.accounts.oracle_price_update.as_ref(),
+    )?;
     let rl_config = ctx
         .accounts
         .rate_limit_config
-        .as_ref()
+        .as_mut()
         .ok_or(error!(GatewayError::InvalidAccount))?;
     let token_rate_limit = ctx
         .accounts
@@ -144,6 +350,7 @@ pub fn send_universal_tx_to_uea(
         token_rate_limit,
         token,
         withdraw_amount as u128,
+        usd_amount,
         rl_config,
     )?;
 
@@ -196,3 +403,251 @@ pub fn send_universal_tx_to_uea(
 
     Ok(())
 }
+
+/// Gas leg of the CEA -> UEA route: mirrors `send_tx_with_gas_route` with the CEA as sender.
+/// Enforces the USD caps and block USD cap via Pyth, moves the lamports CEA -> vault and
+/// emits a separate `UniversalTx` (TxType::Gas, from_cea: true).
+fn send_cea_gas_to_uea(
+    ctx: &mut Context<FinalizeUniversalTx>,
+    push_account: [u8; 20],
+    gas_amount: u64,
+    revert_recipient: &Pubkey,
+    cea_seeds: &[&[u8]],
+) -> Result<()> {
+    require!(gas_amount > 0, GatewayError::InvalidAmount);
+    require!(
+        gas_amount <= ctx.accounts.cea_authority.lamports(),
+        GatewayError::InsufficientBalance
+    );
+
+    let rate_limit_config = ctx
+        .accounts
+        .rate_limit_config
+        .as_mut()
+        .ok_or(error!(GatewayError::InvalidAccount))?;
+
+    // USD caps: min $1, max $10 (enforced via Pyth oracle)
+    // Same price policy as GAS deposits: primary feed, then secondaries / fallback.
+    let price_data = resolve_sol_price(
+        &ctx.accounts.config,
+        ctx.accounts.sol_oracle.as_ref(),
+        ctx.accounts.price_update.as_ref(),
+        ctx.accounts.price_sources.as_ref(),
+        [
+            ctx.accounts.secondary_price_update_1.as_ref(),
+            ctx.accounts.secondary_price_update_2.as_ref(),
+        ],
+    )?;
+    check_usd_caps(&ctx.accounts.config, gas_amount, &price_data)?;
+    check_price_deviation(&mut ctx.accounts.config, &price_data)?;
+    let usd_amount = calculate_usd_amount(gas_amount, &price_data)?;
+    // Block-based USD cap: per-slot limit (disabled if block_usd_cap == 0)
+    check_block_usd_cap(rate_limit_config, usd_amount)?;
+
+    pda_system_transfer(
+        &ctx.accounts.cea_authority.to_account_info(),
+        &ctx.accounts.vault_sol.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        gas_amount,
+        cea_seeds,
+    )?;
+
+    emit!(UniversalTx {
+        sender: ctx.accounts.cea_authority.key(),
+        recipient: push_account,
+        token: Pubkey::default(),
+        amount: gas_amount,
+        payload: vec![],
+        revert_recipient: *revert_recipient,
+        tx_type: TxType::Gas,
+        signature_data: vec![],
+        from_cea: true,
+    });
+
+    Ok(())
+}
+
+/// Post-execute sweep (FINALIZE_FLAG_SWEEP_CEA): return whatever the target left in the CEA
+/// to the vault and credit the UEA, one `UniversalTx` (from_cea: true) per non-empty asset.
+/// Covers CEA lamports, the primary CEA ATA and every token leg's CEA ATA. The ATAs were
+/// validated when the assets were staged earlier in the same finalize.
+/// Each credit consumes its token's rate limit and the epoch USD cap through the matching
+/// `credit_accounts` slot (`CEA_CREDIT_ACCOUNTS`: native SOL, primary mint, legs).
+pub fn sweep_cea_to_vault<'info>(
+    ctx: &mut Context<'_, '_, 'info, 'info, FinalizeUniversalTx<'info>>,
+    push_account: [u8; 20],
+    token_legs: &[TokenLeg],
+    credit_accounts: &'info [AccountInfo<'info>],
+    cea_seeds: &[&[u8]],
+) -> Result<()> {
+    // Anchor persists the named token_rate_limit on exit, which would overwrite a slot's update.
+    require!(ctx.accounts.token_rate_limit.is_none(), GatewayError::InvalidAccount);
+    let cea = ctx.accounts.cea_authority.to_account_info();
+
+    let residual_lamports = cea.lamports();
+    if residual_lamports > 0 {
+        pda_system_transfer(
+            &cea,
+            &ctx.accounts.vault_sol.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            residual_lamports,
+            cea_seeds,
+        )?;
+    }
+    let mut credits = vec![(Pubkey::default(), residual_lamports)];
+
+    if let Some(mint) = ctx.accounts.mint.as_ref() {
+        let residual = match (ctx.accounts.vault_ata.as_ref(), ctx.accounts.cea_ata.as_ref()) {
+            (Some(vault_ata), Some(cea_ata)) => sweep_cea_ata(
+                ctx,
+                &cea_ata.to_account_info(),
+                &vault_ata.to_account_info(),
+                cea_seeds,
+            )?,
+            _ => 0,
+        };
+        credits.push((mint.key(), residual));
+    }
+
+    let leg_accounts = ctx.remaining_accounts[..token_legs.len() * TOKEN_LEG_ACCOUNTS]
+        .chunks_exact(TOKEN_LEG_ACCOUNTS);
+    for (leg, accounts) in token_legs.iter().zip(leg_accounts) {
+        // [mint, vault_ata, cea_ata]
+        let residual = sweep_cea_ata(ctx, &accounts[2], &accounts[1], cea_seeds)?;
+        credits.push((leg.mint, residual));
+    }
+
+    require!(
+        credit_accounts.len() == credits.len() * CEA_CREDIT_ACCOUNTS,
+        GatewayError::AccountListLengthMismatch
+    );
+    let rate_limit_config = ctx
+        .accounts
+        .rate_limit_config
+        .as_mut()
+        .ok_or(error!(GatewayError::InvalidAccount))?;
+    for ((mint, amount), slot) in credits
+        .into_iter()
+        .zip(credit_accounts.chunks_exact(CEA_CREDIT_ACCOUNTS))
+    {
+        if amount == 0 {
+            continue;
+        }
+        consume_cea_credit_limits(rate_limit_config, slot, mint, amount)?;
+        emit_cea_credit(&cea, push_account, mint, amount);
+    }
+
+    Ok(())
+}
+
+/// Move a CEA ATA's balance to the vault ATA; returns the amount moved.
+fn sweep_cea_ata<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, FinalizeUniversalTx<'info>>,
+    cea_ata: &AccountInfo<'info>,
+    vault_ata: &AccountInfo<'info>,
+    cea_seeds: &[&[u8]],
+) -> Result<u64> {
+    let residual = parse_token_account(cea_ata)?.amount;
+    if residual > 0 {
+        pda_spl_transfer(
+            cea_ata,
+            vault_ata,
+            &ctx.accounts.cea_authority.to_account_info(),
+            residual,
+            cea_seeds,
+        )?;
+    }
+    Ok(residual)
+}
+
+/// Consume the limits of a swept CEA credit, as `send_universal_tx_to_uea` does for the same
+/// CEA -> UEA credit: the token's epoch rate limit and the global epoch USD cap.
+/// `slot` is one `CEA_CREDIT_ACCOUNTS` entry. Shared with the `sweep_cea` instruction.
+pub fn consume_cea_credit_limits<'info>(
+    rate_limit_config: &mut Account<'info, RateLimitConfig>,
+    slot: &'info [AccountInfo<'info>],
+    mint: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    require!(slot[0].is_writable, GatewayError::InvalidAccount);
+    let mut token_rate_limit = Account::<TokenRateLimit>::try_from(&slot[0])?;
+    let oracle = optional_slot_account::<OracleRegistry>(&slot[1])?;
+    let oracle_price_update = optional_slot_account::<PriceUpdateV2>(&slot[2])?;
+
+    let usd_amount =
+        oracle_usd_amount(&mint, amount, oracle.as_ref(), oracle_price_update.as_ref())?;
+    validate_token_and_consume_rate_limit(
+        &mut token_rate_limit,
+        mint,
+        amount as u128,
+        usd_amount,
+        rate_limit_config,
+    )?;
+    // Accounts loaded from remaining_accounts are not persisted by Anchor.
+    token_rate_limit.exit(&crate::ID)
+}
+
+/// Credit swept CEA funds to the UEA (Funds, no payload). Shared with the `sweep_cea` instruction.
+pub fn emit_cea_credit(cea: &AccountInfo, push_account: [u8; 20], token: Pubkey, amount: u64) {
+    let cea_key = cea.key();
+    emit!(UniversalTx {
+        sender: cea_key,
+        recipient: push_account,
+        token,
+        amount,
+        payload: vec![],
+        // No user-supplied revert target on a sweep; a reverted credit goes back to the CEA.
+        revert_recipient: cea_key,
+        tx_type: TxType::Funds,
+        signature_data: vec![],
+        from_cea: true,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v1_bytes() -> Vec<u8> {
+        SendUniversalTxToUEAArgsV1 {
+            token: Pubkey::default(),
+            amount: 42,
+            payload: vec![1, 2, 3],
+            revert_recipient: Pubkey::new_unique(),
+        }
+        .try_to_vec()
+        .unwrap()
+    }
+
+    #[test]
+    fn decode_none_gas_amount() {
+        let mut data = v1_bytes();
+        data.push(0); // Borsh `None`
+        let args = SendUniversalTxToUEAArgs::decode(&data).unwrap();
+        assert_eq!(args.amount, 42);
+        assert_eq!(args.payload, vec![1, 2, 3]);
+        assert_eq!(args.gas_amount, None);
+    }
+
+    #[test]
+    fn decode_some_gas_amount() {
+        let mut data = v1_bytes();
+        data.push(1);
+        data.extend_from_slice(&7u64.to_le_bytes());
+        assert_eq!(SendUniversalTxToUEAArgs::decode(&data).unwrap().gas_amount, Some(7));
+    }
+
+    #[test]
+    fn decode_legacy_layout() {
+        let args = SendUniversalTxToUEAArgs::decode(&v1_bytes()).unwrap();
+        assert_eq!(args.amount, 42);
+        assert_eq!(args.gas_amount, None);
+    }
+
+    #[test]
+    fn decode_rejects_trailing_bytes() {
+        let mut data = v1_bytes();
+        data.extend_from_slice(&[0, 0]);
+        assert!(SendUniversalTxToUEAArgs::decode(&data).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+#[cfg(feature = "cpi")]
+pub mod gateway_cpi;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -28,6 +30,74 @@ pub mod universal_gateway {
         instructions::deposit::send_universal_tx(ctx, req, native_amount)
     }
 
+    /// @notice CPI-composable deposit: `sender` (e.g. a program PDA) is the logical sender,
+    ///         `user` is the funding payer. See `gateway_cpi` for a typed caller-side helper.
+    pub fn send_universal_tx_cpi(
+        ctx: Context<SendUniversalTx>,
+        req: UniversalTxRequest,
+        native_amount: u64,
+    ) -> Result<()> {
+        instructions::deposit::send_universal_tx_cpi(ctx, req, native_amount)
+    }
+
+    /// @notice Sponsored SPL deposit: `user` pays fees, the depositor's tokens are pulled via the
+    ///         SPL delegate approval granted to the `deposit_delegate` PDA.
+    pub fn send_universal_tx_sponsored(
+        ctx: Context<SendUniversalTx>,
+        req: UniversalTxRequest,
+        native_amount: u64,
+    ) -> Result<()> {
+        instructions::deposit::send_universal_tx_sponsored(ctx, req, native_amount)
+    }
+
+    /// @notice Fund Push gas with SPL tokens (EVM `swapToNative` parity): swaps through the
+    ///         allowlisted adapter with a min-out bound, then routes the lamports as GAS.
+    pub fn send_universal_tx_swap_gas<'info>(
+        ctx: Context<'_, '_, '_, 'info, SendUniversalTx<'info>>,
+        req: UniversalTxRequest,
+        amount_in: u64,
+        min_amount_out: u64,
+        native_amount: u64,
+    ) -> Result<()> {
+        instructions::deposit::send_universal_tx_swap_gas(
+            ctx,
+            req,
+            amount_in,
+            min_amount_out,
+            native_amount,
+        )
+    }
+
+    /// @notice Pay Push gas with an allowlisted stable SPL token (pegged or Pyth-priced),
+    ///         under the same USD caps and block USD cap as SOL gas.
+    pub fn send_universal_tx_gas_token(
+        ctx: Context<SendUniversalTx>,
+        req: UniversalTxRequest,
+        native_amount: u64,
+    ) -> Result<()> {
+        instructions::deposit::send_universal_tx_gas_token(ctx, req, native_amount)
+    }
+
+    /// @notice Batched deposits: one `UniversalTx` per request, per-request native amounts.
+    /// @dev    Per-request rate-limit, oracle (and SPL token) accounts come in remaining_accounts.
+    pub fn send_universal_tx_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SendUniversalTx<'info>>,
+        reqs: Vec<UniversalTxRequest>,
+        native_amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::deposit::send_universal_tx_batch(ctx, reqs, native_amounts)
+    }
+
+    /// @notice Multi-token deposit: locks every leg atomically and emits one `UniversalTxBundle`.
+    /// @dev    Per-leg rate-limit, oracle (and SPL token) accounts come in remaining_accounts.
+    pub fn send_universal_tx_bundle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SendUniversalTx<'info>>,
+        req: UniversalTxBundleRequest,
+        native_amount: u64,
+    ) -> Result<()> {
+        instructions::deposit::send_universal_tx_bundle(ctx, req, native_amount)
+    }
+
     // =========================
     //           ADMIN
     // =========================
@@ -83,9 +153,48 @@ pub mod universal_gateway {
         instructions::admin::set_protocol_fee(ctx, fee_lamports)
     }
 
-    /// @notice Set Pyth price feed
-    pub fn set_pyth_price_feed(ctx: Context<AdminAction>, price_feed: Pubkey) -> Result<()> {
-        instructions::admin::set_pyth_price_feed(ctx, price_feed)
+    /// @notice Set a USD-priced protocol fee (8 decimals, 0 disables) with lamport clamps.
+    /// Not gated by `!config.paused`, like `set_protocol_fee`.
+    pub fn set_protocol_fee_usd(
+        ctx: Context<FeeVaultAdminAction>,
+        fee_usd: u64,
+        min_fee_lamports: u64,
+        max_fee_lamports: u64,
+    ) -> Result<()> {
+        instructions::admin::set_protocol_fee_usd(ctx, fee_usd, min_fee_lamports, max_fee_lamports)
+    }
+
+    /// @notice Set per-route lamport fees (gas / funds / payload add-on); replace the flat fee while enabled
+    pub fn set_route_fees(
+        ctx: Context<FeeVaultAdminAction>,
+        enabled: bool,
+        gas_fee_lamports: u64,
+        funds_fee_lamports: u64,
+        payload_fee_lamports: u64,
+    ) -> Result<()> {
+        instructions::admin::set_route_fees(
+            ctx,
+            enabled,
+            gas_fee_lamports,
+            funds_fee_lamports,
+            payload_fee_lamports,
+        )
+    }
+
+    /// @notice Exempt (or un-exempt) a depositor identity from protocol and bps fees
+    pub fn set_fee_exemption(ctx: Context<FeeExemptionAction>, exempt: bool) -> Result<()> {
+        instructions::admin::set_fee_exemption(ctx, exempt)
+    }
+
+    /// @notice Charge the protocol fee once per send_universal_tx_batch (true) or once per request (false).
+    pub fn set_batch_fee_mode(ctx: Context<FeeVaultAdminAction>, fee_per_batch: bool) -> Result<()> {
+        instructions::admin::set_batch_fee_mode(ctx, fee_per_batch)
+    }
+
+    /// @notice Withdraw collected protocol fees to a treasury: lamports above the fee vault's
+    /// rent-exempt minimum, or SPL bps fees from a fee-vault-owned token account.
+    pub fn withdraw_protocol_fees(ctx: Context<WithdrawProtocolFees>, amount: u64) -> Result<()> {
+        instructions::admin::withdraw_protocol_fees(ctx, amount)
     }
 
     /// @notice Set Pyth confidence threshold
@@ -93,6 +202,38 @@ pub mod universal_gateway {
         instructions::admin::set_pyth_confidence_threshold(ctx, threshold)
     }
 
+    /// @notice Set the relative (bps) confidence bound and the EMA-price switch for cap checks
+    pub fn set_pyth_price_mode(
+        ctx: Context<AdminAction>,
+        confidence_bps: u16,
+        use_ema: bool,
+    ) -> Result<()> {
+        instructions::admin::set_pyth_price_mode(ctx, confidence_bps, use_ema)
+    }
+
+    /// @notice Set the SOL/USD deviation circuit breaker (0 bps disables) and reset its reference price
+    pub fn set_price_deviation_guard(
+        ctx: Context<AdminAction>,
+        max_deviation_bps: u16,
+        window_seconds: u64,
+    ) -> Result<()> {
+        instructions::admin::set_price_deviation_guard(ctx, max_deviation_bps, window_seconds)
+    }
+
+    /// @notice Set the Pyth max price age and minimum verification level (0 / 0 = defaults)
+    pub fn set_pyth_price_policy(
+        ctx: Context<AdminAction>,
+        max_age_seconds: u64,
+        min_verification_signatures: u8,
+    ) -> Result<()> {
+        instructions::admin::set_pyth_price_policy(ctx, max_age_seconds, min_verification_signatures)
+    }
+
+    /// @notice Set the allowlisted swap adapter program (Pubkey::default() disables swap-to-gas)
+    pub fn set_swap_adapter(ctx: Context<AdminAction>, swap_adapter: Pubkey) -> Result<()> {
+        instructions::admin::set_swap_adapter(ctx, swap_adapter)
+    }
+
     // =========================
     //        RATE LIMITING
     // =========================
@@ -113,6 +254,16 @@ pub mod universal_gateway {
         instructions::admin::update_epoch_duration(ctx, epoch_duration_sec)
     }
 
+    /// @notice Set the global all-token USD cap per epoch (0 disables)
+    pub fn set_epoch_usd_cap(ctx: Context<RateLimitConfigAction>, epoch_usd_cap: u128) -> Result<()> {
+        instructions::admin::set_epoch_usd_cap(ctx, epoch_usd_cap)
+    }
+
+    /// @notice Switch a token's rate limit threshold between natural units and USD
+    pub fn set_token_rate_limit_usd_mode(ctx: Context<TokenRateLimitAction>, usd_mode: bool) -> Result<()> {
+        instructions::admin::set_token_rate_limit_usd_mode(ctx, usd_mode)
+    }
+
     /// @notice Set token-specific rate limit threshold
     pub fn set_token_rate_limit(
         ctx: Context<TokenRateLimitAction>,
@@ -121,6 +272,74 @@ pub mod universal_gateway {
         instructions::admin::set_token_rate_limit(ctx, limit_threshold)
     }
 
+    /// @notice Set per-deposit USD bounds for a token's FUNDS routes (priced via its oracle)
+    pub fn set_token_usd_caps(
+        ctx: Context<TokenRateLimitAction>,
+        min_funds_usd: u128,
+        max_funds_usd: u128,
+    ) -> Result<()> {
+        instructions::admin::set_token_usd_caps(ctx, min_funds_usd, max_funds_usd)
+    }
+
+    /// @notice Set a token's FUNDS fee override (lamports) and bps fee on SPL FUNDS amounts
+    pub fn set_token_fee(
+        ctx: Context<TokenRateLimitAction>,
+        funds_fee_override: bool,
+        funds_fee_lamports: u64,
+        funds_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::admin::set_token_fee(ctx, funds_fee_override, funds_fee_lamports, funds_fee_bps)
+    }
+
+    /// @notice Register the Pyth feed, max age, confidence bound (bps) and verification level for a mint
+    pub fn set_oracle(
+        ctx: Context<OracleAction>,
+        price_feed: Pubkey,
+        feed_id: [u8; 32],
+        max_age_seconds: u64,
+        confidence_bps: u16,
+        min_verification_signatures: u8,
+    ) -> Result<()> {
+        instructions::admin::set_oracle(
+            ctx,
+            price_feed,
+            feed_id,
+            max_age_seconds,
+            confidence_bps,
+            min_verification_signatures,
+        )
+    }
+
+    /// @notice Set the secondary SOL/USD feeds and their aggregation for the instant route
+    pub fn set_price_sources(
+        ctx: Context<PriceSourcesAction>,
+        secondary_feeds: [Pubkey; MAX_SECONDARY_PRICE_FEEDS],
+        aggregation: PriceAggregation,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::admin::set_price_sources(ctx, secondary_feeds, aggregation, enabled)
+    }
+
+    /// @notice Push a fallback SOL/USD price (8 decimals, 0 clears) used until `expires_at`
+    pub fn set_fallback_price(
+        ctx: Context<PriceSourcesAction>,
+        price_usd: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::admin::set_fallback_price(ctx, price_usd, expires_at)
+    }
+
+    /// @notice Allowlist an SPL token as Push gas, valued at `peg_usd` or via its Pyth feed
+    pub fn set_gas_token(
+        ctx: Context<GasTokenAction>,
+        peg_usd: u64,
+        price_feed: Pubkey,
+        feed_id: [u8; 32],
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::admin::set_gas_token(ctx, peg_usd, price_feed, feed_id, enabled)
+    }
+
     // =========================
     //             TSS
     // =========================
@@ -145,8 +364,12 @@ pub mod universal_gateway {
     // =========================
     /// @notice Unified outbound entrypoint: withdraw (mode 1) or execute (mode 2)
     /// @param instruction_id 1=withdraw (vault→CEA→recipient), 2=execute (vault→CEA→CPI)
-    pub fn finalize_universal_tx(
-        ctx: Context<FinalizeUniversalTx>,
+    /// @param nonce / nonce_mode per-push-account outbound ordering (see `NonceMode`)
+    /// @param flags signed option bits (`FINALIZE_FLAG_*`)
+    /// @param token_legs extra SPL (mint, amount) legs staged to the CEA before the CPI (execute only)
+    /// @param cea_index CEA sub-account of `push_account` (0 = legacy CEA)
+    pub fn finalize_universal_tx<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeUniversalTx<'info>>,
         instruction_id: u8,
         sub_tx_id: [u8; 32],
         universal_tx_id: [u8; 32],
@@ -155,6 +378,11 @@ pub mod universal_gateway {
         writable_flags: Vec<u8>,
         ix_data: Vec<u8>,
         gas_fee: u64,
+        nonce: u64,
+        nonce_mode: NonceMode,
+        flags: u8,
+        token_legs: Vec<TokenLeg>,
+        cea_index: u16,
         signature: [u8; 64],
         recovery_id: u8,
         message_hash: [u8; 32],
@@ -169,6 +397,11 @@ pub mod universal_gateway {
             writable_flags,
             ix_data,
             gas_fee,
+            nonce,
+            nonce_mode,
+            flags,
+            token_legs,
+            cea_index,
             signature,
             recovery_id,
             message_hash,
@@ -203,6 +436,42 @@ pub mod universal_gateway {
         )
     }
 
+    // =========================
+    //        CEA SWEEP
+    // =========================
+    /// @notice TSS-verified sweep of a CEA: moves the listed CEA token accounts' balances to the
+    ///         vault ATAs, closes them (rent to vault) and drains CEA lamports to the vault.
+    ///         Credits the UEA via `UniversalTx` (from_cea), rent excluded, consuming rate limits.
+    ///         Replay-protected via ExecutedSubTx PDA
+    /// @param token_accounts CEA token accounts to close; remaining_accounts =
+    ///        [cea_token_account, vault_ata, token_rate_limit, oracle, oracle_price_update] each,
+    ///        then [token_rate_limit, oracle, oracle_price_update] for native SOL
+    pub fn sweep_cea<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepCea<'info>>,
+        sub_tx_id: [u8; 32],
+        universal_tx_id: [u8; 32],
+        push_account: [u8; 20],
+        cea_index: u16,
+        token_accounts: Vec<Pubkey>,
+        gas_fee: u64,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::sweep::sweep_cea(
+            ctx,
+            sub_tx_id,
+            universal_tx_id,
+            push_account,
+            cea_index,
+            token_accounts,
+            gas_fee,
+            signature,
+            recovery_id,
+            message_hash,
+        )
+    }
+
     // =========================
     //        REVERT
     // =========================
@@ -237,47 +506,99 @@ pub mod universal_gateway {
     // =========================
     /// @notice View function for SOL price (locker-compatible)
     pub fn get_sol_price(ctx: Context<GetSolPrice>) -> Result<PriceData> {
-        utils::get_sol_price(&ctx.accounts.price_update)
+        utils::get_sol_price(&ctx.accounts.sol_oracle, &ctx.accounts.price_update)
     }
 }
 
 /// Accounts for get_sol_price view function
 #[derive(Accounts)]
 pub struct GetSolPrice<'info> {
+    /// Native SOL `OracleRegistry` entry; `price_update` must be its `price_feed`.
+    pub sol_oracle: Account<'info, OracleRegistry>,
     pub price_update: Account<'info, pyth_solana_receiver_sdk::price_update::PriceUpdateV2>,
 }
 
 // Re-export account structs and types
 pub use instructions::admin::{
-    AdminAction, FeeVaultAdminAction, PauseAction, RateLimitConfigAction, SetAuthoritiesAction, TokenRateLimitAction,
+    AdminAction, FeeExemptionAction, FeeVaultAdminAction, GasTokenAction, OracleAction, PauseAction,
+    PriceSourcesAction, RateLimitConfigAction, SetAuthoritiesAction, TokenRateLimitAction,
 };
 pub use instructions::deposit::SendUniversalTx;
 pub use instructions::execute::FinalizeUniversalTx;
 pub use instructions::initialize::Initialize;
 pub use instructions::rescue::RescueFunds;
 pub use instructions::revert::RevertUniversalTx;
+pub use instructions::sweep::SweepCea;
 pub use utils::PriceData;
 
 pub use state::{
     // Events
+    BatchFeeModeUpdated,
     CapsUpdated,
+    CeaState,
+    CeaSwept,
     Config,
+    EpochUsdCapUpdated,
     ExecutedSubTx,
+    FallbackPriceUpdated,
+    FeeExemption,
+    FeeExemptionUpdated,
     FeeVault,
+    FundsFeeCollected,
     FundsRescued,
+    GasToken,
+    GasTokenUpdated,
     GatewayAccountMeta,
+    NonceMode,
+    OracleRegistry,
+    OracleUpdated,
     ProtocolFeeCollected,
     ProtocolFeeReimbursed,
     ProtocolFeeUpdated,
+    ProtocolFeesWithdrawn,
+    ProtocolFeeUsdUpdated,
+    PriceAggregation,
+    PriceDeviationGuardUpdated,
+    PriceSources,
+    PriceSourcesUpdated,
+    PythPriceModeUpdated,
+    PythPricePolicyUpdated,
     RevertInstructions,
+    RouteFeesUpdated,
+    SwapAdapterUpdated,
+    TokenFeeUpdated,
+    TokenRateLimitModeUpdated,
+    TokenUsdCapsUpdated,
+    TokenLeg,
     TxType,
     UniversalTx,
+    UniversalTxBundle,
+    UniversalTxBundleRequest,
     UniversalTxFinalized,
     UniversalTxRequest,
     VerificationType,
+    CEA_STATE_SEED,
     CONFIG_SEED,
+    DEPOSIT_DELEGATE_SEED,
     EXECUTED_SUB_TX_SEED,
-    FEED_ID,
+    FEE_EXEMPTION_SEED,
     FEE_VAULT_SEED,
+    FINALIZE_FLAGS_SUPPORTED,
+    FINALIZE_FLAG_SWEEP_CEA,
+    FINALIZE_FLAG_TOKEN_ACCOUNT_RECIPIENT,
+    FINALIZE_FLAG_WRAP_NATIVE,
+    GAS_TOKEN_SEED,
+    BATCH_NATIVE_ACCOUNTS,
+    BPS_DENOMINATOR,
+    BATCH_SPL_ACCOUNTS,
+    MAX_BATCH_REQUESTS,
+    MAX_BUNDLE_LEGS,
+    MAX_CEA_PAYLOAD_LEN,
+    MAX_SECONDARY_PRICE_FEEDS,
+    ORACLE_SEED,
+    PRICE_SOURCES_SEED,
+    SWAP_TO_NATIVE_IX_NAME,
+    TOKEN_LEG_ACCOUNTS,
+    cea_index_seed,
     VAULT_SEED,
 };
@@ -9,9 +9,63 @@ pub const RATE_LIMIT_CONFIG_SEED: &[u8] = b"rate_limit_config";
 pub const RATE_LIMIT_SEED: &[u8] = b"rate_limit";
 pub const EXECUTED_SUB_TX_SEED: &[u8] = b"executed_sub_tx";
 pub const CEA_SEED: &[u8] = b"push_identity";
+pub const CEA_STATE_SEED: &[u8] = b"cea_state";
+pub const DEPOSIT_DELEGATE_SEED: &[u8] = b"deposit_delegate";
+pub const GAS_TOKEN_SEED: &[u8] = b"gas_token";
+pub const ORACLE_SEED: &[u8] = b"oracle";
+pub const PRICE_SOURCES_SEED: &[u8] = b"price_sources";
+pub const FEE_EXEMPTION_SEED: &[u8] = b"fee_exemption";
+
+/// Swap adapter interface: instruction the gateway CPIs into to turn SPL tokens into lamports.
+/// Data: `sighash("global:swap_to_native") || amount_in (u64 LE) || min_amount_out (u64 LE)`.
+/// Accounts start with `[user (signer, writable), user_token_account (writable)]`; the rest
+/// are adapter-specific. Lamports out must land in `user`.
+pub const SWAP_TO_NATIVE_IX_NAME: &[u8] = b"global:swap_to_native";
+
+/// Basis-point denominator (100% = 10_000 bps).
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// Secondary SOL/USD feeds held by `PriceSources` (besides the native SOL oracle's feed).
+pub const MAX_SECONDARY_PRICE_FEEDS: usize = 2;
+
+/// Max requests per send_universal_tx_batch (bounded by tx size and compute).
+pub const MAX_BATCH_REQUESTS: usize = 16;
+
+/// Max legs per send_universal_tx_bundle.
+pub const MAX_BUNDLE_LEGS: usize = 8;
+
+/// Remaining accounts per batch request (and per bundle leg):
+/// `[token_rate_limit, oracle, oracle_price_update]` for native SOL, followed by
+/// `[user_token_account, gateway_token_account]` for any other token. Pass the gateway program
+/// id in an oracle slot to omit it (required once a USD bound, USD mode or the epoch USD cap
+/// applies to the token).
+pub const BATCH_NATIVE_ACCOUNTS: usize = 3;
+pub const BATCH_SPL_ACCOUNTS: usize = 5;
+
+/// Max payload size for payload-only (zero-amount) CEA → UEA messages.
+pub const MAX_CEA_PAYLOAD_LEN: usize = 1024;
+
+/// Extra CEA seed for sub-account `cea_index` (u16 BE).
+/// Empty for index 0, so `[CEA_SEED, push_account, cea_index_seed(0)]` derives the legacy CEA.
+pub fn cea_index_seed(cea_index: u16) -> Vec<u8> {
+    if cea_index == 0 {
+        Vec::new()
+    } else {
+        cea_index.to_be_bytes().to_vec()
+    }
+}
 
-// Price feed ID (Pyth SOL/USD), same as locker for now
-pub const FEED_ID: &str = "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+// Finalize option bits (signed by the TSS as a single `flags` byte)
+/// SPL withdraw: `recipient` is the destination token account itself (no ATA derivation).
+pub const FINALIZE_FLAG_TOKEN_ACCOUNT_RECIPIENT: u8 = 1 << 0;
+/// Native withdraw: deliver the lamports as wSOL into the recipient's wSOL ATA.
+pub const FINALIZE_FLAG_WRAP_NATIVE: u8 = 1 << 1;
+/// Execute: after the CPI, sweep whatever is left in the CEA (and its staged ATAs) back to the
+/// vault and credit the UEA.
+pub const FINALIZE_FLAG_SWEEP_CEA: u8 = 1 << 2;
+/// All option bits understood by this program version; anything else is rejected.
+pub const FINALIZE_FLAGS_SUPPORTED: u8 =
+    FINALIZE_FLAG_TOKEN_ACCOUNT_RECIPIENT | FINALIZE_FLAG_WRAP_NATIVE | FINALIZE_FLAG_SWEEP_CEA;
 
 /// Transaction types matching the EVM Universal Gateway `TX_TYPE`.
 /// Kept 1:1 for relayer/event parity with the EVM implementation.
@@ -41,6 +95,18 @@ pub enum VerificationType {
     UniversalTxVerification,
 }
 
+/// Outbound ordering mode for CEA-bound messages (withdraw / execute).
+/// Chosen per message and covered by the TSS signature.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NonceMode {
+    /// No ordering constraint; the nonce is signed and no `CeaState` is passed or created.
+    Unordered,
+    /// nonce must equal `CeaState.last_nonce + 1` (no gaps).
+    Strict,
+    /// nonce must be greater than `CeaState.last_nonce` (gaps allowed).
+    Loose,
+}
+
 /// Revert instructions for failed transactions (parity with EVM `RevertInstructions`).
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct RevertInstructions {
@@ -59,6 +125,16 @@ pub struct UniversalTxRequest {
     pub signature_data: Vec<u8>,
 }
 
+/// Multi-token deposit bundle: every leg is locked atomically and delivered in one event.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UniversalTxBundleRequest {
+    pub recipient: [u8; 20],      // [0u8; 20] => credit to UEA on Push
+    pub legs: Vec<TokenLeg>,      // Distinct tokens; Pubkey::default() => native SOL
+    pub payload: Vec<u8>,         // serialized payload (may be empty)
+    pub revert_recipient: Pubkey, // Solana address to receive funds if tx is reverted on Push
+    pub signature_data: Vec<u8>,
+}
+
 /// Gateway configuration state (authorities, caps, oracle).
 /// PDA: `[b"config"]`. Holds USD caps (8 decimals) for gas-route deposits and oracle config.
 #[account]
@@ -74,14 +150,36 @@ pub struct Config {
     pub bump: u8,
     pub vault_bump: u8,
     // Pyth oracle configuration
-    pub pyth_price_feed: Pubkey,        // Pyth SOL/USD price feed
+    pub pyth_price_feed: Pubkey,        // Legacy; SOL/USD is read from the native SOL OracleRegistry
     pub pyth_confidence_threshold: u64, // Confidence threshold for price validation
+    /// Allowlisted swap adapter program for SPL → SOL gas swaps. Pubkey::default() disables.
+    /// Taken from the former padding, so existing accounts read disabled.
+    pub swap_adapter: Pubkey,
+    /// Max Pyth price age in seconds for SOL/USD and gas-token feeds. 0 = default (1 hour).
+    pub pyth_max_age_seconds: u64,
+    /// Minimum Pyth verification: 0 = full, n > 0 = partial with at least n signatures.
+    pub pyth_min_verification_signatures: u8,
+    /// Relative confidence bound for cap enforcement: `conf / price <= bps / 10_000`. 0 = disabled.
+    pub pyth_confidence_bps: u16,
+    /// Use Pyth's EMA price (and EMA confidence) instead of the spot price for cap enforcement.
+    pub pyth_use_ema: bool,
+    /// Deviation circuit breaker: max move (bps) from the last accepted SOL/USD price. 0 = disabled.
+    pub max_price_deviation_bps: u16,
+    /// Window (seconds of publish time) in which the deviation bound applies.
+    pub price_deviation_window_seconds: u64,
+    /// Last accepted SOL/USD price (USD per SOL, 8 decimals). 0 = no reference yet.
+    pub last_sol_price_usd: u64,
+    /// Publish time of `last_sol_price_usd`.
+    pub last_sol_publish_time: i64,
+    /// Instant-route SOL/USD pricing goes through `PriceSources`, which must then be passed.
+    pub price_sources_enabled: bool,
 }
 
 impl Config {
     // discriminator + fields + padding
-    // 8 + 32 + 32 + 32 + 16 + 16 + 1 + 1 + 1 + 32 + 8 + 100
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 16 + 16 + 1 + 1 + 1 + 32 + 8 + 100;
+    // 8 + 32 + 32 + 32 + 16 + 16 + 1 + 1 + 1 + 32 + 8 + 32 + 8 + 1 + 2 + 1 + 2 + 8 + 8 + 8 + 1 + 29
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 16 + 16 + 1 + 1 + 1 + 32 + 8 + 32 + 8 + 1 + 2 + 1
+        + 2 + 8 + 8 + 8 + 1 + 29;
 }
 
 /// Fee vault: holds protocol fee lamports and the per-tx fee config.
@@ -92,11 +190,28 @@ impl Config {
 pub struct FeeVault {
     pub protocol_fee_lamports: u64, // Flat fee charged per inbound send_universal_tx; 0 disables
     pub bump: u8,
+    /// send_universal_tx_batch: charge the fee once per batch instead of once per request.
+    /// Taken from the former padding, so existing accounts read `false`.
+    pub fee_per_batch: bool,
+    /// USD-denominated fee (8 decimals) converted to lamports at the SOL/USD price on each
+    /// deposit. Overrides `protocol_fee_lamports` when non-zero.
+    pub protocol_fee_usd: u64,
+    /// Lamport clamps for the converted USD fee. `max_fee_lamports == 0` = no upper clamp.
+    pub min_fee_lamports: u64,
+    pub max_fee_lamports: u64,
+    /// Per-route lamport fees, replacing the flat / USD fee when `route_fees_enabled`.
+    /// A deposit pays `gas_fee_lamports` or `funds_fee_lamports`, plus `payload_fee_lamports`
+    /// when it carries a payload.
+    pub gas_fee_lamports: u64,
+    pub funds_fee_lamports: u64,
+    pub payload_fee_lamports: u64,
+    pub route_fees_enabled: bool,
 }
 
 impl FeeVault {
-    // 8 (discriminator) + 8 (fee) + 1 (bump) + 50 (padding)
-    pub const LEN: usize = 8 + 8 + 1 + 50;
+    // 8 (discriminator) + 8 (fee) + 1 (bump) + 1 (fee_per_batch) + 8 (fee usd) + 8 + 8 (clamps)
+    // + 8 + 8 + 8 (route fees) + 1 (route_fees_enabled); padding fully used
+    pub const LEN: usize = 8 + 8 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
 }
 
 /// Rate limiting configuration (separate account for backward compatibility)
@@ -108,10 +223,14 @@ pub struct RateLimitConfig {
     pub last_slot: u64,          // Last slot for block-based cap tracking
     pub consumed_usd_in_block: u128, // USD consumed in current block
     pub bump: u8,
+    /// Global all-token USD cap per epoch (8 decimals) on FUNDS routes. 0 disables.
+    /// Taken from the former padding, so existing accounts read disabled.
+    pub epoch_usd_cap: u128,
+    pub epoch_usd_usage: EpochUsage, // Current epoch USD usage across all tokens
 }
 
 impl RateLimitConfig {
-    pub const LEN: usize = 8 + 16 + 8 + 8 + 16 + 1 + 100; // discriminator + fields + bump + padding
+    pub const LEN: usize = 8 + 16 + 8 + 8 + 16 + 1 + 16 + 24 + 60; // discriminator + fields + bump + epoch usd cap + usage + padding
 }
 
 /// Token-specific rate limiting state (matching EVM implementation)
@@ -122,10 +241,113 @@ pub struct TokenRateLimit {
     pub limit_threshold: u128,   // Max amount per epoch (token's natural units)
     pub epoch_usage: EpochUsage, // Current epoch usage tracking
     pub bump: u8,
+    /// Per-deposit USD bounds on FUNDS routes (8 decimals), priced via the token's
+    /// `OracleRegistry`. Both 0 = disabled; `max_funds_usd == 0` = no upper bound.
+    /// Taken from the former padding, so existing accounts read disabled.
+    pub min_funds_usd: u128,
+    pub max_funds_usd: u128,
+    /// When set, `limit_threshold` and `epoch_usage.used` are in USD (8 decimals) and deposits
+    /// are converted through the token's `OracleRegistry` before accumulating.
+    pub usd_mode: bool,
+    /// When set, FUNDS deposits of this token pay `funds_fee_lamports` instead of the
+    /// route / flat / USD protocol fee.
+    pub funds_fee_override: bool,
+    pub funds_fee_lamports: u64,
+    /// Fee on SPL FUNDS amounts in bps, taken in the deposited token into the fee vault's
+    /// token account. Only the net amount is bridged. 0 = disabled.
+    pub funds_fee_bps: u16,
 }
 
 impl TokenRateLimit {
-    pub const LEN: usize = 8 + 32 + 16 + 8 + 16 + 1 + 100; // discriminator + token_mint + limit_threshold + epoch + used + bump + padding
+    pub const LEN: usize = 8 + 32 + 16 + 8 + 16 + 1 + 16 + 16 + 1 + 1 + 8 + 2 + 56; // discriminator + token_mint + limit_threshold + epoch + used + bump + min/max funds usd + usd_mode + fee override + bps + padding
+}
+
+/// Protocol fee exemption for a depositor identity (`[FEE_EXEMPTION_SEED, sender]`), e.g. a
+/// partner program's PDA depositing through `send_universal_tx_cpi`. Matched against
+/// `UniversalTx.sender`; exempt deposits pay no protocol fee and no bps fee.
+#[account]
+pub struct FeeExemption {
+    pub sender: Pubkey,
+    pub exempt: bool,
+    pub bump: u8,
+}
+
+impl FeeExemption {
+    // discriminator + sender + exempt + bump + padding
+    pub const LEN: usize = 8 + 32 + 1 + 1 + 32;
+}
+
+/// Per-mint Pyth oracle configuration (`[ORACLE_SEED, mint]`; `Pubkey::default()` = native SOL).
+/// Prices deposited amounts for FUNDS USD bounds and USD-mode rate limits. The native SOL entry
+/// also supplies the SOL/USD feed of the gas route, USD fees and USD caps.
+#[account]
+pub struct OracleRegistry {
+    pub mint: Pubkey,
+    pub decimals: u8,
+    /// `PriceUpdateV2` account holding the asset/USD price.
+    pub price_feed: Pubkey,
+    pub feed_id: [u8; 32],
+    /// Max price age in seconds. 0 = default (`MAX_PRICE_AGE_SECONDS`).
+    pub max_age_seconds: u64,
+    /// Max Pyth confidence relative to the price, in bps (at most 10_000). 0 = disabled.
+    pub confidence_bps: u16,
+    pub bump: u8,
+    /// Minimum Pyth verification: 0 = full, n > 0 = partial with at least n signatures.
+    pub min_verification_signatures: u8,
+}
+
+impl OracleRegistry {
+    // discriminator + mint + decimals + price_feed + feed_id + max_age + confidence + bump + verification + padding
+    pub const LEN: usize = 8 + 32 + 1 + 32 + 32 + 8 + 2 + 1 + 1 + 69;
+}
+
+/// How `PriceSources` combines the fresh SOL/USD feeds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceAggregation {
+    /// First fresh feed in order: primary, then `secondary_feeds`.
+    FirstFresh,
+    /// Lower median of all fresh feeds; every configured feed must be passed.
+    Median,
+}
+
+/// SOL/USD price sources for the instant route (`[PRICE_SOURCES_SEED]`), used when
+/// `Config.price_sources_enabled`. Adds secondary Pyth feeds (same feed id) to
+/// the native SOL oracle's `price_feed` and an admin-pushed fallback used only when no feed is fresh.
+#[account]
+pub struct PriceSources {
+    /// `PriceUpdateV2` accounts; `Pubkey::default()` = unused slot.
+    pub secondary_feeds: [Pubkey; MAX_SECONDARY_PRICE_FEEDS],
+    pub aggregation: PriceAggregation,
+    /// Fallback USD per SOL (8 decimals). 0 = none.
+    pub fallback_price_usd: u64,
+    /// Unix timestamp after which the fallback price is ignored.
+    pub fallback_expires_at: i64,
+    pub bump: u8,
+}
+
+impl PriceSources {
+    // discriminator + secondary_feeds + aggregation + fallback price + expiry + bump + padding
+    pub const LEN: usize = 8 + 32 * MAX_SECONDARY_PRICE_FEEDS + 1 + 8 + 8 + 1 + 64;
+}
+
+/// Allowlisted SPL token accepted as Push gas on the instant route (`send_universal_tx_gas_token`).
+/// Valued at `peg_usd` when non-zero, otherwise through its own Pyth feed.
+#[account]
+pub struct GasToken {
+    pub mint: Pubkey,
+    pub decimals: u8,
+    /// USD per whole token, 8 decimals (100_000_000 = $1.00). 0 = price via `price_feed`.
+    pub peg_usd: u64,
+    /// `PriceUpdateV2` account for the token/USD feed; used only when `peg_usd == 0`.
+    pub price_feed: Pubkey,
+    pub feed_id: [u8; 32],
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+impl GasToken {
+    // discriminator + mint + decimals + peg_usd + price_feed + feed_id + enabled + bump + padding
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 32 + 32 + 1 + 1 + 64;
 }
 
 /// TSS state PDA for ECDSA verification (Ethereum-style secp256k1).
@@ -157,6 +379,23 @@ impl ExecutedSubTx {
     pub const LEN: usize = 8;
 }
 
+/// Per-CEA outbound ordering state.
+/// PDA: `[b"cea_state", push_account, cea_index_seed(cea_index)]` (index 0 = `[b"cea_state", push_account]`).
+/// Created by the relayer on the first Strict / Loose finalize for that CEA.
+/// Holds the last consumed outbound nonce so stateful CEA sequences (e.g. stake → unstake) land in order.
+#[account]
+pub struct CeaState {
+    pub push_account: [u8; 20],
+    pub last_nonce: u64, // Last nonce consumed by a Strict/Loose message (0 = none yet)
+    pub bump: u8,
+    pub cea_index: u16,
+}
+
+impl CeaState {
+    // discriminator (8) + push_account (20) + last_nonce (8) + bump (1) + cea_index (2) + padding (48)
+    pub const LEN: usize = 8 + 20 + 8 + 1 + 2 + 48;
+}
+
 // ============================================
 //    EXECUTE ARBITRARY CALLS (NEW)
 // ============================================
@@ -169,6 +408,23 @@ pub struct GatewayAccountMeta {
     pub is_writable: bool,
 }
 
+/// Extra SPL leg staged from the vault into the CEA before an execute CPI.
+/// Accounts per leg are passed at the front of remaining_accounts: [mint, vault_ata, cea_ata].
+/// Also a `(token, amount)` leg of an inbound deposit bundle, where `Pubkey::default()` is SOL.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TokenLeg {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Number of remaining accounts consumed by each `TokenLeg`.
+pub const TOKEN_LEG_ACCOUNTS: usize = 3;
+
+/// Remaining accounts per asset credited by a CEA sweep:
+/// `[token_rate_limit, oracle, oracle_price_update]`. Pass the gateway program id in an oracle
+/// slot to omit it (Anchor's encoding for an absent optional account).
+pub const CEA_CREDIT_ACCOUNTS: usize = 3;
+
 /// Execute event (parity with EVM `UniversalTxFinalized`).
 #[event]
 pub struct UniversalTxFinalized {
@@ -180,6 +436,8 @@ pub struct UniversalTxFinalized {
     pub token: Pubkey,             // Token (Pubkey::default() for SOL)
     pub amount: u64,
     pub payload: Vec<u8>, // ix_data
+    pub token_legs: Vec<TokenLeg>, // Extra SPL legs staged to the CEA (execute only)
+    pub cea_index: u16,            // CEA sub-account index (0 = legacy CEA)
 }
 
 /// Universal transaction event (parity with EVM V0 `UniversalTx`).
@@ -197,6 +455,19 @@ pub struct UniversalTx {
     pub from_cea: bool, // true = emitted from CEA withdrawal; Push Chain UE uses recipient directly as UEA
 }
 
+/// Multi-token deposit bundle event: all legs are credited together on Push Chain.
+/// A gas top-up batched with the bundle is emitted separately as `UniversalTx` (TxType::Gas).
+#[event]
+pub struct UniversalTxBundle {
+    pub sender: Pubkey,
+    pub recipient: [u8; 20],
+    pub legs: Vec<TokenLeg>,
+    pub payload: Vec<u8>,
+    pub revert_recipient: Pubkey,
+    pub tx_type: TxType, // Funds or FundsAndPayload
+    pub signature_data: Vec<u8>,
+}
+
 /// Revert withdraw event (parity with EVM `RevertUniversalTx`).
 #[event]
 pub struct RevertUniversalTx {
@@ -225,23 +496,141 @@ pub struct EpochDurationUpdated {
     pub epoch_duration_sec: u64,
 }
 
+#[event]
+pub struct EpochUsdCapUpdated {
+    pub epoch_usd_cap: u128,
+}
+
+#[event]
+pub struct TokenRateLimitModeUpdated {
+    pub token_mint: Pubkey,
+    pub usd_mode: bool,
+}
+
 #[event]
 pub struct TokenRateLimitUpdated {
     pub token_mint: Pubkey,
     pub limit_threshold: u128,
 }
 
+#[event]
+pub struct TokenUsdCapsUpdated {
+    pub token_mint: Pubkey,
+    pub min_funds_usd: u128,
+    pub max_funds_usd: u128,
+}
+
+#[event]
+pub struct OracleUpdated {
+    pub mint: Pubkey,
+    pub price_feed: Pubkey,
+    pub feed_id: [u8; 32],
+    pub max_age_seconds: u64,
+    pub confidence_bps: u16,
+    pub min_verification_signatures: u8,
+}
+
+#[event]
+pub struct PythPricePolicyUpdated {
+    pub max_age_seconds: u64,
+    pub min_verification_signatures: u8,
+}
+
+#[event]
+pub struct PythPriceModeUpdated {
+    pub confidence_bps: u16,
+    pub use_ema: bool,
+}
+
+#[event]
+pub struct PriceDeviationGuardUpdated {
+    pub max_deviation_bps: u16,
+    pub window_seconds: u64,
+}
+
+#[event]
+pub struct PriceSourcesUpdated {
+    pub secondary_feeds: [Pubkey; MAX_SECONDARY_PRICE_FEEDS],
+    pub aggregation: PriceAggregation,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct FallbackPriceUpdated {
+    pub price_usd: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct SwapAdapterUpdated {
+    pub old: Pubkey,
+    pub new: Pubkey,
+}
+
+#[event]
+pub struct GasTokenUpdated {
+    pub mint: Pubkey,
+    pub peg_usd: u64,
+    pub price_feed: Pubkey,
+    pub feed_id: [u8; 32],
+    pub enabled: bool,
+}
+
 #[event]
 pub struct ProtocolFeeUpdated {
     pub new_fee_lamports: u64,
 }
 
+#[event]
+pub struct ProtocolFeeUsdUpdated {
+    pub fee_usd: u64,
+    pub min_fee_lamports: u64,
+    pub max_fee_lamports: u64,
+}
+
+#[event]
+pub struct RouteFeesUpdated {
+    pub enabled: bool,
+    pub gas_fee_lamports: u64,
+    pub funds_fee_lamports: u64,
+    pub payload_fee_lamports: u64,
+}
+
+#[event]
+pub struct TokenFeeUpdated {
+    pub token_mint: Pubkey,
+    pub funds_fee_override: bool,
+    pub funds_fee_lamports: u64,
+    pub funds_fee_bps: u16,
+}
+
+#[event]
+pub struct FeeExemptionUpdated {
+    pub sender: Pubkey,
+    pub exempt: bool,
+}
+
+#[event]
+pub struct FundsFeeCollected {
+    pub payer: Pubkey,
+    pub token: Pubkey,
+    pub amount: u64,
+    pub net_amount: u64,
+}
+
+#[event]
+pub struct BatchFeeModeUpdated {
+    pub fee_per_batch: bool,
+}
+
 #[event]
 pub struct ProtocolFeeCollected {
     pub payer: Pubkey,
     pub amount_lamports: u64,
     pub native_amount_before: u64,
     pub native_amount_after: u64,
+    /// USD value of `amount_lamports` (8 decimals) for USD-priced fees; 0 for the flat fee.
+    pub amount_usd: u128,
 }
 
 #[event]
@@ -251,6 +640,26 @@ pub struct ProtocolFeeReimbursed {
     pub amount_lamports: u64,
 }
 
+/// Admin withdrawal from the fee vault. `token` is `Pubkey::default()` for lamports.
+#[event]
+pub struct ProtocolFeesWithdrawn {
+    pub token: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a CEA is swept via the TSS-verified sweep_cea instruction.
+/// Per-asset credits to the UEA are emitted separately as `UniversalTx` (from_cea: true).
+#[event]
+pub struct CeaSwept {
+    pub sub_tx_id: [u8; 32],
+    pub universal_tx_id: [u8; 32],
+    pub push_account: [u8; 20],
+    pub token_accounts: Vec<Pubkey>, // CEA token accounts closed
+    pub lamports: u64,               // CEA lamports credited (closed accounts' rent excluded)
+    pub gas_fee: u64,
+}
+
 /// Emitted when locked funds are rescued back to recipient via TSS-verified rescue instruction.
 #[event]
 pub struct FundsRescued {
@@ -1,4 +1,4 @@
-use crate::state::GatewayAccountMeta;
+use crate::state::{GatewayAccountMeta, TokenLeg};
 
 /// Encode a u64 as big-endian bytes for TSS message construction.
 #[inline]
@@ -26,3 +26,15 @@ pub fn serialize_ix_data(ix_data: &[u8]) -> Vec<u8> {
     buf.extend_from_slice(ix_data);
     buf
 }
+
+/// Serialize token legs into a length-prefixed buffer for TSS signing.
+/// Format: [u32 BE count][mint(32) + amount(u64 BE)] × N
+pub fn serialize_token_legs(legs: &[TokenLeg]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(4 + legs.len() * 40);
+    buf.extend_from_slice(&(legs.len() as u32).to_be_bytes());
+    for leg in legs {
+        buf.extend_from_slice(&leg.mint.to_bytes());
+        buf.extend_from_slice(&leg.amount.to_be_bytes());
+    }
+    buf
+}
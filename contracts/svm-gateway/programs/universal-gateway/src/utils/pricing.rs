@@ -1,10 +1,15 @@
 use crate::errors::GatewayError;
-use crate::state::{Config, FEED_ID};
+use crate::state::{
+    Config, GasToken, OracleRegistry, PriceAggregation, PriceSources, BPS_DENOMINATOR,
+    MAX_SECONDARY_PRICE_FEEDS,
+};
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use pyth_solana_receiver_sdk::error::GetPriceError;
+use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, VerificationLevel};
 
-/// Maximum allowed age for Pyth price updates used by inbound USD-cap checks.
-/// Tune before mainnet if tighter freshness is required.
+/// Default max age for Pyth price updates when none is configured (`0`).
+/// Overridden by `Config.pyth_max_age_seconds` / `OracleRegistry.max_age_seconds`.
 const MAX_PRICE_AGE_SECONDS: u64 = 3_600; // 1 hour
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -15,14 +20,244 @@ pub struct PriceData {
     pub confidence: u64,   // Price confidence interval
 }
 
-pub fn calculate_sol_price(price_update: &Account<PriceUpdateV2>) -> Result<PriceData> {
-    let feed_id = get_feed_id_from_hex(FEED_ID).map_err(|_| error!(GatewayError::InvalidPrice))?;
+/// SOL/USD price for the native SOL `OracleRegistry` entry (`mint == Pubkey::default()`): its
+/// feed id under the stricter of the entry's and the config's max age and verification level,
+/// and the entry's `confidence_bps`. The caller checks which `PriceUpdateV2` account is read.
+/// With `config.pyth_use_ema` the EMA price and EMA confidence of the same (checked) update are returned.
+pub fn calculate_sol_price(
+    config: &Config,
+    sol_oracle: &OracleRegistry,
+    price_update: &Account<PriceUpdateV2>,
+) -> Result<PriceData> {
+    require!(
+        sol_oracle.mint == Pubkey::default(),
+        GatewayError::InvalidAccount
+    );
+    let effective_age = |max_age: u64| {
+        if max_age == 0 {
+            MAX_PRICE_AGE_SECONDS
+        } else {
+            max_age
+        }
+    };
+    let max_age = effective_age(config.pyth_max_age_seconds)
+        .min(effective_age(sol_oracle.max_age_seconds));
+    // 0 (full verification) is the strictest level; otherwise more signatures is stricter.
+    let min_signatures = if config.pyth_min_verification_signatures == 0
+        || sol_oracle.min_verification_signatures == 0
+    {
+        0
+    } else {
+        config
+            .pyth_min_verification_signatures
+            .max(sol_oracle.min_verification_signatures)
+    };
+    let price_data =
+        calculate_feed_price(price_update, &sol_oracle.feed_id, max_age, min_signatures)?;
+    let price_data = if config.pyth_use_ema {
+        let message = &price_update.price_message;
+        require!(message.ema_price > 0, GatewayError::NonPositivePrice);
+        PriceData {
+            price: message.ema_price,
+            confidence: message.ema_conf,
+            ..price_data
+        }
+    } else {
+        price_data
+    };
+    if sol_oracle.confidence_bps > 0 {
+        require!(
+            confidence_within_bps(&price_data, sol_oracle.confidence_bps),
+            GatewayError::LowPriceConfidence
+        );
+    }
+    Ok(price_data)
+}
+
+/// SOL/USD price for the instant route (GAS deposits, USD fees and CEA gas legs).
+/// The native SOL `OracleRegistry` entry and its `price_feed` (the primary) are always
+/// required. Without `config.price_sources_enabled` this is `calculate_sol_price` on it.
+/// Otherwise `price_sources` and every configured secondary are required too, so the caller
+/// cannot choose which feeds count. `FirstFresh` uses the primary
+/// unless it is stale, then the first secondary passing the staleness, verification and
+/// positivity checks. `Median` takes the lower median of all passing feeds. The admin fallback
+/// price applies only when the primary is stale and no feed passes, and until it expires;
+/// otherwise the primary's error is returned.
+pub fn resolve_sol_price(
+    config: &Config,
+    sol_oracle: Option<&Account<OracleRegistry>>,
+    primary: Option<&Account<PriceUpdateV2>>,
+    price_sources: Option<&Account<PriceSources>>,
+    secondaries: [Option<&Account<PriceUpdateV2>>; MAX_SECONDARY_PRICE_FEEDS],
+) -> Result<PriceData> {
+    let sol_oracle = sol_oracle.ok_or(error!(GatewayError::InvalidAccount))?;
+    let primary = primary.ok_or(error!(GatewayError::InvalidAccount))?;
+    require!(
+        primary.key() == sol_oracle.price_feed,
+        GatewayError::InvalidAccount
+    );
+    let primary_price = calculate_sol_price(config, sol_oracle, primary);
+    if !config.price_sources_enabled {
+        return primary_price;
+    }
+    let sources = price_sources.ok_or(error!(GatewayError::InvalidAccount))?;
+
+    let mut secondary_prices = Vec::with_capacity(MAX_SECONDARY_PRICE_FEEDS);
+    for (feed, account) in sources.secondary_feeds.iter().zip(secondaries) {
+        if *feed == Pubkey::default() {
+            require!(account.is_none(), GatewayError::InvalidAccount);
+            continue;
+        }
+        let account = account.ok_or(error!(GatewayError::InvalidAccount))?;
+        require!(account.key() == *feed, GatewayError::InvalidAccount);
+        secondary_prices.push(calculate_sol_price(config, sol_oracle, account).ok());
+    }
+
+    let primary_stale =
+        matches!(&primary_price, Err(err) if *err == error!(GatewayError::StalePrice));
+    let resolved = match sources.aggregation {
+        PriceAggregation::FirstFresh => {
+            if !primary_stale {
+                return primary_price;
+            }
+            secondary_prices.into_iter().flatten().next()
+        }
+        PriceAggregation::Median => {
+            let mut normalized = std::iter::once(primary_price.as_ref().ok().cloned())
+                .chain(secondary_prices)
+                .flatten()
+                .map(|price| normalize_price(&price))
+                .collect::<Result<Vec<_>>>()?;
+            normalized.sort_by_key(|p| p.price);
+            match normalized.len() {
+                0 => None,
+                len => Some(normalized.swap_remove((len - 1) / 2)),
+            }
+        }
+    };
+    if let Some(price_data) = resolved {
+        return Ok(price_data);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if primary_stale && sources.fallback_price_usd > 0 && now < sources.fallback_expires_at {
+        return Ok(PriceData {
+            price: i64::try_from(sources.fallback_price_usd)
+                .map_err(|_| error!(GatewayError::InvalidPrice))?,
+            exponent: -8,
+            publish_time: now,
+            confidence: 0,
+        });
+    }
+    primary_price
+}
+
+/// Rescale price and confidence to exponent -8 so feeds with different exponents compare.
+fn normalize_price(price_data: &PriceData) -> Result<PriceData> {
+    let rescale = |value: u64| -> Result<i64> {
+        let scaled = calculate_token_usd_amount(
+            1,
+            0,
+            &PriceData {
+                price: value as i64,
+                ..price_data.clone()
+            },
+        )?;
+        i64::try_from(scaled).map_err(|_| error!(GatewayError::InvalidPrice))
+    };
+    Ok(PriceData {
+        price: rescale(price_data.price as u64)?,
+        exponent: -8,
+        publish_time: price_data.publish_time,
+        confidence: rescale(price_data.confidence)? as u64,
+    })
+}
+
+/// Price for `mint` from its `OracleRegistry` entry, for USD conversions of deposited amounts.
+/// Checks the entry's feed account, feed id, max age, verification level and relative confidence.
+pub fn price_for(
+    mint: &Pubkey,
+    oracle: &OracleRegistry,
+    price_update: &Account<PriceUpdateV2>,
+) -> Result<PriceData> {
+    require!(oracle.mint == *mint, GatewayError::InvalidAccount);
+    require!(
+        price_update.key() == oracle.price_feed,
+        GatewayError::InvalidAccount
+    );
+    let price_data = calculate_feed_price(
+        price_update,
+        &oracle.feed_id,
+        oracle.max_age_seconds,
+        oracle.min_verification_signatures,
+    )?;
+    if oracle.confidence_bps > 0 {
+        require!(
+            confidence_within_bps(&price_data, oracle.confidence_bps),
+            GatewayError::LowPriceConfidence
+        );
+    }
+    Ok(price_data)
+}
+
+/// USD value (8 decimals) of `amount` of `mint`, or `None` when no oracle accounts for `mint`
+/// were passed. Consumers that need a value (USD bounds, USD-mode rate limits, the epoch USD
+/// cap) reject `None`.
+pub fn oracle_usd_amount(
+    mint: &Pubkey,
+    amount: u64,
+    oracle: Option<&Account<OracleRegistry>>,
+    price_update: Option<&Account<PriceUpdateV2>>,
+) -> Result<Option<u128>> {
+    let (Some(oracle), Some(price_update)) = (oracle, price_update) else {
+        return Ok(None);
+    };
+    if oracle.mint != *mint {
+        return Ok(None);
+    }
+    let price_data = price_for(mint, oracle, price_update)?;
+    calculate_token_usd_amount(amount, oracle.decimals, &price_data).map(Some)
+}
+
+/// Fresh, sufficiently verified, positive price for an arbitrary Pyth feed id.
+/// `max_age_seconds == 0` uses `MAX_PRICE_AGE_SECONDS`. `min_verification_signatures == 0`
+/// requires a fully verified update; `n > 0` also accepts partial updates with >= n signatures.
+pub fn calculate_feed_price(
+    price_update: &Account<PriceUpdateV2>,
+    feed_id: &[u8; 32],
+    max_age_seconds: u64,
+    min_verification_signatures: u8,
+) -> Result<PriceData> {
+    let max_age = if max_age_seconds == 0 {
+        MAX_PRICE_AGE_SECONDS
+    } else {
+        max_age_seconds
+    };
+    let verification_level = if min_verification_signatures == 0 {
+        VerificationLevel::Full
+    } else {
+        VerificationLevel::Partial {
+            num_signatures: min_verification_signatures,
+        }
+    };
+
     let clock = Clock::get()?;
     let price = price_update
-        .get_price_no_older_than(&clock, MAX_PRICE_AGE_SECONDS, &feed_id)
-        .map_err(|_| error!(GatewayError::InvalidPrice))?;
+        .get_price_no_older_than_with_custom_verification_level(
+            &clock,
+            max_age,
+            feed_id,
+            verification_level,
+        )
+        .map_err(|err| match err {
+            GetPriceError::PriceTooOld => error!(GatewayError::StalePrice),
+            GetPriceError::InsufficientVerificationLevel => {
+                error!(GatewayError::InsufficientPriceVerification)
+            }
+            _ => error!(GatewayError::InvalidPrice),
+        })?;
 
-    require!(price.price > 0, GatewayError::InvalidPrice);
+    require!(price.price > 0, GatewayError::NonPositivePrice);
 
     Ok(PriceData {
         price: price.price,
@@ -38,12 +273,21 @@ pub fn calculate_sol_price(price_update: &Account<PriceUpdateV2>) -> Result<Pric
 ///      Result is in 8 decimals (matching EVM's 18 decimals but scaled to 8 for consistency)
 ///      Formula: USD_8dec = (lamports * price * 10^(exponent + 8)) / 1e9
 pub fn calculate_usd_amount(lamports: u64, price_data: &PriceData) -> Result<u128> {
-    let lamports_u128 = lamports as u128;
+    calculate_token_usd_amount(lamports, 9, price_data)
+}
+
+/// Same as `calculate_usd_amount` for a token with `decimals` base-unit decimals.
+/// Formula: USD_8dec = (amount * price * 10^(exponent + 8)) / 10^decimals
+pub fn calculate_token_usd_amount(amount: u64, decimals: u8, price_data: &PriceData) -> Result<u128> {
+    let amount_u128 = amount as u128;
     let price_u128 = price_data.price as u128;
+    let unit = 10u128
+        .checked_pow(decimals as u32)
+        .ok_or(GatewayError::InvalidAmount)?;
 
     // Multiply first to preserve precision, then apply exponent adjustment
     // For exponent = -8: we need to multiply by 10^(exponent + 8) = 10^0 = 1
-    let product = lamports_u128
+    let product = amount_u128
         .checked_mul(price_u128)
         .ok_or(GatewayError::InvalidAmount)?;
 
@@ -53,12 +297,12 @@ pub fn calculate_usd_amount(lamports: u64, price_data: &PriceData) -> Result<u12
     let usd_amount = if exponent_adjustment >= 0 {
         product
             .checked_mul(10u128.pow(exponent_adjustment as u32))
-            .and_then(|x| x.checked_div(1_000_000_000))
+            .and_then(|x| x.checked_div(unit))
             .ok_or(GatewayError::InvalidAmount)?
     } else {
         product
             .checked_div(10u128.pow((-exponent_adjustment) as u32))
-            .and_then(|x| x.checked_div(1_000_000_000))
+            .and_then(|x| x.checked_div(unit))
             .ok_or(GatewayError::InvalidAmount)?
     };
 
@@ -66,19 +310,87 @@ pub fn calculate_usd_amount(lamports: u64, price_data: &PriceData) -> Result<u12
 }
 
 // Check USD caps for gas deposits using the integer-safe path
-pub fn check_usd_caps(
-    config: &Config,
-    lamports: u64,
-    price_update: &Account<PriceUpdateV2>,
-) -> Result<()> {
-    let price_data = calculate_sol_price(price_update)?;
+pub fn check_usd_caps(config: &Config, lamports: u64, price_data: &PriceData) -> Result<()> {
+    check_price_confidence(config, price_data)?;
+    let usd_amount = calculate_usd_amount(lamports, price_data)?;
+    check_usd_cap_bounds(config, usd_amount)
+}
+
+/// Absolute (`pyth_confidence_threshold`) and relative (`pyth_confidence_bps`) confidence bounds.
+pub fn check_price_confidence(config: &Config, price_data: &PriceData) -> Result<()> {
     if config.pyth_confidence_threshold > 0 {
         require!(
             price_data.confidence <= config.pyth_confidence_threshold,
-            GatewayError::InvalidPrice
+            GatewayError::LowPriceConfidence
         );
     }
-    let usd_amount = calculate_usd_amount(lamports, &price_data)?;
+    if config.pyth_confidence_bps > 0 {
+        require!(
+            confidence_within_bps(price_data, config.pyth_confidence_bps),
+            GatewayError::LowPriceConfidence
+        );
+    }
+    Ok(())
+}
+
+/// Lamports worth `usd_amount` (8 decimals) at `price_data`, rounded up.
+pub fn usd_to_lamports(usd_amount: u64, price_data: &PriceData) -> Result<u64> {
+    let usd_per_sol = calculate_usd_amount(LAMPORTS_PER_SOL, price_data)?;
+    require!(usd_per_sol > 0, GatewayError::InvalidPrice);
+    let lamports = (usd_amount as u128)
+        .checked_mul(LAMPORTS_PER_SOL as u128)
+        .ok_or(GatewayError::InvalidAmount)?
+        .div_ceil(usd_per_sol);
+    u64::try_from(lamports).map_err(|_| error!(GatewayError::InvalidAmount))
+}
+
+/// `confidence / price <= bps / 10_000`, independent of the exponent and price level.
+pub fn confidence_within_bps(price_data: &PriceData, bps: u16) -> bool {
+    let conf_scaled = (price_data.confidence as u128).saturating_mul(BPS_DENOMINATOR as u128);
+    let bound = (price_data.price.max(0) as u128).saturating_mul(bps as u128);
+    conf_scaled <= bound
+}
+
+/// Deviation circuit breaker for the SOL/USD price used by the instant route.
+/// Rejects a price that moved more than `max_price_deviation_bps` from the last accepted price
+/// when it was published within `price_deviation_window_seconds` of it. Otherwise the price
+/// becomes the new reference (older updates never replace a newer reference). No-op when disabled.
+pub fn check_price_deviation(config: &mut Config, price_data: &PriceData) -> Result<()> {
+    if config.max_price_deviation_bps == 0 {
+        return Ok(());
+    }
+
+    let price_usd = u64::try_from(calculate_usd_amount(LAMPORTS_PER_SOL, price_data)?)
+        .map_err(|_| error!(GatewayError::InvalidPrice))?;
+    let elapsed = price_data
+        .publish_time
+        .saturating_sub(config.last_sol_publish_time);
+    if config.last_sol_price_usd > 0 && elapsed <= config.price_deviation_window_seconds as i64 {
+        require!(
+            deviation_within_bps(
+                config.last_sol_price_usd,
+                price_usd,
+                config.max_price_deviation_bps
+            ),
+            GatewayError::PriceDeviationExceeded
+        );
+    }
+
+    if price_data.publish_time >= config.last_sol_publish_time {
+        config.last_sol_price_usd = price_usd;
+        config.last_sol_publish_time = price_data.publish_time;
+    }
+    Ok(())
+}
+
+/// `|current - reference| / reference <= bps / 10_000`.
+pub fn deviation_within_bps(reference: u64, current: u64, bps: u16) -> bool {
+    let diff = reference.abs_diff(current) as u128;
+    diff * BPS_DENOMINATOR as u128 <= reference as u128 * bps as u128
+}
+
+/// Enforce `min_cap_universal_tx_usd <= usd_amount <= max_cap_universal_tx_usd` (8 decimals).
+pub fn check_usd_cap_bounds(config: &Config, usd_amount: u128) -> Result<()> {
     require!(
         usd_amount >= config.min_cap_universal_tx_usd,
         GatewayError::BelowMinCap
@@ -90,9 +402,152 @@ pub fn check_usd_caps(
     Ok(())
 }
 
+/// USD value (8 decimals) of `amount` base units of an allowlisted gas token.
+/// Pegged tokens use `peg_usd`; feed-priced tokens require their configured `price_update`
+/// and follow the config's max age, verification level and `pyth_confidence_bps` bound.
+pub fn calculate_gas_token_usd_amount(
+    config: &Config,
+    gas_token: &GasToken,
+    amount: u64,
+    price_update: Option<&Account<PriceUpdateV2>>,
+) -> Result<u128> {
+    if gas_token.peg_usd > 0 {
+        let unit = 10u128
+            .checked_pow(gas_token.decimals as u32)
+            .ok_or(GatewayError::InvalidAmount)?;
+        return (amount as u128)
+            .checked_mul(gas_token.peg_usd as u128)
+            .and_then(|x| x.checked_div(unit))
+            .ok_or(error!(GatewayError::InvalidAmount));
+    }
+
+    let price_update = price_update.ok_or(error!(GatewayError::InvalidAccount))?;
+    require!(
+        price_update.key() == gas_token.price_feed,
+        GatewayError::InvalidAccount
+    );
+    let price_data = calculate_feed_price(
+        price_update,
+        &gas_token.feed_id,
+        config.pyth_max_age_seconds,
+        config.pyth_min_verification_signatures,
+    )?;
+    // Same relative bound as SOL pricing; the absolute threshold is in SOL/USD price units.
+    if config.pyth_confidence_bps > 0 {
+        require!(
+            confidence_within_bps(&price_data, config.pyth_confidence_bps),
+            GatewayError::LowPriceConfidence
+        );
+    }
+    calculate_token_usd_amount(amount, gas_token.decimals, &price_data)
+}
+
 /// View function for SOL price (locker-compatible)
 /// Anyone can fetch SOL price in USD
-/// This is the core utility function - the Anchor account struct wrapper is in instructions/price.rs
-pub fn get_sol_price(price_update: &Account<PriceUpdateV2>) -> Result<PriceData> {
-    calculate_sol_price(price_update)
+/// This is the core utility function - the Anchor account struct wrapper is in lib.rs
+/// Takes no config account, so only the native SOL `OracleRegistry` entry's policy applies.
+pub fn get_sol_price(
+    sol_oracle: &OracleRegistry,
+    price_update: &Account<PriceUpdateV2>,
+) -> Result<PriceData> {
+    price_for(&Pubkey::default(), sol_oracle, price_update)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE_SOL: u64 = 1_000_000_000;
+    // $150.25 in 8 decimals
+    const SOL_USD_8DEC: u128 = 15_025_000_000;
+
+    fn price(price: i64, exponent: i32, confidence: u64) -> PriceData {
+        PriceData {
+            price,
+            exponent,
+            publish_time: 0,
+            confidence,
+        }
+    }
+
+    #[test]
+    fn usd_amount_expo_minus_8() {
+        let p = price(15_025_000_000, -8, 0);
+        assert_eq!(calculate_usd_amount(ONE_SOL, &p).unwrap(), SOL_USD_8DEC);
+    }
+
+    #[test]
+    fn usd_amount_expo_minus_6() {
+        let p = price(150_250_000, -6, 0);
+        assert_eq!(calculate_usd_amount(ONE_SOL, &p).unwrap(), SOL_USD_8DEC);
+    }
+
+    #[test]
+    fn usd_amount_expo_minus_10() {
+        let p = price(1_502_500_000_000, -10, 0);
+        assert_eq!(calculate_usd_amount(ONE_SOL, &p).unwrap(), SOL_USD_8DEC);
+    }
+
+    #[test]
+    fn usd_amount_expo_zero() {
+        let p = price(150, 0, 0);
+        assert_eq!(calculate_usd_amount(ONE_SOL, &p).unwrap(), 15_000_000_000);
+    }
+
+    #[test]
+    fn usd_amount_truncates_dust() {
+        let p = price(15_025_000_000, -8, 0);
+        assert_eq!(calculate_usd_amount(1, &p).unwrap(), 15);
+        assert_eq!(calculate_usd_amount(0, &p).unwrap(), 0);
+    }
+
+    #[test]
+    fn token_usd_amount_six_decimals() {
+        // 2.5 USDC at $1.00
+        let p = price(100_000_000, -8, 0);
+        assert_eq!(calculate_token_usd_amount(2_500_000, 6, &p).unwrap(), 250_000_000);
+    }
+
+    #[test]
+    fn usd_to_lamports_rounds_up() {
+        let p = price(15_025_000_000, -8, 0);
+        assert_eq!(usd_to_lamports(15_025_000_000, &p).unwrap(), ONE_SOL);
+        // $0.50 / $150.25 = 3_327_787.02 lamports
+        assert_eq!(usd_to_lamports(50_000_000, &p).unwrap(), 3_327_788);
+        assert_eq!(usd_to_lamports(0, &p).unwrap(), 0);
+    }
+
+    #[test]
+    fn usd_amount_overflow_errors() {
+        let p = price(i64::MAX, 0, 0);
+        assert!(calculate_usd_amount(u64::MAX, &p).is_err());
+    }
+
+    #[test]
+    fn confidence_bps_is_scale_independent() {
+        // 0.1% confidence at two different exponents
+        let expo_8 = price(15_025_000_000, -8, 15_025_000);
+        let expo_5 = price(15_025_000, -5, 15_025);
+        for p in [&expo_8, &expo_5] {
+            assert!(confidence_within_bps(p, 10));
+            assert!(!confidence_within_bps(p, 9));
+        }
+    }
+
+    #[test]
+    fn deviation_bps_bounds_both_directions() {
+        let reference = 15_025_000_000;
+        // 5% = 500 bps either way
+        assert!(deviation_within_bps(reference, 15_776_250_000, 500));
+        assert!(deviation_within_bps(reference, 14_273_750_000, 500));
+        assert!(!deviation_within_bps(reference, 15_776_250_001, 500));
+        assert!(!deviation_within_bps(reference, 14_273_749_999, 500));
+        assert!(deviation_within_bps(reference, reference, 0));
+    }
+
+    #[test]
+    fn confidence_bps_rejects_non_positive_price() {
+        assert!(!confidence_within_bps(&price(0, -8, 1), 10_000));
+        assert!(!confidence_within_bps(&price(-1, -8, 1), 10_000));
+    }
 }
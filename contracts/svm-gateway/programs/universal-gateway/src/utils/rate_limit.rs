@@ -37,11 +37,20 @@ pub fn check_block_usd_cap(
 }
 
 /// Consume rate limit for a token (matching EVM _consumeRateLimit)
+/// @dev In USD mode the deposit's USD value (priced via the token's `OracleRegistry`) is
+///      accumulated instead of `amount`; it must be supplied.
 pub fn consume_rate_limit(
     token_rate_limit: &mut Account<TokenRateLimit>,
     amount: u128,
+    usd_amount: Option<u128>,
     epoch_duration_sec: u64,
 ) -> Result<()> {
+    let amount = if token_rate_limit.usd_mode {
+        usd_amount.ok_or(GatewayError::InvalidAccount)?
+    } else {
+        amount
+    };
+
     let clock = Clock::get()?;
     let current_epoch = clock.unix_timestamp as u64 / epoch_duration_sec;
 
@@ -65,6 +74,37 @@ pub fn consume_rate_limit(
     Ok(())
 }
 
+/// Consume the global all-token epoch USD cap (disabled if `epoch_usd_cap == 0`).
+pub fn consume_epoch_usd_cap(
+    rate_limit_config: &mut Account<RateLimitConfig>,
+    usd_amount: Option<u128>,
+    epoch_duration_sec: u64,
+) -> Result<()> {
+    if rate_limit_config.epoch_usd_cap == 0 {
+        return Ok(());
+    }
+    let usd_amount = usd_amount.ok_or(GatewayError::InvalidAccount)?;
+
+    let current_epoch = Clock::get()?.unix_timestamp as u64 / epoch_duration_sec;
+    if current_epoch > rate_limit_config.epoch_usd_usage.epoch {
+        rate_limit_config.epoch_usd_usage.epoch = current_epoch;
+        rate_limit_config.epoch_usd_usage.used = 0;
+    }
+
+    let new_used = rate_limit_config
+        .epoch_usd_usage
+        .used
+        .checked_add(usd_amount)
+        .ok_or(GatewayError::EpochUsdCapExceeded)?;
+    require!(
+        new_used <= rate_limit_config.epoch_usd_cap,
+        GatewayError::EpochUsdCapExceeded
+    );
+    rate_limit_config.epoch_usd_usage.used = new_used;
+
+    Ok(())
+}
+
 /// Validate token support and consume rate limit if enabled (EVM v0 parity)
 /// @dev Checks if token is supported (limit_threshold > 0) and optionally consumes rate limit
 ///      if epoch_duration > 0. This consolidates the threshold check used in send_universal_tx routes.
@@ -72,7 +112,8 @@ pub fn validate_token_and_consume_rate_limit(
     token_rate_limit: &mut Account<TokenRateLimit>,
     expected_token_mint: Pubkey,
     amount: u128,
-    rate_limit_config: &Account<RateLimitConfig>,
+    usd_amount: Option<u128>,
+    rate_limit_config: &mut Account<RateLimitConfig>,
 ) -> Result<()> {
     // Validate token_rate_limit account matches expected token
     require!(
@@ -90,7 +131,8 @@ pub fn validate_token_and_consume_rate_limit(
     // Epoch-based token rate limit (skip if disabled: epoch_duration == 0)
     let epoch_duration = rate_limit_config.epoch_duration_sec;
     if epoch_duration > 0 {
-        consume_rate_limit(token_rate_limit, amount, epoch_duration)?;
+        consume_rate_limit(token_rate_limit, amount, usd_amount, epoch_duration)?;
+        consume_epoch_usd_cap(rate_limit_config, usd_amount, epoch_duration)?;
     }
 
     Ok(())
@@ -2,6 +2,7 @@ use crate::errors::GatewayError;
 use crate::state::{FeeVault, ProtocolFeeReimbursed, VAULT_SEED};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
+use anchor_spl::associated_token::spl_associated_token_account;
 use anchor_spl::token::spl_token;
 
 /// Transfer SOL from a PDA signer to a destination account.
@@ -56,6 +57,46 @@ pub fn pda_spl_transfer<'info>(
     Ok(())
 }
 
+/// Create the associated token account for `owner`/`mint` if it does not exist yet.
+/// Idempotent: a no-op when the account already holds data. `payer` funds the rent.
+pub fn create_ata_if_missing<'info>(
+    payer: &AccountInfo<'info>,
+    ata: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    ata_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+) -> Result<()> {
+    if !ata.data_is_empty() {
+        return Ok(());
+    }
+
+    let create_ata_ix = spl_associated_token_account::instruction::create_associated_token_account(
+        payer.key,
+        owner.key,
+        mint.key,
+        &spl_token::ID,
+    );
+    invoke_signed(
+        &create_ata_ix,
+        &[
+            payer.clone(),
+            ata.clone(),
+            owner.clone(),
+            mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            ata_program.clone(),
+            rent.clone(),
+        ],
+        &[],
+    )?;
+
+    Ok(())
+}
+
 /// Reimburse relayer gas from the fee vault while preserving rent exemption.
 pub fn reimburse_relayer_from_fee_vault<'info>(
     fee_vault: &Account<'info, FeeVault>,
@@ -11,6 +11,20 @@ pub fn parse_token_account(account: &AccountInfo) -> Result<SplAccount> {
     SplAccount::unpack(&data).map_err(|_| error!(GatewayError::InvalidAccount))
 }
 
+/// Load an optional account from a fixed remaining_accounts slot.
+/// `None` when the slot holds the gateway program id.
+pub fn optional_slot_account<'info, T>(
+    info: &'info AccountInfo<'info>,
+) -> Result<Option<Account<'info, T>>>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    if info.key() == crate::ID {
+        return Ok(None);
+    }
+    Account::try_from(info).map(Some)
+}
+
 /// Validate remaining_accounts match signed accounts.
 /// CRITICAL: No account in remaining_accounts can have is_signer == true.
 /// Only gateway PDAs (vault, cea_authority) become signers via invoke_signed.
@@ -6,6 +6,8 @@ import { expect } from "chai";
 import * as sharedState from "./shared-state";
 import { getTssEthAddress, TSS_CHAIN_ID } from "./helpers/tss";
 import { ensureTestSetup } from "./helpers/test-setup";
+import { getTxEvents } from "./helpers/test-utils";
+import { SOL_FEED_ID } from "./setup-pricefeed";
 
 
 describe("Universal Gateway - Admin Functions Tests", () => {
@@ -30,6 +32,7 @@ describe("Universal Gateway - Admin Functions Tests", () => {
     let vaultPda: PublicKey;
     let tssPda: PublicKey;
     let rateLimitConfigPda: PublicKey;
+    let solOraclePda: PublicKey;
 
     // Mock assets
     let mockPriceFeed: PublicKey;
@@ -78,6 +81,11 @@ describe("Universal Gateway - Admin Functions Tests", () => {
             program.programId
         );
 
+        [solOraclePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("oracle"), PublicKey.default.toBuffer()],
+            program.programId
+        );
+
         const config = await program.account.config.fetch(configPda);
         expect(config.admin.toString()).to.equal(admin.publicKey.toString());
         expect(config.pauser.toString()).to.equal(pauser.publicKey.toString());
@@ -358,10 +366,25 @@ describe("Universal Gateway - Admin Functions Tests", () => {
         it("Updates Pyth configuration", async () => {
             const newPriceFeed = Keypair.generate().publicKey;
             const newConfidenceThreshold = new anchor.BN(2000000);
+            const setSolOracle = (priceFeed: PublicKey) =>
+                program.methods
+                    .setOracle(priceFeed, Array.from(Buffer.from(SOL_FEED_ID, "hex")), new anchor.BN(0), 0, 0)
+                    .accountsPartial({
+                        config: configPda,
+                        oracle: solOraclePda,
+                        tokenMint: PublicKey.default,
+                        admin: admin.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .signers([admin])
+                    .rpc();
+
+            // Update the SOL/USD feed (native SOL oracle registry entry)
+            await setSolOracle(newPriceFeed);
 
-            // Update price feed
+            // Update confidence threshold
             await program.methods
-                .setPythPriceFeed(newPriceFeed)
+                .setPythConfidenceThreshold(newConfidenceThreshold)
                 .accountsPartial({
                     admin: admin.publicKey,
                     config: configPda,
@@ -369,23 +392,40 @@ describe("Universal Gateway - Admin Functions Tests", () => {
                 .signers([admin])
                 .rpc();
 
-            // Update confidence threshold
-            await program.methods
-                .setPythConfidenceThreshold(newConfidenceThreshold)
+            const config = await program.account.config.fetch(configPda);
+            const solOracle = await program.account.oracleRegistry.fetch(solOraclePda);
+            expect(solOracle.priceFeed.toString()).to.equal(newPriceFeed.toString());
+            expect(config.pythConfidenceThreshold.toString()).to.equal(newConfidenceThreshold.toString());
+
+            // Restore original price feed for other tests
+            await setSolOracle(mockPriceFeed);
+        });
+
+        it("Updates the swap adapter and emits SwapAdapterUpdated", async () => {
+            const previousAdapter = (await program.account.config.fetch(configPda)).swapAdapter;
+            const newAdapter = Keypair.generate().publicKey;
+
+            const tx = await program.methods
+                .setSwapAdapter(newAdapter)
                 .accountsPartial({
                     admin: admin.publicKey,
                     config: configPda,
                 })
                 .signers([admin])
-                .rpc();
+                .rpc({ commitment: "confirmed" });
 
             const config = await program.account.config.fetch(configPda);
-            expect(config.pythPriceFeed.toString()).to.equal(newPriceFeed.toString());
-            expect(config.pythConfidenceThreshold.toString()).to.equal(newConfidenceThreshold.toString());
+            expect(config.swapAdapter.toString()).to.equal(newAdapter.toString());
 
-            // Restore original price feed for other tests
+            const event = (await getTxEvents(provider.connection, program, tx))
+                .find((e) => e?.name === "swapAdapterUpdated");
+            expect(event, "SwapAdapterUpdated event not found").to.exist;
+            expect(event.data.old.toString()).to.equal(previousAdapter.toString());
+            expect(event.data.new.toString()).to.equal(newAdapter.toString());
+
+            // Restore the previous adapter for other tests
             await program.methods
-                .setPythPriceFeed(mockPriceFeed)
+                .setSwapAdapter(previousAdapter)
                 .accountsPartial({
                     admin: admin.publicKey,
                     config: configPda,
@@ -576,6 +616,7 @@ describe("Universal Gateway - Admin Functions Tests", () => {
             const priceData = await program.methods
                 .getSolPrice()
                 .accountsPartial({
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                 })
                 .view();
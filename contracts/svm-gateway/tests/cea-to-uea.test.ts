@@ -12,12 +12,14 @@ import {
 } from "@solana/spl-token";
 import * as spl from "@solana/spl-token";
 import * as sharedState from "./shared-state";
+import { getSolPrice, calculateSolAmount } from "./setup-pricefeed";
 import {
   signTssMessage,
   buildExecuteAdditionalData,
   TssInstruction,
   GatewayAccountMeta,
   generateUniversalTxId,
+  buildSweepCeaAdditionalData,
 } from "./helpers/tss";
 import { ensureTestSetup } from "./helpers/test-setup";
 import {
@@ -65,6 +67,8 @@ describe("Universal Gateway - CEA to UEA Tests", () => {
   let rateLimitConfigPda: PublicKey;
   let nativeSolTokenRateLimitPda: PublicKey;
   let usdtTokenRateLimitPda: PublicKey;
+  let mockPriceFeed: PublicKey;
+  let solPrice: number;
 
   let mockUSDT: any;
   let vaultUsdtAccount: PublicKey;
@@ -86,6 +90,8 @@ describe("Universal Gateway - CEA to UEA Tests", () => {
     admin = sharedState.getAdmin();
     mockUSDT = sharedState.getMockUSDT();
     counterAuthority = sharedState.getCounterAuthority();
+    mockPriceFeed = sharedState.getMockPriceFeed();
+    solPrice = await getSolPrice(mockPriceFeed);
 
     const airdropLamports = 100 * anchor.web3.LAMPORTS_PER_SOL;
     await Promise.all([
@@ -294,6 +300,7 @@ describe("Universal Gateway - CEA to UEA Tests", () => {
         })(),
         Buffer.from([0, 0, 0, 0]), // payload = empty Vec<u8> (Borsh: 4-byte LE length = 0)
         cea.toBuffer(), // revert_recipient = CEA itself
+        Buffer.from([0]), // gas_amount = None
       ]);
       const withdrawIxData = Buffer.concat([withdrawDiscr, withdrawArgs]);
 
@@ -362,6 +369,70 @@ describe("Universal Gateway - CEA to UEA Tests", () => {
       expect(ceaBalAfter).to.equal(0);
     });
 
+    it("should accept the original args layout without the gas_amount byte", async () => {
+      const pushAccount = generateSender();
+      const cea = getCeaAuthorityPda(pushAccount);
+      const withdrawAmount = 2_000_000;
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: admin.publicKey,
+            toPubkey: cea,
+            lamports: withdrawAmount,
+          })
+        ),
+        [admin]
+      );
+
+      const subTxId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const amountBuf = Buffer.alloc(8);
+      amountBuf.writeBigUInt64LE(BigInt(withdrawAmount));
+      // Relayers that predate the gas leg end the args at revert_recipient.
+      const legacyIxData = Buffer.concat([
+        computeDiscriminator("global:send_universal_tx_to_uea"),
+        Buffer.alloc(32, 0), // token = Pubkey::default()
+        amountBuf,
+        Buffer.from([0, 0, 0, 0]), // payload = empty Vec<u8>
+        cea.toBuffer(), // revert_recipient
+      ]);
+      const { gasFee } = await calculateSolExecuteFees(provider.connection);
+      const sig = await signTssMessage({
+        instruction: TssInstruction.Execute,
+        amount: BigInt(0),
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildExecuteAdditionalData(
+          new Uint8Array(universalTxId),
+          new Uint8Array(subTxId),
+          gatewayProgram.programId,
+          new Uint8Array(pushAccount),
+          [],
+          legacyIxData,
+          gasFee
+        ),
+      });
+
+      await finalizeUniversalTx({
+        instructionId: 2,
+        subTxId,
+        universalTxId,
+        amount: new anchor.BN(0),
+        pushAccount,
+        writableFlags: accountsToWritableFlagsOnly([]),
+        ixData: legacyIxData,
+        gasFee: new anchor.BN(Number(gasFee)),
+        sig,
+        caller: admin.publicKey,
+        destinationProgram: gatewayProgram.programId,
+        rateLimitConfig: rateLimitConfigPda,
+        tokenRateLimit: nativeSolTokenRateLimitPda,
+      })
+        .signers([admin])
+        .rpc();
+
+      expect(await provider.connection.getBalance(cea)).to.equal(0);
+    });
+
     it("should emit FundsAndPayload + from_cea when CEA withdrawal has non-empty payload", async () => {
       const pushAccount = generateSender();
       const cea = getCeaAuthorityPda(pushAccount);
@@ -450,6 +521,7 @@ describe("Universal Gateway - CEA to UEA Tests", () => {
           return Buffer.concat([lenBuf, ceaPayload]);
         })(),
         cea.toBuffer(), // revert_recipient = CEA itself
+        Buffer.from([0]), // gas_amount = None
       ]);
       const withdrawIxData = Buffer.concat([withdrawDiscr, withdrawArgs]);
 
@@ -556,6 +628,342 @@ describe("Universal Gateway - CEA to UEA Tests", () => {
       );
     });
 
+    it("should emit GasAndPayload + from_cea for a payload-only (zero amount) CEA message", async () => {
+      const pushAccount = generateSender();
+      const cea = getCeaAuthorityPda(pushAccount);
+
+      const txId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const withdrawDiscr = computeDiscriminator("global:send_universal_tx_to_uea");
+      const ceaPayload = Buffer.from([0xde, 0xad, 0xbe, 0xef]);
+      const { gasFee } = await calculateSolExecuteFees(provider.connection);
+
+      const withdrawArgs = Buffer.concat([
+        Buffer.alloc(32, 0), // token = Pubkey::default() (SOL)
+        Buffer.alloc(8, 0), // amount = 0 → payload-only
+        (() => {
+          const lenBuf = Buffer.alloc(4);
+          lenBuf.writeUInt32LE(ceaPayload.length, 0);
+          return Buffer.concat([lenBuf, ceaPayload]);
+        })(),
+        cea.toBuffer(), // revert_recipient = CEA itself
+        Buffer.from([0]), // gas_amount = None
+      ]);
+      const withdrawIxData = Buffer.concat([withdrawDiscr, withdrawArgs]);
+
+      const sig = await signTssMessage({
+        instruction: TssInstruction.Execute,
+        amount: BigInt(0),
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildExecuteAdditionalData(
+          new Uint8Array(universalTxId),
+          new Uint8Array(txId),
+          gatewayProgram.programId,
+          new Uint8Array(pushAccount),
+          [],
+          withdrawIxData,
+          gasFee
+        ),
+      });
+
+      const ceaBalBefore = await provider.connection.getBalance(cea);
+
+      // No rate-limit accounts: payload-only messages move no funds.
+      const tx = await finalizeUniversalTx({
+        instructionId: 2,
+        subTxId: txId,
+        universalTxId,
+        amount: new anchor.BN(0),
+        pushAccount,
+        writableFlags: accountsToWritableFlagsOnly([]),
+        ixData: withdrawIxData,
+        gasFee: new anchor.BN(Number(gasFee)),
+        sig,
+        caller: admin.publicKey,
+        destinationProgram: gatewayProgram.programId,
+      })
+        .signers([admin])
+        .rpc();
+
+      expect(await provider.connection.getBalance(cea)).to.equal(ceaBalBefore);
+
+      let txDetails = null;
+      for (let attempt = 0; attempt < 10; attempt++) {
+        txDetails = await provider.connection.getTransaction(tx, {
+          commitment: "confirmed",
+          maxSupportedTransactionVersion: 0,
+        });
+        if (txDetails) break;
+        await new Promise((r) => setTimeout(r, 500));
+      }
+      expect(txDetails, "getTransaction returned null after retries").to.exist;
+
+      const eventCoder = new anchor.BorshEventCoder(gatewayProgram.idl);
+      const events = (txDetails.meta?.logMessages ?? [])
+        .filter((log) => log.includes("Program data:"))
+        .map((log) => {
+          try {
+            return eventCoder.decode(log.split("Program data: ")[1]);
+          } catch {
+            return null;
+          }
+        })
+        .filter((e) => e !== null);
+
+      const universalTxEvent = events.find((e) => e.name === "universalTx");
+      expect(universalTxEvent, "UniversalTx event not found").to.exist;
+      expect(
+        universalTxEvent.data.txType.gasAndPayload !== undefined,
+        "txType should be GasAndPayload"
+      ).to.be.true;
+      expect(universalTxEvent.data.fromCea, "from_cea should be true").to.be
+        .true;
+      expect(universalTxEvent.data.amount.toString()).to.equal("0");
+      expect(universalTxEvent.data.sender.toString()).to.equal(cea.toString());
+      expect(
+        Buffer.from(universalTxEvent.data.payload).toString("hex")
+      ).to.equal(ceaPayload.toString("hex"));
+    });
+
+    it("rejects a payload-only CEA message larger than MAX_CEA_PAYLOAD_LEN", async () => {
+      const pushAccount = generateSender();
+      const cea = getCeaAuthorityPda(pushAccount);
+
+      const txId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const withdrawDiscr = computeDiscriminator("global:send_universal_tx_to_uea");
+      const oversizedPayload = Buffer.alloc(1025, 0x11);
+      const { gasFee } = await calculateSolExecuteFees(provider.connection);
+
+      const lenBuf = Buffer.alloc(4);
+      lenBuf.writeUInt32LE(oversizedPayload.length, 0);
+      const withdrawIxData = Buffer.concat([
+        withdrawDiscr,
+        Buffer.alloc(32, 0), // token = Pubkey::default()
+        Buffer.alloc(8, 0), // amount = 0
+        lenBuf,
+        oversizedPayload,
+        cea.toBuffer(),
+        Buffer.from([0]), // gas_amount = None
+      ]);
+
+      const sig = await signTssMessage({
+        instruction: TssInstruction.Execute,
+        amount: BigInt(0),
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildExecuteAdditionalData(
+          new Uint8Array(universalTxId),
+          new Uint8Array(txId),
+          gatewayProgram.programId,
+          new Uint8Array(pushAccount),
+          [],
+          withdrawIxData,
+          gasFee
+        ),
+      });
+
+      try {
+        await finalizeUniversalTx({
+          instructionId: 2,
+          subTxId: txId,
+          universalTxId,
+          amount: new anchor.BN(0),
+          pushAccount,
+          writableFlags: accountsToWritableFlagsOnly([]),
+          ixData: withdrawIxData,
+          gasFee: new anchor.BN(Number(gasFee)),
+          sig,
+          caller: admin.publicKey,
+          destinationProgram: gatewayProgram.programId,
+        })
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have thrown PayloadTooLarge");
+      } catch (err: any) {
+        expect(err.toString()).to.include("PayloadTooLarge");
+      }
+    });
+
+    it("should emit a separate Gas event for a CEA gas leg alongside the funds", async () => {
+      const pushAccount = generateSender();
+      const cea = getCeaAuthorityPda(pushAccount);
+
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: admin.publicKey,
+            toPubkey: cea,
+            lamports: asLamports(0.5).toNumber(),
+          })
+        ),
+        [admin]
+      );
+
+      const txId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const withdrawDiscr = computeDiscriminator("global:send_universal_tx_to_uea");
+      const { gasFee } = await calculateSolExecuteFees(provider.connection);
+
+      // $2.50 gas leg (within the $1-$10 caps); the rest of the CEA balance is bridged as funds.
+      const gasAmount = BigInt(calculateSolAmount(2.5, solPrice));
+      const ceaBalBefore = BigInt(await provider.connection.getBalance(cea));
+      const fundsAmount = ceaBalBefore - gasAmount;
+
+      const amountBuf = Buffer.alloc(8);
+      amountBuf.writeBigUInt64LE(fundsAmount);
+      const gasAmountBuf = Buffer.alloc(8);
+      gasAmountBuf.writeBigUInt64LE(gasAmount);
+      const withdrawIxData = Buffer.concat([
+        withdrawDiscr,
+        Buffer.alloc(32, 0), // token = Pubkey::default() (SOL)
+        amountBuf,
+        Buffer.from([0, 0, 0, 0]), // empty payload
+        cea.toBuffer(), // revert_recipient = CEA itself
+        Buffer.from([1]), // gas_amount = Some(..)
+        gasAmountBuf,
+      ]);
+
+      const sig = await signTssMessage({
+        instruction: TssInstruction.Execute,
+        amount: BigInt(0),
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildExecuteAdditionalData(
+          new Uint8Array(universalTxId),
+          new Uint8Array(txId),
+          gatewayProgram.programId,
+          new Uint8Array(pushAccount),
+          [],
+          withdrawIxData,
+          gasFee
+        ),
+      });
+
+      const tx = await finalizeUniversalTx({
+        instructionId: 2,
+        subTxId: txId,
+        universalTxId,
+        amount: new anchor.BN(0),
+        pushAccount,
+        writableFlags: accountsToWritableFlagsOnly([]),
+        ixData: withdrawIxData,
+        gasFee: new anchor.BN(Number(gasFee)),
+        sig,
+        caller: admin.publicKey,
+        destinationProgram: gatewayProgram.programId,
+        rateLimitConfig: rateLimitConfigPda,
+        tokenRateLimit: nativeSolTokenRateLimitPda,
+        priceUpdate: mockPriceFeed,
+      })
+        .signers([admin])
+        .rpc();
+
+      expect(await provider.connection.getBalance(cea)).to.equal(0);
+
+      let txDetails = null;
+      for (let attempt = 0; attempt < 10; attempt++) {
+        txDetails = await provider.connection.getTransaction(tx, {
+          commitment: "confirmed",
+          maxSupportedTransactionVersion: 0,
+        });
+        if (txDetails) break;
+        await new Promise((r) => setTimeout(r, 500));
+      }
+      expect(txDetails, "getTransaction returned null after retries").to.exist;
+
+      const eventCoder = new anchor.BorshEventCoder(gatewayProgram.idl);
+      const universalTxEvents = (txDetails.meta?.logMessages ?? [])
+        .filter((log) => log.includes("Program data:"))
+        .map((log) => {
+          try {
+            return eventCoder.decode(log.split("Program data: ")[1]);
+          } catch {
+            return null;
+          }
+        })
+        .filter((e) => e !== null && e.name === "universalTx");
+
+      expect(universalTxEvents).to.have.length(2);
+      const [gasEvent, fundsEvent] = universalTxEvents;
+      expect(gasEvent.data.txType.gas !== undefined, "first event should be Gas").to
+        .be.true;
+      expect(gasEvent.data.amount.toString()).to.equal(gasAmount.toString());
+      expect(gasEvent.data.fromCea).to.be.true;
+      expect(fundsEvent.data.txType.funds !== undefined, "second event should be Funds")
+        .to.be.true;
+      expect(fundsEvent.data.amount.toString()).to.equal(fundsAmount.toString());
+    });
+
+    it("rejects a CEA gas leg below the USD min cap", async () => {
+      const pushAccount = generateSender();
+      const cea = getCeaAuthorityPda(pushAccount);
+
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: admin.publicKey,
+            toPubkey: cea,
+            lamports: asLamports(0.1).toNumber(),
+          })
+        ),
+        [admin]
+      );
+
+      const txId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const withdrawDiscr = computeDiscriminator("global:send_universal_tx_to_uea");
+      const { gasFee } = await calculateSolExecuteFees(provider.connection);
+
+      const gasAmountBuf = Buffer.alloc(8);
+      gasAmountBuf.writeBigUInt64LE(BigInt(calculateSolAmount(0.1, solPrice)));
+      const withdrawIxData = Buffer.concat([
+        withdrawDiscr,
+        Buffer.alloc(32, 0), // token = Pubkey::default()
+        Buffer.alloc(8, 0), // amount = 0 → gas-only top-up
+        Buffer.from([0, 0, 0, 0]), // empty payload
+        cea.toBuffer(),
+        Buffer.from([1]), // gas_amount = Some(..)
+        gasAmountBuf,
+      ]);
+
+      const sig = await signTssMessage({
+        instruction: TssInstruction.Execute,
+        amount: BigInt(0),
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildExecuteAdditionalData(
+          new Uint8Array(universalTxId),
+          new Uint8Array(txId),
+          gatewayProgram.programId,
+          new Uint8Array(pushAccount),
+          [],
+          withdrawIxData,
+          gasFee
+        ),
+      });
+
+      try {
+        await finalizeUniversalTx({
+          instructionId: 2,
+          subTxId: txId,
+          universalTxId,
+          amount: new anchor.BN(0),
+          pushAccount,
+          writableFlags: accountsToWritableFlagsOnly([]),
+          ixData: withdrawIxData,
+          gasFee: new anchor.BN(Number(gasFee)),
+          sig,
+          caller: admin.publicKey,
+          destinationProgram: gatewayProgram.programId,
+          rateLimitConfig: rateLimitConfigPda,
+          priceUpdate: mockPriceFeed,
+        })
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have thrown BelowMinCap");
+      } catch (err: any) {
+        expect(err.toString()).to.include("BelowMinCap");
+      }
+    });
+
     it("rejects CEA → UEA self-call with zero revert_recipient", async () => {
       const pushAccount = generateSender();
       const cea = getCeaAuthorityPda(pushAccount);
@@ -582,6 +990,7 @@ describe("Universal Gateway - CEA to UEA Tests", () => {
         (() => { const b = Buffer.alloc(8); b.writeBigUInt64LE(drainAmount); return b; })(),
         Buffer.from([0, 0, 0, 0]), // empty payload
         Buffer.alloc(32, 0),       // revert_recipient = Pubkey::default() ← invalid
+        Buffer.from([0]), // gas_amount = None
       ]);
       const withdrawIxData = Buffer.concat([withdrawDiscr, withdrawArgs]);
 
@@ -745,6 +1154,7 @@ describe("Universal Gateway - CEA to UEA Tests", () => {
         })(),
         Buffer.from([0, 0, 0, 0]), // payload = empty Vec<u8> (Borsh: 4-byte LE length = 0)
         cea.toBuffer(), // revert_recipient = CEA itself
+        Buffer.from([0]), // gas_amount = None
       ]);
       const withdrawIxData = Buffer.concat([withdrawDiscr, withdrawArgs]);
 
@@ -825,4 +1235,228 @@ describe("Universal Gateway - CEA to UEA Tests", () => {
       expect(Number(ceaAtaAfter.value.amount)).to.equal(0);
     });
   });
+
+  describe("CEA sweep (sweep_cea)", () => {
+    // [token_rate_limit, oracle, oracle_price_update]; the program id marks an absent oracle.
+    const creditSlot = (tokenRateLimit: PublicKey) => [
+      { pubkey: tokenRateLimit, isWritable: true, isSigner: false },
+      { pubkey: gatewayProgram.programId, isWritable: false, isSigner: false },
+      { pubkey: gatewayProgram.programId, isWritable: false, isSigner: false },
+    ];
+
+    const sweepCea = (
+      subTxId: number[],
+      universalTxId: number[],
+      pushAccount: number[],
+      tokenAccounts: PublicKey[],
+      vaultAtas: PublicKey[],
+      tokenRateLimits: PublicKey[],
+      gasFee: bigint,
+      sig: { signature: number[]; recoveryId: number; messageHash: number[] }
+    ) =>
+      gatewayProgram.methods
+        .sweepCea(
+          Array.from(subTxId),
+          Array.from(universalTxId),
+          Array.from(pushAccount),
+          0, // cea_index
+          tokenAccounts,
+          new anchor.BN(gasFee.toString()),
+          sig.signature,
+          sig.recoveryId,
+          sig.messageHash
+        )
+        .accountsPartial({
+          config: configPda,
+          vaultSol: vaultPda,
+          ceaAuthority: getCeaAuthorityPda(pushAccount),
+          tssPda,
+          rateLimitConfig: rateLimitConfigPda,
+          executedSubTx: getExecutedTxPda(subTxId),
+          caller: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          ...tokenAccounts.flatMap((account, i) => [
+            { pubkey: account, isWritable: true, isSigner: false },
+            { pubkey: vaultAtas[i], isWritable: true, isSigner: false },
+            ...creditSlot(tokenRateLimits[i]),
+          ]),
+          ...creditSlot(nativeSolTokenRateLimitPda),
+        ])
+        .signers([admin]);
+
+    it("should close CEA token accounts, drain CEA lamports to the vault and credit only the balances", async () => {
+      const pushAccount = generateSender();
+      const cea = getCeaAuthorityPda(pushAccount);
+      const ceaAta = await getCeaAta(pushAccount, mockUSDT.mint.publicKey);
+
+      // Dust: lamports on the CEA PDA and tokens in a CEA ATA
+      const dustLamports = 3_000_000;
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: admin.publicKey,
+            toPubkey: cea,
+            lamports: dustLamports,
+          }),
+          createAssociatedTokenAccountInstruction(
+            admin.publicKey,
+            ceaAta,
+            cea,
+            mockUSDT.mint.publicKey,
+            TOKEN_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+          )
+        ),
+        [admin]
+      );
+      await mockUSDT.mintTo(ceaAta, 7);
+      const ataRent = (await provider.connection.getAccountInfo(ceaAta))!
+        .lamports;
+
+      const subTxId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const gasFee = BigInt(await getExecutedTxRent(provider.connection)) + COMPUTE_BUFFER;
+      const sig = await signTssMessage({
+        instruction: TssInstruction.SweepCea,
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildSweepCeaAdditionalData(
+          new Uint8Array(subTxId),
+          new Uint8Array(universalTxId),
+          new Uint8Array(pushAccount),
+          [ceaAta],
+          gasFee
+        ),
+      });
+
+      const setEpochDuration = (seconds: anchor.BN) =>
+        gatewayProgram.methods
+          .updateEpochDuration(seconds)
+          .accountsPartial({
+            admin: admin.publicKey,
+            config: configPda,
+            rateLimitConfig: rateLimitConfigPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      const previousEpochDuration = (
+        await gatewayProgram.account.rateLimitConfig.fetch(rateLimitConfigPda)
+      ).epochDurationSec;
+      await setEpochDuration(new anchor.BN(3600));
+      const usdtUsageBefore = (
+        await gatewayProgram.account.tokenRateLimit.fetch(usdtTokenRateLimitPda)
+      ).epochUsage;
+
+      const vaultSolBefore = await provider.connection.getBalance(vaultPda);
+      const vaultUsdtBefore = await mockUSDT.getBalance(vaultUsdtAccount);
+
+      const tx = await sweepCea(
+        subTxId,
+        universalTxId,
+        pushAccount,
+        [ceaAta],
+        [vaultUsdtAccount],
+        [usdtTokenRateLimitPda],
+        gasFee,
+        sig
+      )
+        .rpc({ commitment: "confirmed" })
+        .finally(() => setEpochDuration(previousEpochDuration));
+
+      expect(await provider.connection.getAccountInfo(ceaAta)).to.be.null;
+      expect(await provider.connection.getBalance(cea)).to.equal(0);
+      expect((await mockUSDT.getBalance(vaultUsdtAccount)) - vaultUsdtBefore).to.equal(7);
+      // The closed ATA's rent stays in the vault without being credited.
+      expect((await provider.connection.getBalance(vaultPda)) - vaultSolBefore).to.equal(
+        dustLamports + ataRent - Number(gasFee)
+      );
+
+      const usdtUsage = (
+        await gatewayProgram.account.tokenRateLimit.fetch(usdtTokenRateLimitPda)
+      ).epochUsage;
+      // Usage restarts from zero when the sweep opened a new epoch.
+      const expectedUsage = usdtUsage.epoch.eq(usdtUsageBefore.epoch)
+        ? usdtUsageBefore.used.addn(7)
+        : new anchor.BN(7);
+      expect(usdtUsage.used.toString()).to.equal(expectedUsage.toString());
+
+      const txDetails = await provider.connection.getTransaction(tx, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const eventCoder = new anchor.BorshEventCoder(gatewayProgram.idl);
+      const events = (txDetails?.meta?.logMessages ?? [])
+        .filter((log) => log.includes("Program data:"))
+        .map((log) => {
+          try {
+            return eventCoder.decode(log.split("Program data: ")[1]);
+          } catch {
+            return null;
+          }
+        })
+        .filter((e) => e !== null);
+      const credits = events.filter((e) => e.name === "universalTx");
+      const solCredit = credits.find((e) => e.data.token.equals(PublicKey.default));
+      const usdtCredit = credits.find((e) =>
+        e.data.token.equals(mockUSDT.mint.publicKey)
+      );
+      expect(solCredit.data.amount.toNumber()).to.equal(dustLamports);
+      expect(usdtCredit.data.amount.toNumber()).to.equal(7);
+      const swept = events.find((e) => e.name === "ceaSwept");
+      expect(swept.data.lamports.toNumber()).to.equal(dustLamports);
+    });
+
+    it("should reject a token account list that differs from the signed list", async () => {
+      const pushAccount = generateSender();
+      const cea = getCeaAuthorityPda(pushAccount);
+      const ceaAta = await getCeaAta(pushAccount, mockUSDT.mint.publicKey);
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountInstruction(
+            admin.publicKey,
+            ceaAta,
+            cea,
+            mockUSDT.mint.publicKey,
+            TOKEN_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+          )
+        ),
+        [admin]
+      );
+
+      const subTxId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const sig = await signTssMessage({
+        instruction: TssInstruction.SweepCea,
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildSweepCeaAdditionalData(
+          new Uint8Array(subTxId),
+          new Uint8Array(universalTxId),
+          new Uint8Array(pushAccount),
+          [], // TSS signed a lamports-only sweep
+          BigInt(0)
+        ),
+      });
+
+      try {
+        await sweepCea(
+          subTxId,
+          universalTxId,
+          pushAccount,
+          [ceaAta],
+          [vaultUsdtAccount],
+          [usdtTokenRateLimitPda],
+          BigInt(0),
+          sig
+        ).rpc();
+        expect.fail("Should reject unsigned token accounts");
+      } catch (error: any) {
+        expect(error.toString()).to.include("MessageHashMismatch");
+      }
+      expect(await provider.connection.getAccountInfo(ceaAta)).to.not.be.null;
+    });
+  });
 });
@@ -29,6 +29,8 @@ import {
   TssInstruction,
   GatewayAccountMeta,
   generateUniversalTxId,
+  NonceMode,
+  FinalizeFlags,
 } from "./helpers/tss";
 import { ensureTestSetup } from "./helpers/test-setup";
 import { createHash } from "crypto";
@@ -463,6 +465,11 @@ describe("Universal Gateway - Execute Tests", () => {
           writableFlags,
           Buffer.from(counterIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -571,6 +578,11 @@ describe("Universal Gateway - Execute Tests", () => {
           accountsToWritableFlagsOnly(preseedAccounts),
           Buffer.from(preseedIx.data),
           new anchor.BN(Number(preseedGasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(preseedSig.signature),
           preseedSig.recoveryId,
@@ -645,6 +657,11 @@ describe("Universal Gateway - Execute Tests", () => {
           accountsToWritableFlagsOnly(transferAccounts),
           Buffer.from(transferIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(transferSig.signature),
           transferSig.recoveryId,
@@ -742,6 +759,11 @@ describe("Universal Gateway - Execute Tests", () => {
           accountsToWritableFlagsOnly(accounts),
           Buffer.from(sysTransferIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -780,6 +802,154 @@ describe("Universal Gateway - Execute Tests", () => {
       expect(ceaAfter).to.equal(0);
     });
 
+    it("should sweep residual CEA lamports back to the vault when the sweep flag is signed", async () => {
+      const pushAccount = generateSender();
+      const ceaAuthority = getCeaAuthorityPda(pushAccount);
+
+      const topupLamports = 50_000_000; // 0.05 SOL released from vault
+      const spentLamports = 20_000_000; // target only uses 0.02 SOL
+      const residualLamports = topupLamports - spentLamports;
+
+      const subTxId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const sysTransferIx = anchor.web3.SystemProgram.transfer({
+        fromPubkey: ceaAuthority,
+        toPubkey: recipient.publicKey,
+        lamports: spentLamports,
+      });
+      const accounts = instructionAccountsToGatewayMetas(sysTransferIx);
+      const remainingAccounts = instructionAccountsToRemaining(sysTransferIx);
+      const { gasFee } = await calculateSolExecuteFees(provider.connection);
+
+      const sig = await signTssMessage({
+        instruction: TssInstruction.Execute,
+        amount: BigInt(topupLamports),
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildExecuteAdditionalData(
+          new Uint8Array(universalTxId),
+          new Uint8Array(subTxId),
+          anchor.web3.SystemProgram.programId,
+          new Uint8Array(pushAccount),
+          accounts,
+          sysTransferIx.data,
+          gasFee,
+          PublicKey.default,
+          BigInt(0),
+          NonceMode.Unordered,
+          FinalizeFlags.SweepCea
+        ),
+      });
+
+      // Swept credits consume the native rate limit like a CEA -> UEA credit.
+      const setEpochDuration = (seconds: anchor.BN) =>
+        gatewayProgram.methods
+          .updateEpochDuration(seconds)
+          .accountsPartial({
+            admin: admin.publicKey,
+            config: configPda,
+            rateLimitConfig: rateLimitConfigPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      const previousEpochDuration = (
+        await gatewayProgram.account.rateLimitConfig.fetch(rateLimitConfigPda)
+      ).epochDurationSec;
+      await setEpochDuration(new anchor.BN(3600));
+      const nativeUsageBefore = (
+        await gatewayProgram.account.tokenRateLimit.fetch(nativeSolTokenRateLimitPda)
+      ).epochUsage;
+
+      const vaultBefore = await provider.connection.getBalance(vaultPda);
+
+      const tx = await gatewayProgram.methods
+        .finalizeUniversalTx(
+          2,
+          Array.from(subTxId),
+          Array.from(universalTxId),
+          new anchor.BN(topupLamports),
+          Array.from(pushAccount),
+          accountsToWritableFlagsOnly(accounts),
+          Buffer.from(sysTransferIx.data),
+          new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          FinalizeFlags.SweepCea, // flags
+          [], // token_legs
+          0, // cea_index
+          Array.from(sig.signature),
+          sig.recoveryId,
+          Array.from(sig.messageHash)
+        )
+        .accountsPartial({
+          caller: admin.publicKey,
+          config: configPda,
+          vaultSol: vaultPda,
+          ceaAuthority,
+          tssPda,
+          executedSubTx: getExecutedTxPda(subTxId),
+          rateLimitConfig: rateLimitConfigPda,
+          tokenRateLimit: null,
+          destinationProgram: anchor.web3.SystemProgram.programId,
+          recipient: null,
+          vaultAta: null,
+          ceaAta: null,
+          mint: null,
+          tokenProgram: null,
+          rent: null,
+          associatedTokenProgram: null,
+          recipientAta: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...remainingAccounts,
+          // Native SOL credit slot: [token_rate_limit, oracle, oracle_price_update]
+          { pubkey: nativeSolTokenRateLimitPda, isWritable: true, isSigner: false },
+          { pubkey: gatewayProgram.programId, isWritable: false, isSigner: false },
+          { pubkey: gatewayProgram.programId, isWritable: false, isSigner: false },
+        ])
+        .signers([admin])
+        .rpc({ commitment: "confirmed" })
+        .finally(() => setEpochDuration(previousEpochDuration));
+
+      const vaultAfter = await provider.connection.getBalance(vaultPda);
+      const ceaAfter = await provider.connection.getBalance(ceaAuthority);
+
+      expect(ceaAfter).to.equal(0);
+      const nativeUsage = (
+        await gatewayProgram.account.tokenRateLimit.fetch(nativeSolTokenRateLimitPda)
+      ).epochUsage;
+      // Usage restarts from zero when the sweep opened a new epoch.
+      const expectedUsage = nativeUsage.epoch.eq(nativeUsageBefore.epoch)
+        ? nativeUsageBefore.used.addn(residualLamports)
+        : new anchor.BN(residualLamports);
+      expect(nativeUsage.used.toString()).to.equal(expectedUsage.toString());
+      expect(vaultBefore - vaultAfter).to.equal(
+        topupLamports + Number(gasFee) - residualLamports
+      );
+
+      const txDetails = await provider.connection.getTransaction(tx, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const eventCoder = new anchor.BorshEventCoder(gatewayProgram.idl);
+      const sweepEvents = (txDetails?.meta?.logMessages ?? [])
+        .filter((log) => log.includes("Program data:"))
+        .map((log) => {
+          try {
+            return eventCoder.decode(log.split("Program data: ")[1]);
+          } catch {
+            return null;
+          }
+        })
+        .filter((e) => e !== null && e.name === "UniversalTx");
+
+      expect(sweepEvents.length).to.equal(1);
+      expect(sweepEvents[0].data.fromCea).to.equal(true);
+      expect(sweepEvents[0].data.amount.toNumber()).to.equal(residualLamports);
+      expect(Array.from(sweepEvents[0].data.recipient)).to.deep.equal(pushAccount);
+    });
+
     it("should revert SOL transfer when preseed + top-up is insufficient (atomic rollback)", async () => {
       const pushAccount = generateSender();
       const ceaAuthority = getCeaAuthorityPda(pushAccount);
@@ -841,6 +1011,11 @@ describe("Universal Gateway - Execute Tests", () => {
             accountsToWritableFlagsOnly(transferAccounts),
             Buffer.from(transferIx.data),
             new anchor.BN(Number(gasFee)),
+            new anchor.BN(0), // nonce
+            { unordered: {} }, // nonce_mode
+            0, // flags
+            [], // token_legs
+            0, // cea_index
 
             Array.from(transferSig.signature),
             transferSig.recoveryId,
@@ -933,6 +1108,11 @@ describe("Universal Gateway - Execute Tests", () => {
           writableFlags1,
           Buffer.from(counterIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig1.signature),
           sig1.recoveryId,
@@ -990,6 +1170,11 @@ describe("Universal Gateway - Execute Tests", () => {
             writableFlags2,
             Buffer.from(counterIx.data),
             new anchor.BN(Number(gasFee)),
+            new anchor.BN(0), // nonce
+            { unordered: {} }, // nonce_mode
+            0, // flags
+            [], // token_legs
+            0, // cea_index
 
             Array.from(sig2.signature),
             sig2.recoveryId,
@@ -1103,6 +1288,11 @@ describe("Universal Gateway - Execute Tests", () => {
           splWritableFlags1,
           Buffer.from(counterIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -1232,6 +1422,11 @@ describe("Universal Gateway - Execute Tests", () => {
           accountsToWritableFlagsOnly(preseedAccounts),
           Buffer.from(preseedIx.data),
           new anchor.BN(Number(preseedGasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(preseedSig.signature),
           preseedSig.recoveryId,
@@ -1309,6 +1504,11 @@ describe("Universal Gateway - Execute Tests", () => {
           accountsToWritableFlagsOnly(transferAccounts),
           Buffer.from(transferIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(transferSig.signature),
           transferSig.recoveryId,
@@ -1420,6 +1620,11 @@ describe("Universal Gateway - Execute Tests", () => {
           accountsToWritableFlagsOnly(accounts),
           Buffer.from(splTransferIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -1456,6 +1661,226 @@ describe("Universal Gateway - Execute Tests", () => {
       expect(Number(ceaAfter.value.amount)).to.equal(0);
     });
 
+    it("should stage SOL plus an SPL token leg to the CEA in one execute", async () => {
+      const pushAccount = generateSender();
+      const ceaAuthority = getCeaAuthorityPda(pushAccount);
+      const ceaAta = await getCeaAta(pushAccount, mockUSDT.mint.publicKey);
+
+      const solAmount = asLamports(0.01); // primary asset: native SOL
+      const legTokens = asTokenAmount(25); // token leg: USDT from vault ATA
+      const tokenLegs = [
+        { mint: mockUSDT.mint.publicKey, amount: BigInt(legTokens.toString()) },
+      ];
+
+      // Payload spends the leg: CEA ATA -> recipient ATA
+      const subTxId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const splTransferIx = spl.createTransferInstruction(
+        ceaAta,
+        recipientUsdtAccount,
+        ceaAuthority,
+        Number(legTokens.toString()),
+        [],
+        TOKEN_PROGRAM_ID
+      );
+      const accounts = instructionAccountsToGatewayMetas(splTransferIx);
+      // Leg accounts lead remaining_accounts: [mint, vault_ata, cea_ata] per leg
+      const legAccounts = [
+        { pubkey: mockUSDT.mint.publicKey, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdtAccount, isWritable: true, isSigner: false },
+        { pubkey: ceaAta, isWritable: true, isSigner: false },
+      ];
+      const remainingAccounts = [
+        ...legAccounts,
+        ...instructionAccountsToRemaining(splTransferIx),
+      ];
+      const { gasFee } = await calculateSplExecuteFees(
+        provider.connection,
+        ceaAta
+      );
+
+      const sig = await signTssMessage({
+        instruction: TssInstruction.Execute,
+        amount: BigInt(solAmount.toString()),
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildExecuteAdditionalData(
+          new Uint8Array(universalTxId),
+          new Uint8Array(subTxId),
+          TOKEN_PROGRAM_ID,
+          new Uint8Array(pushAccount),
+          accounts,
+          splTransferIx.data,
+          gasFee,
+          PublicKey.default,
+          BigInt(0),
+          NonceMode.Unordered,
+          0,
+          tokenLegs
+        ),
+      });
+
+      const recipientBefore = await mockUSDT.getBalance(recipientUsdtAccount);
+      const ceaSolBefore = await provider.connection.getBalance(ceaAuthority);
+
+      await gatewayProgram.methods
+        .finalizeUniversalTx(
+          2,
+          Array.from(subTxId),
+          Array.from(universalTxId),
+          solAmount,
+          Array.from(pushAccount),
+          accountsToWritableFlagsOnly(accounts),
+          Buffer.from(splTransferIx.data),
+          new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          tokenLegs.map((leg) => ({
+            mint: leg.mint,
+            amount: new anchor.BN(leg.amount.toString()),
+          })),
+          0, // cea_index
+          Array.from(sig.signature),
+          sig.recoveryId,
+          Array.from(sig.messageHash)
+        )
+        .accountsPartial({
+          caller: admin.publicKey,
+          config: configPda,
+          vaultAta: null,
+          vaultSol: vaultPda,
+          ceaAuthority,
+          ceaAta: null,
+          mint: null,
+          tssPda,
+          executedSubTx: getExecutedTxPda(subTxId),
+          rateLimitConfig: null,
+          tokenRateLimit: null,
+          destinationProgram: TOKEN_PROGRAM_ID,
+          recipient: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          recipientAta: null,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([admin])
+        .rpc();
+
+      const recipientAfter = await mockUSDT.getBalance(recipientUsdtAccount);
+      const ceaSolAfter = await provider.connection.getBalance(ceaAuthority);
+      const ceaAtaAfter = await provider.connection.getTokenAccountBalance(ceaAta);
+
+      expect(recipientAfter - recipientBefore).to.equal(25);
+      expect(ceaSolAfter - ceaSolBefore).to.equal(solAmount.toNumber());
+      expect(Number(ceaAtaAfter.value.amount)).to.equal(0);
+    });
+
+    it("should reject token legs that differ from the signed legs", async () => {
+      const pushAccount = generateSender();
+      const ceaAuthority = getCeaAuthorityPda(pushAccount);
+      const ceaAta = await getCeaAta(pushAccount, mockUSDT.mint.publicKey);
+
+      const signedLegTokens = asTokenAmount(5);
+      const submittedLegTokens = asTokenAmount(50); // relayer inflates the leg
+
+      const subTxId = generateTxId();
+      const universalTxId = generateUniversalTxId();
+      const splTransferIx = spl.createTransferInstruction(
+        ceaAta,
+        recipientUsdtAccount,
+        ceaAuthority,
+        Number(signedLegTokens.toString()),
+        [],
+        TOKEN_PROGRAM_ID
+      );
+      const accounts = instructionAccountsToGatewayMetas(splTransferIx);
+      const remainingAccounts = [
+        { pubkey: mockUSDT.mint.publicKey, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdtAccount, isWritable: true, isSigner: false },
+        { pubkey: ceaAta, isWritable: true, isSigner: false },
+        ...instructionAccountsToRemaining(splTransferIx),
+      ];
+      const { gasFee } = await calculateSplExecuteFees(
+        provider.connection,
+        ceaAta
+      );
+
+      const sig = await signTssMessage({
+        instruction: TssInstruction.Execute,
+        amount: BigInt(0),
+        chainId: (await gatewayProgram.account.tssPda.fetch(tssPda)).chainId,
+        additional: buildExecuteAdditionalData(
+          new Uint8Array(universalTxId),
+          new Uint8Array(subTxId),
+          TOKEN_PROGRAM_ID,
+          new Uint8Array(pushAccount),
+          accounts,
+          splTransferIx.data,
+          gasFee,
+          PublicKey.default,
+          BigInt(0),
+          NonceMode.Unordered,
+          0,
+          [
+            {
+              mint: mockUSDT.mint.publicKey,
+              amount: BigInt(signedLegTokens.toString()),
+            },
+          ]
+        ),
+      });
+
+      try {
+        await gatewayProgram.methods
+          .finalizeUniversalTx(
+            2,
+            Array.from(subTxId),
+            Array.from(universalTxId),
+            new anchor.BN(0),
+            Array.from(pushAccount),
+            accountsToWritableFlagsOnly(accounts),
+            Buffer.from(splTransferIx.data),
+            new anchor.BN(Number(gasFee)),
+            new anchor.BN(0), // nonce
+            { unordered: {} }, // nonce_mode
+            0, // flags
+            [{ mint: mockUSDT.mint.publicKey, amount: submittedLegTokens }],
+            0, // cea_index
+            Array.from(sig.signature),
+            sig.recoveryId,
+            Array.from(sig.messageHash)
+          )
+          .accountsPartial({
+            caller: admin.publicKey,
+            config: configPda,
+            vaultAta: null,
+            vaultSol: vaultPda,
+            ceaAuthority,
+            ceaAta: null,
+            mint: null,
+            tssPda,
+            executedSubTx: getExecutedTxPda(subTxId),
+            rateLimitConfig: null,
+            tokenRateLimit: null,
+            destinationProgram: TOKEN_PROGRAM_ID,
+            recipient: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+            recipientAta: null,
+          })
+          .remainingAccounts(remainingAccounts)
+          .signers([admin])
+          .rpc();
+        expect.fail("Should reject unsigned token leg amount");
+      } catch (error: any) {
+        expect(error.toString()).to.include("MessageHashMismatch");
+      }
+    });
+
     it("should revert SPL transfer when preseed + top-up is insufficient (atomic rollback)", async () => {
       const pushAccount = generateSender();
       const ceaAuthority = getCeaAuthorityPda(pushAccount);
@@ -1535,6 +1960,11 @@ describe("Universal Gateway - Execute Tests", () => {
             accountsToWritableFlagsOnly(transferAccounts),
             Buffer.from(transferIx.data),
             new anchor.BN(Number(gasFee)),
+            new anchor.BN(0), // nonce
+            { unordered: {} }, // nonce_mode
+            0, // flags
+            [], // token_legs
+            0, // cea_index
 
             Array.from(transferSig.signature),
             transferSig.recoveryId,
@@ -1655,6 +2085,11 @@ describe("Universal Gateway - Execute Tests", () => {
             splWritableFlags2,
             Buffer.from(counterIx.data),
             new anchor.BN(Number(gasFee)),
+            new anchor.BN(0), // nonce
+            { unordered: {} }, // nonce_mode
+            0, // flags
+            [], // token_legs
+            0, // cea_index
 
             Array.from(sig.signature),
             sig.recoveryId,
@@ -1766,6 +2201,11 @@ describe("Universal Gateway - Execute Tests", () => {
           splWritableFlags4,
           Buffer.from(counterIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -1920,6 +2360,11 @@ describe("Universal Gateway - Execute Tests", () => {
           decodedWritableFlags,
           Buffer.from(decoded.ixData),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -2078,6 +2523,11 @@ describe("Universal Gateway - Execute Tests", () => {
           accountsForSigningWritableFlags,
           Buffer.from(decoded.ixData),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -2227,6 +2677,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 accountsToWritableFlagsOnly(accounts),
                 Buffer.from(counterIx.data),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
                 Array.from(sig.signature),
                 sig.recoveryId,
                 Array.from(sig.messageHash)
@@ -2327,6 +2782,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 correctWritableFlags,
                 Buffer.from(counterIx.data),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 Array.from(sig.signature),
                 sig.recoveryId,
@@ -2417,6 +2877,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 correctWritableFlags2,
                 Buffer.from(counterIx.data),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 Array.from(sig.signature),
                 sig.recoveryId,
@@ -2509,6 +2974,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 correctWritableFlags3,
                 Buffer.from(counterIx.data),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 Array.from(sig.signature),
                 sig.recoveryId,
@@ -2600,6 +3070,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 correctWritableFlags4,
                 Buffer.from(counterIx.data),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 Array.from(sig.signature),
                 sig.recoveryId,
@@ -2667,6 +3142,11 @@ describe("Universal Gateway - Execute Tests", () => {
               writableFlags,
               Buffer.from([]),
               new anchor.BN(Number(gasFee)),
+              new anchor.BN(0), // nonce
+              { unordered: {} }, // nonce_mode
+              0, // flags
+              [], // token_legs
+              0, // cea_index
 
               dummySig,
               0,
@@ -2764,6 +3244,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 sigWritableFlags,
                 Buffer.from(counterIx.data),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 corruptedSignature, // Invalid!
                 sig.recoveryId,
@@ -2853,6 +3338,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 hashWritableFlags,
                 Buffer.from(counterIx.data),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 Array.from(sig.signature),
                 sig.recoveryId,
@@ -2937,6 +3427,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 progWritableFlags,
                 Buffer.from([0x01]),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 Array.from(sig.signature),
                 sig.recoveryId,
@@ -3027,6 +3522,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 maliciousWritableFlags,
                 Buffer.from(counterIx.data),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 Array.from(sig.signature),
                 sig.recoveryId,
@@ -3120,6 +3620,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 targetMismatchWritableFlags,
                 Buffer.from(counterIx.data),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 Array.from(sig.signature),
                 sig.recoveryId,
@@ -3229,6 +3734,11 @@ describe("Universal Gateway - Execute Tests", () => {
                 targetMismatchWritableFlags,
                 Buffer.from(decoded.ixData),
                 new anchor.BN(Number(gasFee)),
+                new anchor.BN(0), // nonce
+                { unordered: {} }, // nonce_mode
+                0, // flags
+                [], // token_legs
+                0, // cea_index
 
                 Array.from(sig.signature),
                 sig.recoveryId,
@@ -3396,6 +3906,11 @@ describe("Universal Gateway - Execute Tests", () => {
           user1WritableFlags1,
           Buffer.from(stakeIx.data),
           new anchor.BN(Number(gasFee1)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig1.signature),
           sig1.recoveryId,
@@ -3499,6 +4014,11 @@ describe("Universal Gateway - Execute Tests", () => {
           user1WritableFlags2,
           Buffer.from(stakeIx2.data),
           new anchor.BN(Number(gasFee2)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig2.signature),
           sig2.recoveryId,
@@ -3602,6 +4122,11 @@ describe("Universal Gateway - Execute Tests", () => {
           user1UnstakeWritableFlags,
           Buffer.from(unstakeIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -3763,6 +4288,11 @@ describe("Universal Gateway - Execute Tests", () => {
           user2WritableFlags,
           Buffer.from(stakeIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -3854,6 +4384,11 @@ describe("Universal Gateway - Execute Tests", () => {
           user2UnstakeWritableFlags,
           Buffer.from(unstakeIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -3976,6 +4511,11 @@ describe("Universal Gateway - Execute Tests", () => {
           user3StakeWritableFlags,
           Buffer.from(stakeIx.data),
           gasFeeBn,
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -4082,6 +4622,11 @@ describe("Universal Gateway - Execute Tests", () => {
           user3UnstakeWritableFlags,
           Buffer.from(unstakeIx.data),
           new anchor.BN(Number(gasFee)),
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig.signature),
           sig.recoveryId,
@@ -4290,6 +4835,11 @@ describe("Universal Gateway - Execute Tests", () => {
           accounts1WritableFlags,
           Buffer.from(stakeIx.data),
           gasFeeBn,
+          new anchor.BN(0), // nonce
+          { unordered: {} }, // nonce_mode
+          0, // flags
+          [], // token_legs
+          0, // cea_index
 
           Array.from(sig1.signature),
           sig1.recoveryId,
@@ -4385,6 +4935,11 @@ describe("Universal Gateway - Execute Tests", () => {
             accountsToWritableFlagsOnly(crossAccounts),
             Buffer.from(crossUnstakeIx.data),
             new anchor.BN(Number(gasFee2)),
+            new anchor.BN(0), // nonce
+            { unordered: {} }, // nonce_mode
+            0, // flags
+            [], // token_legs
+            0, // cea_index
 
             Array.from(sigCross.signature),
             sigCross.recoveryId,
@@ -1,8 +1,16 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { UniversalGateway } from "../../target/types/universal_gateway";
-import { PublicKey, SystemProgram } from "@solana/web3.js";
-import { getCeaAuthorityPda, getExecutedTxPda } from "./test-utils";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  getCeaAuthorityPda,
+  getCeaStatePda,
+  getExecutedTxPda,
+  getOraclePda,
+  getTokenRateLimitPda,
+} from "./test-utils";
+import { NonceMode, nonceModeArg } from "./tss";
 
 // =============================================================================
 // FinalizeUniversalTx builder
@@ -17,6 +25,11 @@ export interface FinalizeUniversalTxArgs {
   writableFlags?: Buffer;
   ixData?: Buffer;
   gasFee: anchor.BN;
+  nonce?: anchor.BN;
+  nonceMode?: NonceMode;
+  flags?: number;
+  tokenLegs?: { mint: PublicKey; amount: anchor.BN }[];
+  ceaIndex?: number;
   sig: {
     signature: ArrayLike<number>;
     recoveryId: number;
@@ -34,6 +47,7 @@ export interface FinalizeUniversalTxArgs {
   recipientAta?: PublicKey | null;
   rateLimitConfig?: PublicKey | null;
   tokenRateLimit?: PublicKey | null;
+  priceUpdate?: PublicKey | null;
 }
 
 /**
@@ -62,6 +76,11 @@ export const makeFinalizeUniversalTxBuilder =
     writableFlags = Buffer.alloc(0),
     ixData = Buffer.from([]),
     gasFee,
+    nonce = new anchor.BN(0),
+    nonceMode = NonceMode.Unordered,
+    flags = 0,
+    tokenLegs = [],
+    ceaIndex = 0,
     sig,
     caller,
     destinationProgram,
@@ -75,6 +94,7 @@ export const makeFinalizeUniversalTxBuilder =
     recipientAta = null,
     rateLimitConfig = null,
     tokenRateLimit = null,
+    priceUpdate = null,
   }: FinalizeUniversalTxArgs) =>
     program.methods
       .finalizeUniversalTx(
@@ -86,6 +106,11 @@ export const makeFinalizeUniversalTxBuilder =
         writableFlags,
         ixData,
         gasFee,
+        nonce,
+        nonceModeArg(nonceMode),
+        flags,
+        tokenLegs,
+        ceaIndex,
         Array.from(sig.signature),
         sig.recoveryId,
         Array.from(sig.messageHash)
@@ -96,10 +121,16 @@ export const makeFinalizeUniversalTxBuilder =
         vaultSol: vaultPda,
         ceaAuthority: getCeaAuthorityPda(
           Array.from(pushAccount),
-          program.programId
+          program.programId,
+          ceaIndex
         ),
         tssPda,
         executedSubTx: getExecutedTxPda(Array.from(subTxId), program.programId),
+        // Only ordered messages read (and on first use create) the CEA's nonce state
+        ceaState:
+          nonceMode === NonceMode.Unordered
+            ? null
+            : getCeaStatePda(Array.from(pushAccount), program.programId, ceaIndex),
         destinationProgram: destinationProgram ?? SystemProgram.programId,
         recipient,
         vaultAta,
@@ -111,5 +142,81 @@ export const makeFinalizeUniversalTxBuilder =
         recipientAta,
         rateLimitConfig,
         tokenRateLimit,
+        solOracle: priceUpdate ? getOraclePda(PublicKey.default, program.programId) : null,
+        priceUpdate,
         systemProgram: SystemProgram.programId,
       });
+
+// =============================================================================
+// GAS-route sendUniversalTx helper
+// =============================================================================
+
+export interface SendGasArgs {
+  user: Keypair;
+  /** Lamports sent with the call: gas amount plus any protocol fee */
+  nativeAmount: anchor.BN;
+  payload?: Buffer;
+  /** SOL/USD price account; null omits it together with the native SOL oracle */
+  priceUpdate?: PublicKey | null;
+  priceSources?: PublicKey | null;
+  feeExemption?: PublicKey | null;
+  commitment?: anchor.web3.Commitment;
+}
+
+/**
+ * Returns a helper that sends a native SOL GAS (or GAS_AND_PAYLOAD) deposit signed by `user`
+ * and resolves to the transaction signature.
+ *
+ * Usage (call once in before(), assign to a `let` variable):
+ *   sendGas = makeSendGas(program, configPda, vaultPda, feeVaultPda, rateLimitConfigPda, mockPriceFeed);
+ */
+export const makeSendGas =
+  (
+    program: Program<UniversalGateway>,
+    configPda: PublicKey,
+    vaultPda: PublicKey,
+    feeVaultPda: PublicKey,
+    rateLimitConfigPda: PublicKey,
+    defaultPriceUpdate: PublicKey
+  ) =>
+  ({
+    user,
+    nativeAmount,
+    payload = Buffer.from([]),
+    priceUpdate = defaultPriceUpdate,
+    priceSources = null,
+    feeExemption = null,
+    commitment,
+  }: SendGasArgs): Promise<string> =>
+    program.methods
+      .sendUniversalTx(
+        {
+          recipient: Array.from(Buffer.alloc(20, 0)),
+          token: PublicKey.default,
+          amount: new anchor.BN(0),
+          payload,
+          revertRecipient: user.publicKey,
+          signatureData: Buffer.from([]),
+        },
+        nativeAmount
+      )
+      .accountsPartial({
+        config: configPda,
+        vault: vaultPda,
+        feeVault: feeVaultPda,
+        userTokenAccount: null,
+        gatewayTokenAccount: null,
+        user: user.publicKey,
+        solOracle: priceUpdate ? getOraclePda(PublicKey.default, program.programId) : null,
+        priceUpdate,
+        rateLimitConfig: rateLimitConfigPda,
+        tokenRateLimit: getTokenRateLimitPda(PublicKey.default, program.programId),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        priceSources,
+        secondaryPriceUpdate1: null,
+        secondaryPriceUpdate2: null,
+        feeExemption,
+      })
+      .signers([user])
+      .rpc(commitment ? { commitment } : undefined);
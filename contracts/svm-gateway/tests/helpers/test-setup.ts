@@ -5,7 +5,7 @@ import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
 import { createMockUSDT, createMockUSDC } from "./mockSpl";
 import * as sharedState from "../shared-state";
 import { getTssEthAddress, TSS_CHAIN_ID } from "./tss";
-import { setupPriceFeed } from "../setup-pricefeed";
+import { setupPriceFeed, SOL_FEED_ID } from "../setup-pricefeed";
 
 // Module-level promise to ensure setup runs only once per process
 let setupPromise: Promise<void> | null = null;
@@ -130,6 +130,29 @@ export async function ensureTestSetup(): Promise<void> {
             sharedState.setMockPriceFeed(configAccount.pythPriceFeed);
         }
 
+        // Step 6.5: Register the mock feed as the native SOL oracle entry (SOL/USD pricing)
+        const [solOraclePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("oracle"), PublicKey.default.toBuffer()],
+            program.programId
+        );
+        await program.methods
+            .setOracle(
+                sharedState.getMockPriceFeed(),
+                Array.from(Buffer.from(SOL_FEED_ID, "hex")),
+                new anchor.BN(0),
+                0,
+                0
+            )
+            .accountsPartial({
+                config: configPda,
+                oracle: solOraclePda,
+                tokenMint: PublicKey.default,
+                admin: admin.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([admin])
+            .rpc();
+
         // Step 7: Initialize or update TSS
         const [tssPda] = PublicKey.findProgramAddressSync([Buffer.from("tsspda_v2")], program.programId);
         const expectedTssEthAddress = getTssEthAddress();
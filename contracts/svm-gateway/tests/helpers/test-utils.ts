@@ -1,5 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
-import { PublicKey } from "@solana/web3.js";
+import { Connection, PublicKey } from "@solana/web3.js";
 import { getAssociatedTokenAddress } from "@solana/spl-token";
 import {
   accountsToWritableFlags,
@@ -26,6 +26,23 @@ export const asLamports = (sol: number) =>
 export const asTokenAmount = (tokens: number) =>
   new anchor.BN(Number(BigInt(tokens) * TOKEN_MULTIPLIER));
 
+/** Anchor events emitted by `program` in a confirmed transaction, in log order */
+export const getTxEvents = async (
+  connection: Connection,
+  program: anchor.Program<any>,
+  signature: string
+): Promise<any[]> => {
+  const txDetails = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const eventCoder = new anchor.BorshEventCoder(program.idl);
+  return (txDetails?.meta?.logMessages ?? [])
+    .filter((log) => log.startsWith("Program data: "))
+    .map((log) => eventCoder.decode(log.slice("Program data: ".length)))
+    .filter((e) => e !== null);
+};
+
 /** Compute the first 8 bytes of SHA-256 of `name` — matches Anchor's discriminator format */
 export const computeDiscriminator = (name: string): Buffer =>
   createHash("sha256").update(name).digest().slice(0, 8);
@@ -74,20 +91,53 @@ export const getExecutedTxPda = (
   return pda;
 };
 
+/** CEA PDA; `ceaIndex` > 0 appends the u16 BE index seed (index 0 = legacy derivation) */
 export const getCeaAuthorityPda = (
   sender: number[],
-  programId: PublicKey
+  programId: PublicKey,
+  ceaIndex: number = 0
 ): PublicKey => {
+  const seeds = [Buffer.from("push_identity"), Buffer.from(sender)];
+  if (ceaIndex > 0) {
+    const indexSeed = Buffer.alloc(2);
+    indexSeed.writeUInt16BE(ceaIndex, 0);
+    seeds.push(indexSeed);
+  }
+  const [pda] = PublicKey.findProgramAddressSync(seeds, programId);
+  return pda;
+};
+
+/** CeaState PDA; `ceaIndex` > 0 appends the u16 BE index seed, like the CEA itself */
+export const getCeaStatePda = (
+  sender: number[],
+  programId: PublicKey,
+  ceaIndex: number = 0
+): PublicKey => {
+  const seeds = [Buffer.from("cea_state"), Buffer.from(sender)];
+  if (ceaIndex > 0) {
+    const indexSeed = Buffer.alloc(2);
+    indexSeed.writeUInt16BE(ceaIndex, 0);
+    seeds.push(indexSeed);
+  }
+  const [pda] = PublicKey.findProgramAddressSync(seeds, programId);
+  return pda;
+};
+
+export const getFeeVaultPda = (programId: PublicKey): PublicKey => {
   const [pda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("push_identity"), Buffer.from(sender)],
+    [Buffer.from("fee_vault")],
     programId
   );
   return pda;
 };
 
-export const getFeeVaultPda = (programId: PublicKey): PublicKey => {
+/** `OracleRegistry` PDA for a mint; `PublicKey.default` = native SOL (the SOL/USD feed) */
+export const getOraclePda = (
+  mint: PublicKey,
+  programId: PublicKey
+): PublicKey => {
   const [pda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("fee_vault")],
+    [Buffer.from("oracle"), mint.toBuffer()],
     programId
   );
   return pda;
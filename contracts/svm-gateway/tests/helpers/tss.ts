@@ -10,6 +10,51 @@ export enum TssInstruction {
   Execute = 2, // Unified execute (vault→CEA→CPI)
   Revert = 3,  // Unified revert (SOL or SPL)
   Rescue = 4,  // Emergency rescue (SOL or SPL)
+  SweepCea = 5, // CEA sweep: close CEA token accounts, drain CEA lamports to vault
+}
+
+/** Outbound ordering mode (matches Rust `NonceMode` variant index) */
+export enum NonceMode {
+  Unordered = 0, // nonce signed but not checked
+  Strict = 1,    // nonce == last_nonce + 1
+  Loose = 2,     // nonce > last_nonce
+}
+
+/** Anchor IDL enum encoding for `NonceMode` */
+export function nonceModeArg(mode: NonceMode): any {
+  switch (mode) {
+    case NonceMode.Strict:
+      return { strict: {} };
+    case NonceMode.Loose:
+      return { loose: {} };
+    default:
+      return { unordered: {} };
+  }
+}
+
+/** Finalize option bits (matches Rust `FINALIZE_FLAG_*`) */
+export const FinalizeFlags = {
+  TokenAccountRecipient: 1 << 0, // SPL withdraw straight into a signed token account
+  WrapNative: 1 << 1, // SOL withdraw delivered as wSOL into the recipient's wSOL ATA
+  SweepCea: 1 << 2, // execute: sweep CEA residue back to the vault after the CPI, credit the UEA
+};
+
+/** nonce (u64 BE) || nonce_mode (1 byte) || flags (1 byte) || cea_index (u16 BE) — common fields for withdraw/execute */
+function encodeOrdering(
+  nonce: bigint,
+  nonceMode: NonceMode,
+  flags: number,
+  ceaIndex: number
+): Buffer[] {
+  const nonceBuf = Buffer.alloc(8);
+  nonceBuf.writeBigUInt64BE(nonce, 0);
+  return [nonceBuf, Buffer.from([nonceMode]), Buffer.from([flags]), encodeCeaIndex(ceaIndex)];
+}
+
+function encodeCeaIndex(ceaIndex: number): Buffer {
+  const buf = Buffer.alloc(2);
+  buf.writeUInt16BE(ceaIndex, 0);
+  return buf;
 }
 
 // Default to Devnet cluster pubkey if not specified
@@ -124,7 +169,11 @@ export function generateUniversalTxId(): number[] {
  * 3. push_account (20 bytes) - common
  * 4. token (32 bytes) - common
  * 5. gas_fee (u64 BE) - common
- * 6. target (32 bytes) - withdraw specific
+ * 6. nonce (u64 BE) - common
+ * 7. nonce_mode (1 byte) - common
+ * 8. flags (1 byte) - common
+ * 9. cea_index (u16 BE) - common
+ * 10. target (32 bytes) - withdraw specific (destination token account with TokenAccountRecipient)
  */
 export function buildWithdrawAdditionalData(
   universalTxId: BytesLike,
@@ -132,7 +181,11 @@ export function buildWithdrawAdditionalData(
   pushAccount: BytesLike,
   token: PublicKey,
   target: PublicKey,
-  gasFee: bigint = BigInt(0)
+  gasFee: bigint = BigInt(0),
+  nonce: bigint = BigInt(0),
+  nonceMode: NonceMode = NonceMode.Unordered,
+  flags: number = 0,
+  ceaIndex: number = 0
 ): BytesLike[] {
   const gasFeeBuf = Buffer.alloc(8);
   gasFeeBuf.writeBigUInt64BE(gasFee, 0);
@@ -143,6 +196,7 @@ export function buildWithdrawAdditionalData(
     pushAccount, // push_account (20 bytes) - common
     token.toBuffer(), // token (32 bytes) - common
     gasFeeBuf, // gas_fee (8 bytes, u64 BE) - common
+    ...encodeOrdering(nonce, nonceMode, flags, ceaIndex), // nonce + nonce_mode + flags + cea_index - common
     target.toBuffer(), // target/recipient (32 bytes) - withdraw specific
   ];
 }
@@ -156,6 +210,25 @@ export interface GatewayAccountMeta {
   isWritable: boolean;
 }
 
+/** Extra SPL leg staged from the vault into the CEA before the execute CPI */
+export interface TokenLeg {
+  mint: PublicKey;
+  amount: bigint;
+}
+
+function encodeTokenLegs(legs: TokenLeg[]): Buffer {
+  const count = Buffer.alloc(4);
+  count.writeUInt32BE(legs.length, 0);
+  return Buffer.concat([
+    count,
+    ...legs.map((leg) => {
+      const amountBuf = Buffer.alloc(8);
+      amountBuf.writeBigUInt64BE(leg.amount, 0);
+      return Buffer.concat([leg.mint.toBuffer(), amountBuf]);
+    }),
+  ]);
+}
+
 /**
  * Build execute message additional_data buffers (accounts and ix_data with length prefixes)
  *
@@ -168,9 +241,14 @@ export interface GatewayAccountMeta {
  * 3. push_account (20 bytes) - common
  * 4. token (32 bytes) - common
  * 5. gas_fee (u64 BE) - common
- * 6. target_program (32 bytes) - execute specific, MUST match decoded payload
- * 7. accounts_buf (variable) - execute specific
- * 8. ix_data_buf (variable) - execute specific
+ * 6. nonce (u64 BE) - common
+ * 7. nonce_mode (1 byte) - common
+ * 8. flags (1 byte) - common
+ * 9. cea_index (u16 BE) - common
+ * 10. target_program (32 bytes) - execute specific, MUST match decoded payload
+ * 11. accounts_buf (variable) - execute specific
+ * 12. ix_data_buf (variable) - execute specific
+ * 13. token_legs_buf (variable) - execute specific, [u32 BE count][mint(32) + amount(u64 BE)] × N
  */
 export function buildExecuteAdditionalData(
   universalTxId: BytesLike,
@@ -180,7 +258,12 @@ export function buildExecuteAdditionalData(
   accounts: GatewayAccountMeta[],
   ixData: Uint8Array,
   gasFee: bigint = BigInt(0),
-  token: PublicKey = PublicKey.default
+  token: PublicKey = PublicKey.default,
+  nonce: bigint = BigInt(0),
+  nonceMode: NonceMode = NonceMode.Unordered,
+  flags: number = 0,
+  tokenLegs: TokenLeg[] = [],
+  ceaIndex: number = 0
 ): BytesLike[] {
   // Build accounts buffer with length prefix (u32 BE)
   const accountsCount = Buffer.alloc(4);
@@ -215,9 +298,11 @@ export function buildExecuteAdditionalData(
     pushAccount, // push_account (20 bytes) - common
     token.toBuffer(), // token (32 bytes) - common
     gasFeeBuf, // gas_fee (8 bytes, u64 BE) - common
+    ...encodeOrdering(nonce, nonceMode, flags, ceaIndex), // nonce + nonce_mode + flags + cea_index - common
     targetProgramFromPayload.toBuffer(), // target_program (32 bytes) - execute specific, from decoded payload
     accountsBuf, // accounts with length prefix - execute specific
     ixDataBuf, // ix_data with length prefix - execute specific
+    encodeTokenLegs(tokenLegs), // token legs with length prefix - execute specific
   ];
 }
 
@@ -248,3 +333,41 @@ export function buildRescueAdditionalData(
   }
   return [subTxId, universalTxId, recipient.toBuffer(), gasFeeBuf];
 }
+
+// =========================
+// CEA SWEEP MESSAGE HELPERS
+// =========================
+
+/**
+ * Build sweep_cea message additional_data (instruction_id=5, no amount field).
+ *
+ * [sub_tx_id, universal_tx_id, push_account, cea_index, gas_fee, token_accounts_buf]
+ * token_accounts_buf = [u32 BE count][pubkey(32)] × N
+ */
+export function buildSweepCeaAdditionalData(
+  subTxId: BytesLike,
+  universalTxId: BytesLike,
+  pushAccount: BytesLike,
+  tokenAccounts: PublicKey[],
+  gasFee: bigint = BigInt(0),
+  ceaIndex: number = 0
+): BytesLike[] {
+  const gasFeeBuf = Buffer.alloc(8);
+  gasFeeBuf.writeBigUInt64BE(gasFee, 0);
+
+  const count = Buffer.alloc(4);
+  count.writeUInt32BE(tokenAccounts.length, 0);
+  const tokenAccountsBuf = Buffer.concat([
+    count,
+    ...tokenAccounts.map((account) => account.toBuffer()),
+  ]);
+
+  return [
+    subTxId,
+    universalTxId,
+    pushAccount,
+    encodeCeaIndex(ceaIndex),
+    gasFeeBuf,
+    tokenAccountsBuf,
+  ];
+}
@@ -23,6 +23,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
     let vaultPda: PublicKey;
     let feeVaultPda: PublicKey;
     let rateLimitConfigPda: PublicKey;
+    let solOraclePda: PublicKey;
     let mockPriceFeed: PublicKey;
     let solPrice: number;
     let mockUSDT: any;
@@ -49,6 +50,10 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
         [vaultPda] = PublicKey.findProgramAddressSync([Buffer.from("vault")], program.programId);
         [feeVaultPda] = PublicKey.findProgramAddressSync([Buffer.from("fee_vault")], program.programId);
         [rateLimitConfigPda] = PublicKey.findProgramAddressSync([Buffer.from("rate_limit_config")], program.programId);
+        [solOraclePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("oracle"), PublicKey.default.toBuffer()],
+            program.programId
+        );
 
         mockPriceFeed = sharedState.getMockPriceFeed();
         solPrice = await getSolPrice(mockPriceFeed);
@@ -113,6 +118,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: null,
                     gatewayTokenAccount: null,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -152,6 +158,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                         userTokenAccount: null,
                         gatewayTokenAccount: null,
                         user: user1.publicKey,
+                        solOracle: solOraclePda,
                         priceUpdate: mockPriceFeed,
                         rateLimitConfig: rateLimitConfigPda,
                         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -265,6 +272,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: null,
                     gatewayTokenAccount: null,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -291,6 +299,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: null,
                     gatewayTokenAccount: null,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -364,6 +373,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: null,
                     gatewayTokenAccount: null,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -383,6 +393,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: null,
                     gatewayTokenAccount: null,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -444,6 +455,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: null,
                     gatewayTokenAccount: null,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -472,6 +484,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: null,
                     gatewayTokenAccount: null,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -502,6 +515,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                         userTokenAccount: null,
                         gatewayTokenAccount: null,
                         user: user1.publicKey,
+                        solOracle: solOraclePda,
                         priceUpdate: mockPriceFeed,
                         rateLimitConfig: rateLimitConfigPda,
                         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -559,6 +573,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: userTokenAccount,
                     gatewayTokenAccount: gatewayTokenAccount,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: usdtTokenRateLimitPda,
@@ -587,6 +602,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: userTokenAccount,
                     gatewayTokenAccount: gatewayTokenAccount,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: usdtTokenRateLimitPda,
@@ -617,6 +633,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                         userTokenAccount: userTokenAccount,
                         gatewayTokenAccount: gatewayTokenAccount,
                         user: user1.publicKey,
+                        solOracle: solOraclePda,
                         priceUpdate: mockPriceFeed,
                         rateLimitConfig: rateLimitConfigPda,
                         tokenRateLimit: usdtTokenRateLimitPda,
@@ -669,6 +686,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                         userTokenAccount: null,
                         gatewayTokenAccount: null,
                         user: user1.publicKey,
+                        solOracle: solOraclePda,
                         priceUpdate: mockPriceFeed,
                         rateLimitConfig: rateLimitConfigPda,
                         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -736,6 +754,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: null,
                     gatewayTokenAccount: null,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -747,6 +766,136 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
         });
     });
 
+    describe("USD-denominated Rate Limits", () => {
+        const rateLimitConfigAccounts = () => ({
+            admin: admin.publicKey,
+            config: configPda,
+            rateLimitConfig: rateLimitConfigPda,
+            systemProgram: SystemProgram.programId,
+        });
+
+        const nativeTokenRateLimitAccounts = () => ({
+            admin: admin.publicKey,
+            config: configPda,
+            tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+            tokenMint: PublicKey.default,
+            systemProgram: SystemProgram.programId,
+        });
+
+        // Native SOL FUNDS deposit worth `usd`, optionally priced through the SOL oracle entry.
+        const sendNativeFunds = async (usd: number, withOracle: boolean) => {
+            const lamports = calculateSolAmount(usd, solPrice);
+            const feeVault = await program.account.feeVault.fetch(feeVaultPda);
+            await program.methods
+                .sendUniversalTx(
+                    {
+                        recipient: Array.from(Buffer.alloc(20, 0)),
+                        token: PublicKey.default,
+                        amount: new anchor.BN(lamports),
+                        payload: Buffer.from([]),
+                        revertRecipient: user1.publicKey,
+                        signatureData: Buffer.from([]),
+                    },
+                    new anchor.BN(lamports).add(feeVault.protocolFeeLamports)
+                )
+                .accountsPartial({
+                    config: configPda,
+                    vault: vaultPda,
+                    feeVault: feeVaultPda,
+                    userTokenAccount: null,
+                    gatewayTokenAccount: null,
+                    user: user1.publicKey,
+                    solOracle: solOraclePda,
+                    priceUpdate: mockPriceFeed,
+                    rateLimitConfig: rateLimitConfigPda,
+                    tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+                    tokenProgram: spl.TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    oracle: withOracle ? solOraclePda : null,
+                    oraclePriceUpdate: withOracle ? mockPriceFeed : null,
+                })
+                .signers([user1])
+                .rpc();
+        };
+
+        const expectError = async (promise: Promise<any>, code: string) => {
+            try {
+                await promise;
+                expect.fail(`Should have failed with ${code}`);
+            } catch (error: any) {
+                const errorCode = error.error?.errorCode?.code || error.errorCode?.code || error.code;
+                expect(errorCode).to.equal(code);
+            }
+        };
+
+        before(async () => {
+            await program.methods
+                .updateEpochDuration(new anchor.BN(3600))
+                .accountsPartial(rateLimitConfigAccounts())
+                .signers([admin])
+                .rpc();
+        });
+
+        it("Should accumulate native SOL usage in USD when the token is in USD mode", async () => {
+            await program.methods
+                .setTokenRateLimitUsdMode(true)
+                .accountsPartial(nativeTokenRateLimitAccounts())
+                .signers([admin])
+                .rpc();
+            // $20 per epoch (8 decimals)
+            await program.methods
+                .setTokenRateLimit(new anchor.BN(2_000_000_000))
+                .accountsPartial(nativeTokenRateLimitAccounts())
+                .signers([admin])
+                .rpc();
+
+            try {
+                await sendNativeFunds(15, true);
+
+                const tokenRateLimit = await program.account.tokenRateLimit.fetch(
+                    getTokenRateLimitPda(PublicKey.default)
+                );
+                const usedUsd = tokenRateLimit.epochUsage.used.toNumber() / 1e8;
+                expect(usedUsd).to.be.closeTo(15, 0.01);
+
+                await expectError(sendNativeFunds(10, true), "RateLimitExceeded");
+                await expectError(sendNativeFunds(1, false), "InvalidAccount");
+            } finally {
+                await program.methods
+                    .setTokenRateLimitUsdMode(false)
+                    .accountsPartial(nativeTokenRateLimitAccounts())
+                    .signers([admin])
+                    .rpc();
+                await program.methods
+                    .setTokenRateLimit(new anchor.BN("1000000000000000000000"))
+                    .accountsPartial(nativeTokenRateLimitAccounts())
+                    .signers([admin])
+                    .rpc();
+            }
+        });
+
+        it("Should enforce the global epoch USD cap across deposits", async () => {
+            // $10 per epoch across all tokens
+            await program.methods
+                .setEpochUsdCap(new anchor.BN(1_000_000_000))
+                .accountsPartial(rateLimitConfigAccounts())
+                .signers([admin])
+                .rpc();
+
+            try {
+                await sendNativeFunds(8, true);
+                await expectError(sendNativeFunds(5, true), "EpochUsdCapExceeded");
+                await expectError(sendNativeFunds(1, false), "InvalidAccount");
+            } finally {
+                await program.methods
+                    .setEpochUsdCap(new anchor.BN(0))
+                    .accountsPartial(rateLimitConfigAccounts())
+                    .signers([admin])
+                    .rpc();
+            }
+        });
+    });
+
     describe("Rate Limit Edge Cases", () => {
         it("Should handle rate limits in FUNDS_AND_PAYLOAD routes", async () => {
             // Enable rate limiting
@@ -802,6 +951,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                     userTokenAccount: null,
                     gatewayTokenAccount: null,
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -831,6 +981,7 @@ describe("Universal Gateway - Rate Limiting Tests", () => {
                         userTokenAccount: null,
                         gatewayTokenAccount: null,
                         user: user1.publicKey,
+                        solOracle: solOraclePda,
                         priceUpdate: mockPriceFeed,
                         rateLimitConfig: rateLimitConfigPda,
                         tokenRateLimit: nativeSolTokenRateLimitPda,
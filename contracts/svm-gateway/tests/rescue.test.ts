@@ -59,6 +59,7 @@ describe("Universal Gateway - Rescue Tests", () => {
     let feeVaultPda: PublicKey;
     let tssPda: PublicKey;
     let rateLimitConfigPda: PublicKey;
+    let solOraclePda: PublicKey;
     let mockPriceFeed: PublicKey;
 
     let mockUSDT: any;
@@ -129,6 +130,9 @@ describe("Universal Gateway - Rescue Tests", () => {
         [rateLimitConfigPda] = PublicKey.findProgramAddressSync(
             [Buffer.from("rate_limit_config")], program.programId
         );
+        [solOraclePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("oracle"), PublicKey.default.toBuffer()], program.programId
+        );
 
         // Disable protocol fee so vault seeding is deterministic.
         await program.methods
@@ -191,6 +195,7 @@ describe("Universal Gateway - Rescue Tests", () => {
                 userTokenAccount: null,
                 gatewayTokenAccount: null,
                 user: user1.publicKey,
+                solOracle: solOraclePda,
                 priceUpdate: mockPriceFeed,
                 rateLimitConfig: rateLimitConfigPda,
                 tokenRateLimit: nativeSolRateLimitPda,
@@ -244,6 +249,7 @@ describe("Universal Gateway - Rescue Tests", () => {
                 user: user1.publicKey,
                 userTokenAccount: user1UsdtAccount,
                 gatewayTokenAccount: vaultUsdtAccount,
+                solOracle: solOraclePda,
                 priceUpdate: mockPriceFeed,
                 rateLimitConfig: rateLimitConfigPda,
                 tokenRateLimit: splRateLimitPda,
@@ -5,6 +5,9 @@ import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 // Load pull IDL from SDK source (SDK has bug - uses push IDL, so we load correct one)
 const pullIdl = require("@tkkinn/mock-pyth-sdk/src/idl/mock_pyth_pull.json");
 
+/** Pyth SOL/USD feed id; registered as the native SOL oracle entry by the test setup. */
+export const SOL_FEED_ID = "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+
 export async function setupPriceFeed() {
     const provider = anchor.AnchorProvider.env();
     anchor.setProvider(provider);
@@ -13,9 +16,6 @@ export async function setupPriceFeed() {
         "rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ"
     );
 
-    // IMPORTANT: Must match the Rust FEED_ID exactly
-    const FEED_ID = "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
-
     // SDK creates Program without provider, so we create it ourselves
     // Ensure IDL is a plain object (not a module export)
     const idl = JSON.parse(JSON.stringify(pullIdl));
@@ -40,7 +40,7 @@ export async function setupPriceFeed() {
 
     // createOracle allows specifying FeedId (mock-pyth supports this) - returns [tx, priceFeedPubkey]
     // Note: createOracle already initializes the price feed with the price, so setPrice is not needed
-    const [tx, priceFeedPubkey] = await pullOracle.createOracle(FEED_ID, uiPrice, expo, conf);
+    const [tx, priceFeedPubkey] = await pullOracle.createOracle(SOL_FEED_ID, uiPrice, expo, conf);
 
     // Wait for transaction confirmation
     await provider.connection.confirmTransaction(tx, "confirmed");
@@ -1,6 +1,8 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { UniversalGateway } from "../target/types/universal_gateway";
+import { CpSwapAdapter } from "../target/types/cp_swap_adapter";
+import { TestCounter } from "../target/types/test_counter";
 import {
   PublicKey,
   Keypair,
@@ -9,15 +11,18 @@ import {
 } from "@solana/web3.js";
 import { expect } from "chai";
 import * as sharedState from "./shared-state";
-import { getSolPrice, calculateSolAmount } from "./setup-pricefeed";
+import { getSolPrice, calculateSolAmount, SOL_FEED_ID } from "./setup-pricefeed";
 import * as spl from "@solana/spl-token";
 import { ensureTestSetup } from "./helpers/test-setup";
+import { makeSendGas } from "./helpers/builders";
+import { getTxEvents } from "./helpers/test-utils";
 
 describe("Universal Gateway - send_universal_tx Tests", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
   const provider = anchor.getProvider() as anchor.AnchorProvider;
   const program = anchor.workspace
     .UniversalGateway as Program<UniversalGateway>;
+  const counterProgram = anchor.workspace.TestCounter as Program<TestCounter>;
 
   before(async () => {
     await ensureTestSetup();
@@ -32,8 +37,10 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
   let vaultPda: PublicKey;
   let feeVaultPda: PublicKey;
   let rateLimitConfigPda: PublicKey;
+  let solOraclePda: PublicKey;
   let mockPriceFeed: PublicKey;
   let solPrice: number;
+  let sendGas: ReturnType<typeof makeSendGas>;
   let mockUSDT: any;
   let mockUSDC: any;
   const DEFAULT_PROTOCOL_FEE_LAMPORTS = 50_000;
@@ -113,6 +120,20 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
     return pda;
   };
 
+  // One batch request (or bundle leg) in remaining_accounts:
+  // [token_rate_limit, oracle, oracle_price_update, ...token accounts].
+  // The program id marks an absent oracle slot.
+  const batchSlot = (
+    tokenRateLimit: PublicKey,
+    tokenAccounts: PublicKey[] = [],
+    oracle?: { registry: PublicKey; priceUpdate: PublicKey }
+  ) => [
+    { pubkey: tokenRateLimit, isSigner: false, isWritable: true },
+    { pubkey: oracle?.registry ?? program.programId, isSigner: false, isWritable: false },
+    { pubkey: oracle?.priceUpdate ?? program.programId, isSigner: false, isWritable: false },
+    ...tokenAccounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+  ];
+
   const setProtocolFee = async (feeLamports: number) => {
     await program.methods
       .setProtocolFee(new anchor.BN(feeLamports))
@@ -159,9 +180,14 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
       [Buffer.from("rate_limit_config")],
       program.programId
     );
+    [solOraclePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("oracle"), PublicKey.default.toBuffer()],
+      program.programId
+    );
 
     mockPriceFeed = sharedState.getMockPriceFeed();
     solPrice = await getSolPrice(mockPriceFeed);
+    sendGas = makeSendGas(program, configPda, vaultPda, feeVaultPda, rateLimitConfigPda, mockPriceFeed);
 
     // Get mock tokens
     mockUSDT = sharedState.getMockUSDT();
@@ -219,6 +245,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: null, 
           gatewayTokenAccount: null, 
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -265,6 +292,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: null, 
           gatewayTokenAccount: null, 
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -308,6 +336,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: null, 
           gatewayTokenAccount: null, 
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -345,6 +374,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: null, 
           gatewayTokenAccount: null, 
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -384,6 +414,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: null, 
           gatewayTokenAccount: null, 
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -424,6 +455,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: null,
           gatewayTokenAccount: null,
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -463,6 +495,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
             userTokenAccount: null,
             gatewayTokenAccount: null,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
             tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -479,6 +512,142 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
         expect(errorCode).to.equal("InvalidAmount");
       }
     });
+
+    it("Should unwrap wSOL deposits and route them as native SOL", async () => {
+      const fundsAmount = 0.25 * LAMPORTS_PER_SOL;
+      const wrappedAmount = 0.3 * LAMPORTS_PER_SOL; // extra wSOL is returned to the user as SOL
+      const initialVaultBalance = await provider.connection.getBalance(
+        vaultPda
+      );
+
+      const userWsolAta = spl.getAssociatedTokenAddressSync(
+        spl.NATIVE_MINT,
+        user1.publicKey
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          spl.createAssociatedTokenAccountIdempotentInstruction(
+            user1.publicKey,
+            userWsolAta,
+            user1.publicKey,
+            spl.NATIVE_MINT
+          ),
+          SystemProgram.transfer({
+            fromPubkey: user1.publicKey,
+            toPubkey: userWsolAta,
+            lamports: wrappedAmount,
+          }),
+          spl.createSyncNativeInstruction(userWsolAta)
+        ),
+        [user1]
+      );
+
+      const req = {
+        recipient: Array.from(Buffer.alloc(20, 0)),
+        token: spl.NATIVE_MINT,
+        amount: new anchor.BN(fundsAmount),
+        payload: Buffer.from([]),
+        revertRecipient: user1.publicKey,
+        signatureData: Buffer.from("wsol_sig"),
+      };
+
+      await program.methods
+        .sendUniversalTx(req, withProtocolFee(0)) // only the fee is sent as native SOL
+        .accountsPartial({
+          config: configPda,
+          vault: vaultPda,
+          feeVault: feeVaultPda,
+          userTokenAccount: userWsolAta,
+          gatewayTokenAccount: null,
+          user: user1.publicKey,
+          solOracle: solOraclePda,
+          priceUpdate: mockPriceFeed,
+          rateLimitConfig: rateLimitConfigPda,
+          tokenRateLimit: getTokenRateLimitPda(PublicKey.default), // native rate limit
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const finalVaultBalance = await provider.connection.getBalance(vaultPda);
+      expect(finalVaultBalance - initialVaultBalance).to.equal(fundsAmount);
+      expect(await provider.connection.getAccountInfo(userWsolAta)).to.be.null;
+    });
+
+    it("Should bridge native SOL funds without a price account", async () => {
+      const fundsAmount = 0.25 * LAMPORTS_PER_SOL;
+      const initialVaultBalance = await provider.connection.getBalance(vaultPda);
+
+      const req = {
+        recipient: Array.from(Buffer.alloc(20, 0)),
+        token: PublicKey.default,
+        amount: new anchor.BN(fundsAmount),
+        payload: Buffer.from([]),
+        revertRecipient: user1.publicKey,
+        signatureData: Buffer.from([]),
+      };
+
+      await program.methods
+        .sendUniversalTx(req, withProtocolFee(fundsAmount))
+        .accountsPartial({
+          config: configPda,
+          vault: vaultPda,
+          feeVault: feeVaultPda,
+          userTokenAccount: null,
+          gatewayTokenAccount: null,
+          user: user1.publicKey,
+          priceUpdate: null,
+          rateLimitConfig: rateLimitConfigPda,
+          tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const finalVaultBalance = await provider.connection.getBalance(vaultPda);
+      expect(finalVaultBalance - initialVaultBalance).to.equal(fundsAmount);
+    });
+
+    it("Should require the price account when native funds carry a gas leg", async () => {
+      const fundsAmount = 0.25 * LAMPORTS_PER_SOL;
+      const gasAmount = calculateSolAmount(2.5, solPrice);
+
+      try {
+        await program.methods
+          .sendUniversalTx(
+            {
+              recipient: Array.from(Buffer.alloc(20, 0)),
+              token: PublicKey.default,
+              amount: new anchor.BN(fundsAmount),
+              payload: Buffer.from("payload"),
+              revertRecipient: user1.publicKey,
+              signatureData: Buffer.from([]),
+            },
+            withProtocolFee(fundsAmount + gasAmount)
+          )
+          .accountsPartial({
+            config: configPda,
+            vault: vaultPda,
+            feeVault: feeVaultPda,
+            userTokenAccount: null,
+            gatewayTokenAccount: null,
+            user: user1.publicKey,
+            priceUpdate: null,
+            rateLimitConfig: rateLimitConfigPda,
+            tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+            tokenProgram: spl.TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have required the price account");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidAccount");
+      }
+    });
   });
 
   describe("FUNDS Route - SPL Token", () => {
@@ -520,6 +689,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: userTokenAccount,
           gatewayTokenAccount: gatewayTokenAccount,
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: usdtTokenRateLimitPda,
@@ -570,6 +740,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
             userTokenAccount: victimTokenAccount,
             gatewayTokenAccount: gatewayTokenAccount,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
             tokenRateLimit: usdtTokenRateLimitPda,
@@ -620,6 +791,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
             userTokenAccount: userTokenAccount,
             gatewayTokenAccount: gatewayTokenAccount,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
             tokenRateLimit: usdtTokenRateLimitPda,
@@ -685,6 +857,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: null, 
           gatewayTokenAccount: null, 
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -725,6 +898,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
             userTokenAccount: null,
             gatewayTokenAccount: null,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
             tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -786,6 +960,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: userTokenAccount,
           gatewayTokenAccount: gatewayTokenAccount,
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: usdcTokenRateLimitPda,
@@ -860,6 +1035,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           userTokenAccount: userTokenAccount,
           gatewayTokenAccount: gatewayTokenAccount,
           user: user1.publicKey,
+          solOracle: solOraclePda,
           priceUpdate: mockPriceFeed,
           rateLimitConfig: rateLimitConfigPda,
           tokenRateLimit: usdcTokenRateLimitPda,
@@ -918,6 +1094,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
             userTokenAccount: userTokenAccount,
             gatewayTokenAccount: gatewayTokenAccount,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
             tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -938,160 +1115,1580 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
     });
   });
 
-  describe("Error Cases", () => {
-    it("Should reject when paused", async () => {
-      await program.methods
-        .pause()
-        .accountsPartial({ pauser: pauser.publicKey, config: configPda })
-        .signers([pauser])
-        .rpc();
+  describe("CPI-composable deposits (send_universal_tx_cpi)", () => {
+    it("Should record the sender as UniversalTx.sender while the payer funds the fee", async () => {
+      // `sender` stands in for a caller program's PDA: it holds no SOL and only signs.
+      const sender = Keypair.generate();
+      const senderTokenAccount = await mockUSDT.createTokenAccount(sender.publicKey);
+      const gatewayTokenAccount = await mockUSDT.createTokenAccount(vaultPda, true);
+      await mockUSDT.mintTo(senderTokenAccount, 100);
+      const tokenAmount = new anchor.BN(100 * 10 ** mockUSDT.config.decimals);
 
-      const gasAmount = calculateSolAmount(2.5, solPrice);
-      const nativeSolTokenRateLimitPda = getTokenRateLimitPda(
-        PublicKey.default
+      const initialGatewayBalance = await mockUSDT.getBalance(gatewayTokenAccount);
+      const initialFeeVaultBalance = await provider.connection.getBalance(feeVaultPda);
+
+      const req = {
+        recipient: Array.from(Buffer.alloc(20, 0)),
+        token: mockUSDT.mint.publicKey,
+        amount: tokenAmount,
+        payload: Buffer.from([]),
+        revertRecipient: sender.publicKey,
+        signatureData: Buffer.from("cpi_sender_sig"),
+      };
+
+      const tx = await program.methods
+        .sendUniversalTxCpi(req, withProtocolFee(0))
+        .accountsPartial({
+          config: configPda,
+          vault: vaultPda,
+          feeVault: feeVaultPda,
+          userTokenAccount: senderTokenAccount,
+          gatewayTokenAccount: gatewayTokenAccount,
+          user: user1.publicKey,
+          solOracle: solOraclePda,
+          priceUpdate: mockPriceFeed,
+          rateLimitConfig: rateLimitConfigPda,
+          tokenRateLimit: getTokenRateLimitPda(mockUSDT.mint.publicKey),
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          sender: sender.publicKey,
+        })
+        .signers([user1, sender])
+        .rpc({ commitment: "confirmed" });
+
+      const finalGatewayBalance = await mockUSDT.getBalance(gatewayTokenAccount);
+      const finalFeeVaultBalance = await provider.connection.getBalance(feeVaultPda);
+      expect(
+        (finalGatewayBalance - initialGatewayBalance) * 10 ** mockUSDT.config.decimals
+      ).to.equal(tokenAmount.toNumber());
+      expect(finalFeeVaultBalance - initialFeeVaultBalance).to.equal(DEFAULT_PROTOCOL_FEE_LAMPORTS);
+
+      const universalTxEvent = (await getTxEvents(provider.connection, program, tx))
+        .find((e) => e?.name === "universalTx");
+      expect(universalTxEvent, "UniversalTx event not found").to.exist;
+      expect(universalTxEvent.data.sender.toString()).to.equal(sender.publicKey.toString());
+    });
+
+    it("Should accept a program-to-program deposit signed by the caller's PDA", async () => {
+      // test_counter.bridge_deposit calls gateway_cpi::send_universal_tx with invoke_signed.
+      const [bridgeAuthority] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bridge_authority")],
+        counterProgram.programId
       );
+      const bridgeTokenAccount = await mockUSDT.createTokenAccount(bridgeAuthority, true);
+      const gatewayTokenAccount = await mockUSDT.createTokenAccount(vaultPda, true);
+      await mockUSDT.mintTo(bridgeTokenAccount, 25);
+      const tokenAmount = new anchor.BN(25 * 10 ** mockUSDT.config.decimals);
+
+      const initialGatewayBalance = await mockUSDT.getBalance(gatewayTokenAccount);
+      const initialFeeVaultBalance = await provider.connection.getBalance(feeVaultPda);
+
+      const tx = await counterProgram.methods
+        .bridgeDeposit(
+          {
+            recipient: Array.from(Buffer.alloc(20, 0)),
+            token: mockUSDT.mint.publicKey,
+            amount: tokenAmount,
+            payload: Buffer.from([]),
+            revertRecipient: user1.publicKey,
+            signatureData: Buffer.from("program_cpi_sig"),
+          },
+          withProtocolFee(0)
+        )
+        .accountsPartial({
+          bridgeAuthority,
+          payer: user1.publicKey,
+          config: configPda,
+          vault: vaultPda,
+          feeVault: feeVaultPda,
+          userTokenAccount: bridgeTokenAccount,
+          gatewayTokenAccount: gatewayTokenAccount,
+          solOracle: solOraclePda,
+          priceUpdate: mockPriceFeed,
+          rateLimitConfig: rateLimitConfigPda,
+          tokenRateLimit: getTokenRateLimitPda(mockUSDT.mint.publicKey),
+          gatewayProgram: program.programId,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc({ commitment: "confirmed" });
+
+      const finalGatewayBalance = await mockUSDT.getBalance(gatewayTokenAccount);
+      const finalFeeVaultBalance = await provider.connection.getBalance(feeVaultPda);
+      expect(
+        (finalGatewayBalance - initialGatewayBalance) * 10 ** mockUSDT.config.decimals
+      ).to.equal(tokenAmount.toNumber());
+      expect(finalFeeVaultBalance - initialFeeVaultBalance).to.equal(DEFAULT_PROTOCOL_FEE_LAMPORTS);
+
+      const universalTxEvent = (await getTxEvents(provider.connection, program, tx))
+        .find((e) => e?.name === "universalTx");
+      expect(universalTxEvent, "UniversalTx event not found").to.exist;
+      expect(universalTxEvent.data.sender.toString()).to.equal(bridgeAuthority.toString());
+    });
 
+    it("Should reject send_universal_tx_cpi without a sender (InvalidAccount)", async () => {
       const req = {
         recipient: Array.from(Buffer.alloc(20, 0)),
         token: PublicKey.default,
         amount: new anchor.BN(0),
         payload: Buffer.from([]),
         revertRecipient: user1.publicKey,
-        signatureData: Buffer.from("sig"),
+        signatureData: Buffer.from("cpi_no_sender"),
       };
 
       try {
         await program.methods
-          .sendUniversalTx(req, new anchor.BN(gasAmount))
+          .sendUniversalTxCpi(req, withProtocolFee(calculateSolAmount(2, solPrice)))
           .accountsPartial({
             config: configPda,
             vault: vaultPda,
             feeVault: feeVaultPda,
-            userTokenAccount: null, 
-            gatewayTokenAccount: null, 
+            userTokenAccount: null,
+            gatewayTokenAccount: null,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
-            tokenRateLimit: nativeSolTokenRateLimitPda,
+            tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
             tokenProgram: spl.TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            sender: null,
           })
           .signers([user1])
           .rpc();
-        expect.fail("Should reject when paused");
+        expect.fail("Should have rejected a CPI deposit without sender");
       } catch (error: any) {
-        expect(error).to.exist;
-        expect(error.error?.errorCode?.code || error.code).to.equal("Paused");
-      }
-
-      await program.methods
-        .unpause()
-        .accountsPartial({ pauser: pauser.publicKey, config: configPda })
-        .signers([pauser])
-        .rpc();
-    });
-
-    it("Should reject GAS route amounts outside USD cap bounds", async () => {
-      // Snapshot current caps — other test files may leave config at different values.
-      // We pin to known bounds, test, then restore the exact prior state in `finally`.
-      const configBefore = await program.account.config.fetch(configPda);
-      const prevMin = configBefore.minCapUniversalTxUsd;
-      const prevMax = configBefore.maxCapUniversalTxUsd;
-
-      await program.methods
-        .setCapsUsd(new anchor.BN(100_000_000), new anchor.BN(1_000_000_000)) // $1 min / $10 max
-        .accountsPartial({ admin: admin.publicKey, config: configPda })
-        .signers([admin])
-        .rpc();
-
-      const nativeSolTokenRateLimitPda = getTokenRateLimitPda(PublicKey.default);
-      const cases = [
-        { label: "BelowMinCap", usd: 0.5,  expectedError: "BelowMinCap" },  // $0.50 < $1 min
-        { label: "AboveMaxCap", usd: 15.0, expectedError: "AboveMaxCap" },  // $15 > $10 max
-      ];
-
-      try {
-        for (const { label, usd, expectedError } of cases) {
-          const gasAmount = calculateSolAmount(usd, solPrice);
-          const req = {
-            recipient: Array.from(Buffer.alloc(20, 0)),
-            token: PublicKey.default,
-            amount: new anchor.BN(0),
-            payload: Buffer.from([]),
-            revertRecipient: user1.publicKey,
-            signatureData: Buffer.from(label),
-          };
-          try {
-            await program.methods
-              .sendUniversalTx(req, withProtocolFee(gasAmount))
-              .accountsPartial({
-                config: configPda,
-                vault: vaultPda,
-                feeVault: feeVaultPda,
-                userTokenAccount: null,
-                gatewayTokenAccount: null,
-                user: user1.publicKey,
-                priceUpdate: mockPriceFeed,
-                rateLimitConfig: rateLimitConfigPda,
-                tokenRateLimit: nativeSolTokenRateLimitPda,
-                tokenProgram: spl.TOKEN_PROGRAM_ID,
-                systemProgram: SystemProgram.programId,
-              })
-              .signers([user1])
-              .rpc();
-            expect.fail(`${label}: Should have rejected with ${expectedError}`);
-          } catch (error: any) {
-            const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
-            expect(errorCode).to.equal(expectedError, `${label}: wrong error code`);
-          }
-        }
-      } finally {
-        await program.methods
-          .setCapsUsd(prevMin, prevMax)
-          .accountsPartial({ admin: admin.publicKey, config: configPda })
-          .signers([admin])
-          .rpc();
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidAccount");
       }
     });
 
-    it("Should reject invalid parameter combinations (no gas or funds)", async () => {
-      const nativeSolTokenRateLimitPda = getTokenRateLimitPda(
-        PublicKey.default
+    it("Should reject a wSOL deposit whose sender is not the payer", async () => {
+      // Closing the wSOL account would hand the surplus and rent to the third-party payer.
+      const sender = Keypair.generate();
+      const senderWsolAta = spl.getAssociatedTokenAddressSync(spl.NATIVE_MINT, sender.publicKey);
+      const depositAmount = 0.1 * LAMPORTS_PER_SOL;
+      const wrappedAmount = 0.5 * LAMPORTS_PER_SOL;
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          spl.createAssociatedTokenAccountIdempotentInstruction(
+            user1.publicKey,
+            senderWsolAta,
+            sender.publicKey,
+            spl.NATIVE_MINT
+          ),
+          SystemProgram.transfer({
+            fromPubkey: user1.publicKey,
+            toPubkey: senderWsolAta,
+            lamports: wrappedAmount,
+          }),
+          spl.createSyncNativeInstruction(senderWsolAta)
+        ),
+        [user1]
       );
-
-      const req = {
-        recipient: Array.from(Buffer.alloc(20, 0)),
-        token: PublicKey.default,
-        amount: new anchor.BN(0),
-        payload: Buffer.from([]),
-        revertRecipient: user1.publicKey,
-        signatureData: Buffer.from("sig"),
-      };
+      const senderWsolBefore = await provider.connection.getTokenAccountBalance(senderWsolAta);
 
       try {
         await program.methods
-          .sendUniversalTx(req, withProtocolFee(0))
+          .sendUniversalTxCpi(
+            {
+              recipient: Array.from(Buffer.alloc(20, 0)),
+              token: spl.NATIVE_MINT,
+              amount: new anchor.BN(depositAmount),
+              payload: Buffer.from([]),
+              revertRecipient: sender.publicKey,
+              signatureData: Buffer.from("cpi_wsol"),
+            },
+            withProtocolFee(0)
+          )
           .accountsPartial({
             config: configPda,
             vault: vaultPda,
             feeVault: feeVaultPda,
-            userTokenAccount: null,
+            userTokenAccount: senderWsolAta,
             gatewayTokenAccount: null,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
-            tokenRateLimit: nativeSolTokenRateLimitPda,
+            tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
             tokenProgram: spl.TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            sender: sender.publicKey,
           })
-          .signers([user1])
+          .signers([user1, sender])
           .rpc();
-        expect.fail("Should reject parameter set without gas or funds");
+        expect.fail("Should have rejected a wSOL deposit paid by a third party");
       } catch (error: any) {
-        expect(error).to.exist;
-        const errorCode =
-          error.error?.errorCode?.code || error.error?.errorCode || error.code;
-        expect(errorCode).to.equal("InvalidInput");
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidAccount");
+      }
+
+      const senderWsolAfter = await provider.connection.getTokenAccountBalance(senderWsolAta);
+      expect(senderWsolAfter.value.amount).to.equal(senderWsolBefore.value.amount);
+    });
+  });
+
+  describe("Batched deposits (send_universal_tx_batch)", () => {
+    const batchAccounts = (tokenRateLimit: PublicKey) => ({
+      config: configPda,
+      vault: vaultPda,
+      feeVault: feeVaultPda,
+      userTokenAccount: null,
+      gatewayTokenAccount: null,
+      user: user1.publicKey,
+      solOracle: solOraclePda,
+      priceUpdate: mockPriceFeed,
+      rateLimitConfig: rateLimitConfigPda,
+      tokenRateLimit,
+      tokenProgram: spl.TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    });
+
+    const setBatchFeeMode = async (feePerBatch: boolean) => {
+      await program.methods
+        .setBatchFeeMode(feePerBatch)
+        .accountsPartial({
+          config: configPda,
+          feeVault: feeVaultPda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    };
+
+    const nativeFundsReq = (recipientByte: number, lamports: number) => ({
+      recipient: Array.from(Buffer.alloc(20, recipientByte)),
+      token: PublicKey.default,
+      amount: new anchor.BN(lamports),
+      payload: Buffer.from([]),
+      revertRecipient: user1.publicKey,
+      signatureData: Buffer.from([]),
+    });
+
+    it("Should bridge native and SPL requests in one instruction and charge the fee per request", async () => {
+      const nativeRateLimit = getTokenRateLimitPda(PublicKey.default);
+      const usdtRateLimit = getTokenRateLimitPda(mockUSDT.mint.publicKey);
+      const userTokenAccount = await mockUSDT.createTokenAccount(user1.publicKey);
+      const gatewayTokenAccount = await mockUSDT.createTokenAccount(vaultPda, true);
+      await mockUSDT.mintTo(userTokenAccount, 50);
+      const tokenAmount = new anchor.BN(50 * 10 ** mockUSDT.config.decimals);
+
+      const fundsA = 0.1 * LAMPORTS_PER_SOL;
+      const fundsB = 0.2 * LAMPORTS_PER_SOL;
+      const reqs = [
+        nativeFundsReq(0x11, fundsA),
+        nativeFundsReq(0x22, fundsB),
+        {
+          recipient: Array.from(Buffer.alloc(20, 0x33)),
+          token: mockUSDT.mint.publicKey,
+          amount: tokenAmount,
+          payload: Buffer.from([]),
+          revertRecipient: user1.publicKey,
+          signatureData: Buffer.from([]),
+        },
+      ];
+      const nativeAmounts = [
+        withProtocolFee(fundsA),
+        withProtocolFee(fundsB),
+        withProtocolFee(0),
+      ];
+
+      const initialVaultBalance = await provider.connection.getBalance(vaultPda);
+      const initialFeeVaultBalance = await provider.connection.getBalance(feeVaultPda);
+      const initialGatewayTokenBalance = await mockUSDT.getBalance(gatewayTokenAccount);
+
+      const tx = await program.methods
+        .sendUniversalTxBatch(reqs, nativeAmounts)
+        .accountsPartial(batchAccounts(nativeRateLimit))
+        .remainingAccounts([
+          ...batchSlot(nativeRateLimit),
+          ...batchSlot(nativeRateLimit),
+          ...batchSlot(usdtRateLimit, [userTokenAccount, gatewayTokenAccount]),
+        ])
+        .signers([user1])
+        .rpc({ commitment: "confirmed" });
+
+      expect((await provider.connection.getBalance(vaultPda)) - initialVaultBalance).to.equal(
+        fundsA + fundsB
+      );
+      expect(
+        (await provider.connection.getBalance(feeVaultPda)) - initialFeeVaultBalance
+      ).to.equal(3 * DEFAULT_PROTOCOL_FEE_LAMPORTS);
+      expect(
+        ((await mockUSDT.getBalance(gatewayTokenAccount)) - initialGatewayTokenBalance) *
+          10 ** mockUSDT.config.decimals
+      ).to.equal(tokenAmount.toNumber());
+
+      const recipients = (await getTxEvents(provider.connection, program, tx))
+        .filter((e) => e?.name === "universalTx")
+        .map((e) => Buffer.from(e.data.recipient).toString("hex"));
+      expect(recipients).to.deep.equal([
+        Buffer.alloc(20, 0x11).toString("hex"),
+        Buffer.alloc(20, 0x22).toString("hex"),
+        Buffer.alloc(20, 0x33).toString("hex"),
+      ]);
+    });
+
+    it("Should charge the protocol fee once per batch when fee_per_batch is set", async () => {
+      const nativeRateLimit = getTokenRateLimitPda(PublicKey.default);
+      const funds = 0.05 * LAMPORTS_PER_SOL;
+      await setBatchFeeMode(true);
+
+      try {
+        const initialFeeVaultBalance = await provider.connection.getBalance(feeVaultPda);
+
+        await program.methods
+          .sendUniversalTxBatch(
+            [nativeFundsReq(0x44, funds), nativeFundsReq(0x55, funds)],
+            [withProtocolFee(funds), new anchor.BN(funds)]
+          )
+          .accountsPartial(batchAccounts(nativeRateLimit))
+          .remainingAccounts([...batchSlot(nativeRateLimit), ...batchSlot(nativeRateLimit)])
+          .signers([user1])
+          .rpc();
+
+        expect(
+          (await provider.connection.getBalance(feeVaultPda)) - initialFeeVaultBalance
+        ).to.equal(DEFAULT_PROTOCOL_FEE_LAMPORTS);
+      } finally {
+        await setBatchFeeMode(false);
+      }
+    });
+
+    it("Should reject a batch whose remaining accounts do not match the requests", async () => {
+      const nativeRateLimit = getTokenRateLimitPda(PublicKey.default);
+      const funds = 0.05 * LAMPORTS_PER_SOL;
+
+      try {
+        await program.methods
+          .sendUniversalTxBatch(
+            [nativeFundsReq(0x66, funds), nativeFundsReq(0x77, funds)],
+            [withProtocolFee(funds), withProtocolFee(funds)]
+          )
+          .accountsPartial(batchAccounts(nativeRateLimit))
+          .remainingAccounts(batchSlot(nativeRateLimit))
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected a batch with missing rate-limit accounts");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("AccountListLengthMismatch");
+      }
+    });
+
+    it("Should reject a batch with more than one wSOL request", async () => {
+      const nativeRateLimit = getTokenRateLimitPda(PublicKey.default);
+      const funds = 0.05 * LAMPORTS_PER_SOL;
+      const userWsolAta = spl.getAssociatedTokenAddressSync(spl.NATIVE_MINT, user1.publicKey);
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          spl.createAssociatedTokenAccountIdempotentInstruction(
+            user1.publicKey,
+            userWsolAta,
+            user1.publicKey,
+            spl.NATIVE_MINT
+          ),
+          SystemProgram.transfer({
+            fromPubkey: user1.publicKey,
+            toPubkey: userWsolAta,
+            lamports: 2 * funds,
+          }),
+          spl.createSyncNativeInstruction(userWsolAta)
+        ),
+        [user1]
+      );
+      const wsolReq = (recipientByte: number) => ({
+        ...nativeFundsReq(recipientByte, funds),
+        token: spl.NATIVE_MINT,
+      });
+      // Both requests would unwrap, and close, the same wSOL account.
+      const wsolAccounts = batchSlot(nativeRateLimit, [userWsolAta, vaultPda]);
+
+      try {
+        await program.methods
+          .sendUniversalTxBatch(
+            [wsolReq(0x88), wsolReq(0x99)],
+            [withProtocolFee(0), withProtocolFee(0)]
+          )
+          .accountsPartial(batchAccounts(nativeRateLimit))
+          .remainingAccounts([...wsolAccounts, ...wsolAccounts])
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected a second wSOL request");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidInput");
+      }
+      expect(await provider.connection.getAccountInfo(userWsolAta)).to.not.be.null;
+    });
+  });
+
+  describe("Multi-token bundles (send_universal_tx_bundle)", () => {
+    const bundleAccounts = {
+      userTokenAccount: null,
+      gatewayTokenAccount: null,
+      tokenProgram: spl.TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+
+    it("Should lock SOL, USDT and USDC atomically and emit one UniversalTxBundle", async () => {
+      const nativeRateLimit = getTokenRateLimitPda(PublicKey.default);
+      const usdtRateLimit = getTokenRateLimitPda(mockUSDT.mint.publicKey);
+      const usdcRateLimit = getTokenRateLimitPda(mockUSDC.mint.publicKey);
+
+      const userUsdt = await mockUSDT.createTokenAccount(user1.publicKey);
+      const vaultUsdt = await mockUSDT.createTokenAccount(vaultPda, true);
+      const userUsdc = await mockUSDC.createTokenAccount(user1.publicKey);
+      const vaultUsdc = await mockUSDC.createTokenAccount(vaultPda, true);
+      await mockUSDT.mintTo(userUsdt, 10);
+      await mockUSDC.mintTo(userUsdc, 20);
+
+      const solLeg = 0.1 * LAMPORTS_PER_SOL;
+      const usdtLeg = new anchor.BN(10 * 10 ** mockUSDT.config.decimals);
+      const usdcLeg = new anchor.BN(20 * 10 ** mockUSDC.config.decimals);
+      const req = {
+        recipient: Array.from(Buffer.alloc(20, 0)),
+        legs: [
+          { mint: PublicKey.default, amount: new anchor.BN(solLeg) },
+          { mint: mockUSDT.mint.publicKey, amount: usdtLeg },
+          { mint: mockUSDC.mint.publicKey, amount: usdcLeg },
+        ],
+        payload: serializePayload(createPayload(0x12)),
+        revertRecipient: user1.publicKey,
+        signatureData: Buffer.from([]),
+      };
+
+      const initialVaultBalance = await provider.connection.getBalance(vaultPda);
+      const initialVaultUsdt = await mockUSDT.getBalance(vaultUsdt);
+      const initialVaultUsdc = await mockUSDC.getBalance(vaultUsdc);
+
+      const tx = await program.methods
+        .sendUniversalTxBundle(req, withProtocolFee(solLeg))
+        .accountsPartial({
+          ...bundleAccounts,
+          config: configPda,
+          vault: vaultPda,
+          feeVault: feeVaultPda,
+          user: user1.publicKey,
+          solOracle: solOraclePda,
+          priceUpdate: mockPriceFeed,
+          rateLimitConfig: rateLimitConfigPda,
+          tokenRateLimit: nativeRateLimit,
+        })
+        .remainingAccounts([
+          ...batchSlot(nativeRateLimit),
+          ...batchSlot(usdtRateLimit, [userUsdt, vaultUsdt]),
+          ...batchSlot(usdcRateLimit, [userUsdc, vaultUsdc]),
+        ])
+        .signers([user1])
+        .rpc({ commitment: "confirmed" });
+
+      expect((await provider.connection.getBalance(vaultPda)) - initialVaultBalance).to.equal(solLeg);
+      expect((await mockUSDT.getBalance(vaultUsdt)) - initialVaultUsdt).to.equal(10);
+      expect((await mockUSDC.getBalance(vaultUsdc)) - initialVaultUsdc).to.equal(20);
+
+      const events = await getTxEvents(provider.connection, program, tx);
+      const bundleEvent = events.find((e) => e.name === "universalTxBundle");
+      expect(bundleEvent, "UniversalTxBundle event not found").to.exist;
+      expect(bundleEvent.data.txType.fundsAndPayload !== undefined).to.be.true;
+      expect(
+        bundleEvent.data.legs.map((leg: any) => [leg.mint.toString(), leg.amount.toString()])
+      ).to.deep.equal([
+        [PublicKey.default.toString(), solLeg.toString()],
+        [mockUSDT.mint.publicKey.toString(), usdtLeg.toString()],
+        [mockUSDC.mint.publicKey.toString(), usdcLeg.toString()],
+      ]);
+      expect(events.some((e) => e.name === "universalTx"), "no per-leg UniversalTx").to.be
+        .false;
+    });
+
+    it("Should reject a bundle with duplicate token legs", async () => {
+      const nativeRateLimit = getTokenRateLimitPda(PublicKey.default);
+      const req = {
+        recipient: Array.from(Buffer.alloc(20, 0x01)),
+        legs: [
+          { mint: PublicKey.default, amount: new anchor.BN(1000) },
+          { mint: PublicKey.default, amount: new anchor.BN(2000) },
+        ],
+        payload: Buffer.from([]),
+        revertRecipient: user1.publicKey,
+        signatureData: Buffer.from([]),
+      };
+
+      try {
+        await program.methods
+          .sendUniversalTxBundle(req, withProtocolFee(3000))
+          .accountsPartial({
+            ...bundleAccounts,
+            config: configPda,
+            vault: vaultPda,
+            feeVault: feeVaultPda,
+            user: user1.publicKey,
+            solOracle: solOraclePda,
+            priceUpdate: mockPriceFeed,
+            rateLimitConfig: rateLimitConfigPda,
+            tokenRateLimit: nativeRateLimit,
+          })
+          .remainingAccounts([...batchSlot(nativeRateLimit), ...batchSlot(nativeRateLimit)])
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected duplicate bundle legs");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidInput");
+      }
+    });
+
+    it("Should apply per-token fees to bundle legs and allow one bps-fee mint per bundle", async () => {
+      const usdtRateLimit = getTokenRateLimitPda(mockUSDT.mint.publicKey);
+      const usdcRateLimit = getTokenRateLimitPda(mockUSDC.mint.publicKey);
+      const setTokenFee = async (token: any, override: boolean, lamports: number, bps: number) => {
+        await program.methods
+          .setTokenFee(override, new anchor.BN(lamports), bps)
+          .accountsPartial({
+            config: configPda,
+            tokenRateLimit: getTokenRateLimitPda(token.mint.publicKey),
+            tokenMint: token.mint.publicKey,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      };
+
+      const userUsdt = await mockUSDT.createTokenAccount(user1.publicKey);
+      const vaultUsdt = await mockUSDT.createTokenAccount(vaultPda, true);
+      const feeVaultUsdt = await mockUSDT.createTokenAccount(feeVaultPda, true);
+      const userUsdc = await mockUSDC.createTokenAccount(user1.publicKey);
+      const vaultUsdc = await mockUSDC.createTokenAccount(vaultPda, true);
+      await mockUSDT.mintTo(userUsdt, 20);
+      await mockUSDC.mintTo(userUsdc, 10);
+      const usdtLeg = new anchor.BN(10 * 10 ** mockUSDT.config.decimals);
+      const usdcLeg = new anchor.BN(10 * 10 ** mockUSDC.config.decimals);
+      const tokenFeeLamports = 70_000;
+
+      const sendBundle = (legs: any[], remainingAccounts: any[]) =>
+        program.methods
+          .sendUniversalTxBundle(
+            {
+              recipient: Array.from(Buffer.alloc(20, 0x02)),
+              legs,
+              payload: Buffer.from([]),
+              revertRecipient: user1.publicKey,
+              signatureData: Buffer.from([]),
+            },
+            new anchor.BN(tokenFeeLamports)
+          )
+          .accountsPartial({
+            ...bundleAccounts,
+            config: configPda,
+            vault: vaultPda,
+            feeVault: feeVaultPda,
+            user: user1.publicKey,
+            solOracle: solOraclePda,
+            priceUpdate: mockPriceFeed,
+            rateLimitConfig: rateLimitConfigPda,
+            tokenRateLimit: usdtRateLimit,
+            feeTokenAccount: feeVaultUsdt,
+          })
+          .remainingAccounts(remainingAccounts)
+          .signers([user1])
+          .rpc({ commitment: "confirmed" });
+
+      try {
+        // 1% of the USDT leg and a 70_000 lamport override replace the default protocol fee.
+        await setTokenFee(mockUSDT, true, tokenFeeLamports, 100);
+        const feeVaultBefore = await provider.connection.getBalance(feeVaultPda);
+        const vaultUsdtBefore = await mockUSDT.getBalance(vaultUsdt);
+        const feeVaultUsdtBefore = await mockUSDT.getBalance(feeVaultUsdt);
+
+        const tx = await sendBundle(
+          [{ mint: mockUSDT.mint.publicKey, amount: usdtLeg }],
+          batchSlot(usdtRateLimit, [userUsdt, vaultUsdt])
+        );
+
+        expect((await provider.connection.getBalance(feeVaultPda)) - feeVaultBefore).to.equal(
+          tokenFeeLamports
+        );
+        expect((await mockUSDT.getBalance(vaultUsdt)) - vaultUsdtBefore).to.be.closeTo(9.9, 1e-9);
+        expect((await mockUSDT.getBalance(feeVaultUsdt)) - feeVaultUsdtBefore).to.be.closeTo(
+          0.1,
+          1e-9
+        );
+
+        const bundleEvent = (await getTxEvents(provider.connection, program, tx))
+          .find((e) => e?.name === "universalTxBundle");
+        expect(bundleEvent, "UniversalTxBundle event not found").to.exist;
+        expect(bundleEvent.data.legs[0].amount.toString()).to.equal(
+          usdtLeg.muln(99).divn(100).toString()
+        );
+
+        // A second bps-fee mint cannot share the single fee_token_account.
+        await setTokenFee(mockUSDC, false, 0, 100);
+        try {
+          await sendBundle(
+            [
+              { mint: mockUSDT.mint.publicKey, amount: usdtLeg },
+              { mint: mockUSDC.mint.publicKey, amount: usdcLeg },
+            ],
+            [
+              ...batchSlot(usdtRateLimit, [userUsdt, vaultUsdt]),
+              ...batchSlot(usdcRateLimit, [userUsdc, vaultUsdc]),
+            ]
+          );
+          expect.fail("Should have rejected two bps-fee mints in one bundle");
+        } catch (error: any) {
+          const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+          expect(errorCode).to.equal("InvalidInput");
+        }
+      } finally {
+        await setTokenFee(mockUSDT, false, 0, 0);
+        await setTokenFee(mockUSDC, false, 0, 0);
+      }
+    });
+    it("Should price each bundle leg through its own oracle slot under the epoch USD cap", async () => {
+      const nativeRateLimit = getTokenRateLimitPda(PublicKey.default);
+      const usdtRateLimit = getTokenRateLimitPda(mockUSDT.mint.publicKey);
+      const oraclePda = (mint: PublicKey) =>
+        PublicKey.findProgramAddressSync([Buffer.from("oracle"), mint.toBuffer()], program.programId)[0];
+      const rateLimitConfigAccounts = {
+        admin: admin.publicKey,
+        config: configPda,
+        rateLimitConfig: rateLimitConfigPda,
+        systemProgram: SystemProgram.programId,
+      };
+      // USDT reuses the SOL/USD mock account as its feed; only per-leg routing is under test.
+      for (const mint of [PublicKey.default, mockUSDT.mint.publicKey]) {
+        await program.methods
+          .setOracle(mockPriceFeed, Array.from(Buffer.from(SOL_FEED_ID, "hex")), new anchor.BN(0), 0, 0)
+          .accountsPartial({
+            config: configPda,
+            oracle: oraclePda(mint),
+            tokenMint: mint,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      }
+
+      const userUsdt = await mockUSDT.createTokenAccount(user1.publicKey);
+      const vaultUsdt = await mockUSDT.createTokenAccount(vaultPda, true);
+      await mockUSDT.mintTo(userUsdt, 2);
+      const solLeg = calculateSolAmount(1, solPrice);
+      const usdtLeg = new anchor.BN(10 ** mockUSDT.config.decimals);
+      const solOracle = { registry: oraclePda(PublicKey.default), priceUpdate: mockPriceFeed };
+      const usdtOracle = { registry: oraclePda(mockUSDT.mint.publicKey), priceUpdate: mockPriceFeed };
+
+      const sendBundle = (remainingAccounts: any[]) =>
+        program.methods
+          .sendUniversalTxBundle(
+            {
+              recipient: Array.from(Buffer.alloc(20, 0x03)),
+              legs: [
+                { mint: PublicKey.default, amount: new anchor.BN(solLeg) },
+                { mint: mockUSDT.mint.publicKey, amount: usdtLeg },
+              ],
+              payload: Buffer.from([]),
+              revertRecipient: user1.publicKey,
+              signatureData: Buffer.from([]),
+            },
+            withProtocolFee(solLeg)
+          )
+          .accountsPartial({
+            ...bundleAccounts,
+            config: configPda,
+            vault: vaultPda,
+            feeVault: feeVaultPda,
+            user: user1.publicKey,
+            solOracle: solOraclePda,
+            priceUpdate: mockPriceFeed,
+            rateLimitConfig: rateLimitConfigPda,
+            tokenRateLimit: nativeRateLimit,
+          })
+          .remainingAccounts(remainingAccounts)
+          .signers([user1])
+          .rpc();
+
+      await program.methods
+        .updateEpochDuration(new anchor.BN(3600))
+        .accountsPartial(rateLimitConfigAccounts)
+        .signers([admin])
+        .rpc();
+      // $1,000,000 per epoch (8 decimals): enabled, but never the binding limit here.
+      await program.methods
+        .setEpochUsdCap(new anchor.BN("100000000000000"))
+        .accountsPartial(rateLimitConfigAccounts)
+        .signers([admin])
+        .rpc();
+
+      try {
+        const usedBefore = (await program.account.rateLimitConfig.fetch(rateLimitConfigPda))
+          .epochUsdUsage.used;
+        await sendBundle([
+          ...batchSlot(nativeRateLimit, [], solOracle),
+          ...batchSlot(usdtRateLimit, [userUsdt, vaultUsdt], usdtOracle),
+        ]);
+        const usedAfter = (await program.account.rateLimitConfig.fetch(rateLimitConfigPda))
+          .epochUsdUsage.used;
+        // $1 of SOL plus one USDT priced at the SOL/USD mock.
+        expect(usedAfter.sub(usedBefore).toNumber() / 1e8).to.be.closeTo(1 + solPrice, 0.01);
+
+        try {
+          await sendBundle([
+            ...batchSlot(nativeRateLimit, [], solOracle),
+            ...batchSlot(usdtRateLimit, [userUsdt, vaultUsdt]),
+          ]);
+          expect.fail("Should have rejected an unpriced leg under the epoch USD cap");
+        } catch (error: any) {
+          const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+          expect(errorCode).to.equal("InvalidAccount");
+        }
+      } finally {
+        await program.methods
+          .setEpochUsdCap(new anchor.BN(0))
+          .accountsPartial(rateLimitConfigAccounts)
+          .signers([admin])
+          .rpc();
+        await program.methods
+          .updateEpochDuration(new anchor.BN(0))
+          .accountsPartial(rateLimitConfigAccounts)
+          .signers([admin])
+          .rpc();
+      }
+    });
+  });
+
+  describe("Sponsored deposits (send_universal_tx_sponsored)", () => {
+    let depositDelegatePda: PublicKey;
+
+    before(() => {
+      [depositDelegatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("deposit_delegate")],
+        program.programId
+      );
+    });
+
+    // Depositor holds only USDT (no SOL); user1 sponsors the approval and the deposit.
+    const setupDepositor = async (tokens: number) => {
+      const depositor = Keypair.generate();
+      const depositorTokenAccount = await mockUSDT.createTokenAccount(depositor.publicKey);
+      await mockUSDT.mintTo(depositorTokenAccount, tokens);
+      await spl.approve(
+        provider.connection,
+        user1,
+        depositorTokenAccount,
+        depositDelegatePda,
+        depositor,
+        BigInt(tokens * 10 ** mockUSDT.config.decimals)
+      );
+      return { depositor, depositorTokenAccount };
+    };
+
+    const sponsoredAccounts = (depositor: PublicKey, userTokenAccount: PublicKey, gatewayTokenAccount: PublicKey) => ({
+      config: configPda,
+      vault: vaultPda,
+      feeVault: feeVaultPda,
+      userTokenAccount,
+      gatewayTokenAccount,
+      user: user1.publicKey,
+      solOracle: solOraclePda,
+      priceUpdate: mockPriceFeed,
+      rateLimitConfig: rateLimitConfigPda,
+      tokenRateLimit: getTokenRateLimitPda(mockUSDT.mint.publicKey),
+      tokenProgram: spl.TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      depositor,
+      depositDelegate: depositDelegatePda,
+    });
+
+    it("Should pull delegated tokens while the sponsor pays the fee", async () => {
+      const { depositor, depositorTokenAccount } = await setupDepositor(25);
+      const gatewayTokenAccount = await mockUSDT.createTokenAccount(vaultPda, true);
+      const tokenAmount = new anchor.BN(25 * 10 ** mockUSDT.config.decimals);
+
+      const initialGatewayBalance = await mockUSDT.getBalance(gatewayTokenAccount);
+      const initialFeeVaultBalance = await provider.connection.getBalance(feeVaultPda);
+
+      const tx = await program.methods
+        .sendUniversalTxSponsored(
+          {
+            recipient: Array.from(Buffer.alloc(20, 0)),
+            token: mockUSDT.mint.publicKey,
+            amount: tokenAmount,
+            payload: Buffer.from([]),
+            revertRecipient: depositor.publicKey,
+            signatureData: Buffer.from([]),
+          },
+          withProtocolFee(0)
+        )
+        .accountsPartial(
+          sponsoredAccounts(depositor.publicKey, depositorTokenAccount, gatewayTokenAccount)
+        )
+        .signers([user1])
+        .rpc({ commitment: "confirmed" });
+
+      expect((await mockUSDT.getBalance(gatewayTokenAccount)) - initialGatewayBalance).to.equal(25);
+      expect(
+        (await provider.connection.getBalance(feeVaultPda)) - initialFeeVaultBalance
+      ).to.equal(DEFAULT_PROTOCOL_FEE_LAMPORTS);
+      expect(await provider.connection.getBalance(depositor.publicKey)).to.equal(0);
+
+      const universalTxEvent = (await getTxEvents(provider.connection, program, tx))
+        .find((e) => e?.name === "universalTx");
+      expect(universalTxEvent, "UniversalTx event not found").to.exist;
+      expect(universalTxEvent.data.sender.toString()).to.equal(depositor.publicKey.toString());
+    });
+
+    it("Should reject a sponsored deposit to a recipient other than the depositor's UEA", async () => {
+      const { depositor, depositorTokenAccount } = await setupDepositor(5);
+      const gatewayTokenAccount = await mockUSDT.createTokenAccount(vaultPda, true);
+
+      try {
+        await program.methods
+          .sendUniversalTxSponsored(
+            {
+              recipient: Array.from(Buffer.alloc(20, 0xaa)),
+              token: mockUSDT.mint.publicKey,
+              amount: new anchor.BN(5 * 10 ** mockUSDT.config.decimals),
+              payload: Buffer.from([]),
+              revertRecipient: depositor.publicKey,
+              signatureData: Buffer.from([]),
+            },
+            withProtocolFee(0)
+          )
+          .accountsPartial(
+            sponsoredAccounts(depositor.publicKey, depositorTokenAccount, gatewayTokenAccount)
+          )
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected a sponsored deposit to a foreign recipient");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidRecipient");
+      }
+    });
+
+    it("Should reject a sponsored deposit with a payload (InvalidPayload)", async () => {
+      const { depositor, depositorTokenAccount } = await setupDepositor(5);
+      const gatewayTokenAccount = await mockUSDT.createTokenAccount(vaultPda, true);
+
+      try {
+        await program.methods
+          .sendUniversalTxSponsored(
+            {
+              recipient: Array.from(Buffer.alloc(20, 0)),
+              token: mockUSDT.mint.publicKey,
+              amount: new anchor.BN(5 * 10 ** mockUSDT.config.decimals),
+              payload: Buffer.from([0xde, 0xad, 0xbe, 0xef]),
+              revertRecipient: depositor.publicKey,
+              signatureData: Buffer.from([]),
+            },
+            withProtocolFee(0)
+          )
+          .accountsPartial(
+            sponsoredAccounts(depositor.publicKey, depositorTokenAccount, gatewayTokenAccount)
+          )
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected a sponsored deposit with a payload");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidPayload");
+      }
+    });
+
+    it("Should reject the delegate pull through send_universal_tx (InvalidAccount)", async () => {
+      const { depositor, depositorTokenAccount } = await setupDepositor(5);
+      const gatewayTokenAccount = await mockUSDT.createTokenAccount(vaultPda, true);
+
+      try {
+        await program.methods
+          .sendUniversalTx(
+            {
+              recipient: Array.from(Buffer.alloc(20, 0xaa)),
+              token: mockUSDT.mint.publicKey,
+              amount: new anchor.BN(5 * 10 ** mockUSDT.config.decimals),
+              payload: Buffer.from([]),
+              revertRecipient: user1.publicKey,
+              signatureData: Buffer.from([]),
+            },
+            withProtocolFee(0)
+          )
+          .accountsPartial(
+            sponsoredAccounts(depositor.publicKey, depositorTokenAccount, gatewayTokenAccount)
+          )
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected sponsored accounts on send_universal_tx");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidAccount");
+      }
+    });
+  });
+
+  describe("Swap-to-gas (send_universal_tx_swap_gas)", () => {
+    const adapterProgram = anchor.workspace.CpSwapAdapter as Program<CpSwapAdapter>;
+    let poolPda: PublicKey;
+    let poolVaultPda: PublicKey;
+    let user1UsdtAccount: PublicKey;
+
+    before(async () => {
+      const mint = mockUSDT.mint.publicKey;
+      [poolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pool"), mint.toBuffer()],
+        adapterProgram.programId
+      );
+      [poolVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pool_vault"), mint.toBuffer()],
+        adapterProgram.programId
+      );
+
+      user1UsdtAccount = await mockUSDT.createTokenAccount(user1.publicKey);
+      await mockUSDT.mintTo(user1UsdtAccount, 2000);
+
+      // ~1 USDT ≈ 1 USD at the mock SOL price of 150.25.
+      if (!(await provider.connection.getAccountInfo(poolPda))) {
+        await adapterProgram.methods
+          .initializePool(
+            new anchor.BN(1000 * 10 ** mockUSDT.config.decimals),
+            new anchor.BN(Math.floor((1000 / solPrice) * LAMPORTS_PER_SOL))
+          )
+          .accountsPartial({
+            pool: poolPda,
+            tokenVault: poolVaultPda,
+            mint,
+            providerTokenAccount: user1UsdtAccount,
+            provider: user1.publicKey,
+            tokenProgram: spl.TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+      }
+
+      await program.methods
+        .setSwapAdapter(adapterProgram.programId)
+        .accountsPartial({ config: configPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+    });
+
+    const gasReq = () => ({
+      recipient: Array.from(Buffer.alloc(20, 0)),
+      token: PublicKey.default,
+      amount: new anchor.BN(0),
+      payload: Buffer.from([]),
+      revertRecipient: user1.publicKey,
+      signatureData: Buffer.from([]),
+    });
+
+    const swapGasAccounts = () => ({
+      config: configPda,
+      vault: vaultPda,
+      feeVault: feeVaultPda,
+      userTokenAccount: null,
+      gatewayTokenAccount: null,
+      user: user1.publicKey,
+      solOracle: solOraclePda,
+      priceUpdate: mockPriceFeed,
+      rateLimitConfig: rateLimitConfigPda,
+      tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+      tokenProgram: spl.TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    });
+
+    const adapterRemainingAccounts = (adapterId: PublicKey) => [
+      { pubkey: adapterId, isSigner: false, isWritable: false },
+      { pubkey: user1.publicKey, isSigner: true, isWritable: true },
+      { pubkey: user1UsdtAccount, isSigner: false, isWritable: true },
+      { pubkey: poolPda, isSigner: false, isWritable: true },
+      { pubkey: poolVaultPda, isSigner: false, isWritable: true },
+      { pubkey: spl.TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    it("Should swap USDT into SOL and route the proceeds as gas", async () => {
+      const amountIn = new anchor.BN(3 * 10 ** mockUSDT.config.decimals);
+      const minOut = new anchor.BN(Math.floor(calculateSolAmount(2.5, solPrice)));
+
+      const initialUsdt = await mockUSDT.getBalance(user1UsdtAccount);
+      const initialVaultBalance = await provider.connection.getBalance(vaultPda);
+      const initialFeeVaultBalance = await provider.connection.getBalance(feeVaultPda);
+
+      const tx = await program.methods
+        .sendUniversalTxSwapGas(gasReq(), amountIn, minOut, withProtocolFee(0))
+        .accountsPartial(swapGasAccounts())
+        .remainingAccounts(adapterRemainingAccounts(adapterProgram.programId))
+        .signers([user1])
+        .rpc({ commitment: "confirmed" });
+
+      expect(initialUsdt - (await mockUSDT.getBalance(user1UsdtAccount))).to.equal(3);
+      expect(
+        (await provider.connection.getBalance(feeVaultPda)) - initialFeeVaultBalance
+      ).to.equal(DEFAULT_PROTOCOL_FEE_LAMPORTS);
+
+      const universalTxEvent = (await getTxEvents(provider.connection, program, tx))
+        .find((e) => e?.name === "universalTx");
+      expect(universalTxEvent, "UniversalTx event not found").to.exist;
+      expect(universalTxEvent.data.txType).to.deep.equal({ gas: {} });
+
+      const gasAmount = universalTxEvent.data.amount.toNumber();
+      expect(gasAmount).to.be.at.least(minOut.toNumber());
+      expect((await provider.connection.getBalance(vaultPda)) - initialVaultBalance).to.equal(gasAmount);
+    });
+
+    it("Should reject a swap below min_amount_out (SlippageExceeded)", async () => {
+      const amountIn = new anchor.BN(3 * 10 ** mockUSDT.config.decimals);
+      // More SOL than 3 USDT can buy from the pool.
+      const minOut = new anchor.BN(Math.floor(calculateSolAmount(5, solPrice)));
+
+      try {
+        await program.methods
+          .sendUniversalTxSwapGas(gasReq(), amountIn, minOut, withProtocolFee(0))
+          .accountsPartial(swapGasAccounts())
+          .remainingAccounts(adapterRemainingAccounts(adapterProgram.programId))
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected a swap below min_amount_out");
+      } catch (error: any) {
+        // Both the adapter and the gateway enforce min_amount_out with the same error name.
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("SlippageExceeded");
+      }
+    });
+
+    it("Should reject an adapter program that is not allowlisted (InvalidProgram)", async () => {
+      try {
+        await program.methods
+          .sendUniversalTxSwapGas(
+            gasReq(),
+            new anchor.BN(3 * 10 ** mockUSDT.config.decimals),
+            new anchor.BN(1),
+            withProtocolFee(0)
+          )
+          .accountsPartial(swapGasAccounts())
+          .remainingAccounts(adapterRemainingAccounts(spl.TOKEN_PROGRAM_ID))
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected a non-allowlisted adapter");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidProgram");
+      }
+    });
+  });
+
+  describe("Gas in stablecoins (send_universal_tx_gas_token)", () => {
+    let gasTokenPda: PublicKey;
+    let user1UsdcAccount: PublicKey;
+    let gatewayUsdcAccount: PublicKey;
+    let prevMinCap: anchor.BN;
+    let prevMaxCap: anchor.BN;
+
+    before(async () => {
+      const mint = mockUSDC.mint.publicKey;
+      [gasTokenPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("gas_token"), mint.toBuffer()],
+        program.programId
+      );
+      user1UsdcAccount = await mockUSDC.createTokenAccount(user1.publicKey);
+      await mockUSDC.mintTo(user1UsdcAccount, 100);
+      gatewayUsdcAccount = await mockUSDC.createTokenAccount(vaultPda, true);
+
+      // Pin caps to $1 / $10; restored in `after`.
+      const configBefore = await program.account.config.fetch(configPda);
+      prevMinCap = configBefore.minCapUniversalTxUsd;
+      prevMaxCap = configBefore.maxCapUniversalTxUsd;
+      await program.methods
+        .setCapsUsd(new anchor.BN(100_000_000), new anchor.BN(1_000_000_000))
+        .accountsPartial({ admin: admin.publicKey, config: configPda })
+        .signers([admin])
+        .rpc();
+
+      // USDC pegged at $1.00 (8 decimals).
+      await program.methods
+        .setGasToken(new anchor.BN(100_000_000), PublicKey.default, Array(32).fill(0), true)
+        .accountsPartial({
+          config: configPda,
+          gasToken: gasTokenPda,
+          tokenMint: mint,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setCapsUsd(prevMinCap, prevMaxCap)
+        .accountsPartial({ admin: admin.publicKey, config: configPda })
+        .signers([admin])
+        .rpc();
+    });
+
+    const gasTokenReq = (token: PublicKey, wholeTokens: number) => ({
+      recipient: Array.from(Buffer.alloc(20, 0)),
+      token,
+      amount: new anchor.BN(wholeTokens * 10 ** mockUSDC.config.decimals),
+      payload: Buffer.from([]),
+      revertRecipient: user1.publicKey,
+      signatureData: Buffer.from([]),
+    });
+
+    const gasTokenAccounts = () => ({
+      config: configPda,
+      vault: vaultPda,
+      feeVault: feeVaultPda,
+      userTokenAccount: user1UsdcAccount,
+      gatewayTokenAccount: gatewayUsdcAccount,
+      user: user1.publicKey,
+      solOracle: solOraclePda,
+      priceUpdate: mockPriceFeed,
+      rateLimitConfig: rateLimitConfigPda,
+      tokenRateLimit: getTokenRateLimitPda(mockUSDC.mint.publicKey),
+      tokenProgram: spl.TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      gasToken: gasTokenPda,
+      gasTokenPriceUpdate: null,
+    });
+
+    it("Should lock pegged USDC in the vault ATA and emit a Gas UniversalTx with the token set", async () => {
+      const initialGatewayBalance = await mockUSDC.getBalance(gatewayUsdcAccount);
+      const initialVaultBalance = await provider.connection.getBalance(vaultPda);
+
+      const tx = await program.methods
+        .sendUniversalTxGasToken(gasTokenReq(mockUSDC.mint.publicKey, 3), withProtocolFee(0))
+        .accountsPartial(gasTokenAccounts())
+        .signers([user1])
+        .rpc({ commitment: "confirmed" });
+
+      expect((await mockUSDC.getBalance(gatewayUsdcAccount)) - initialGatewayBalance).to.equal(3);
+      expect(await provider.connection.getBalance(vaultPda)).to.equal(initialVaultBalance);
+
+      const universalTxEvent = (await getTxEvents(provider.connection, program, tx))
+        .find((e) => e?.name === "universalTx");
+      expect(universalTxEvent, "UniversalTx event not found").to.exist;
+      expect(universalTxEvent.data.txType).to.deep.equal({ gas: {} });
+      expect(universalTxEvent.data.token.toString()).to.equal(mockUSDC.mint.publicKey.toString());
+      expect(universalTxEvent.data.amount.toString()).to.equal(
+        (3 * 10 ** mockUSDC.config.decimals).toString()
+      );
+    });
+
+    it("Should apply the USD max cap to token gas (AboveMaxCap)", async () => {
+      try {
+        await program.methods
+          .sendUniversalTxGasToken(gasTokenReq(mockUSDC.mint.publicKey, 25), withProtocolFee(0))
+          .accountsPartial(gasTokenAccounts())
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected $25 of token gas");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("AboveMaxCap");
+      }
+    });
+
+    it("Should reject a token that does not match the gas token entry (NotSupported)", async () => {
+      try {
+        await program.methods
+          .sendUniversalTxGasToken(gasTokenReq(mockUSDT.mint.publicKey, 3), withProtocolFee(0))
+          .accountsPartial(gasTokenAccounts())
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected a non-allowlisted gas token");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("NotSupported");
+      }
+    });
+  });
+
+  describe("Per-token FUNDS USD caps (oracle registry)", () => {
+    const setNativeUsdCaps = async (minUsd: number, maxUsd: number) => {
+      await program.methods
+        .setTokenUsdCaps(new anchor.BN(minUsd), new anchor.BN(maxUsd))
+        .accountsPartial({
+          admin: admin.publicKey,
+          config: configPda,
+          tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+          tokenMint: PublicKey.default,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    };
+
+    before(async () => {
+      // $1 min / $100 max per native SOL FUNDS deposit.
+      await setNativeUsdCaps(100_000_000, 10_000_000_000);
+    });
+
+    after(async () => {
+      await setNativeUsdCaps(0, 0);
+    });
+
+    const nativeFundsReq = (lamports: number) => ({
+      recipient: Array.from(Buffer.alloc(20, 0)),
+      token: PublicKey.default,
+      amount: new anchor.BN(lamports),
+      payload: Buffer.from([]),
+      revertRecipient: user1.publicKey,
+      signatureData: Buffer.from([]),
+    });
+
+    const nativeFundsAccounts = (withOracle: boolean) => ({
+      config: configPda,
+      vault: vaultPda,
+      feeVault: feeVaultPda,
+      userTokenAccount: null,
+      gatewayTokenAccount: null,
+      user: user1.publicKey,
+      solOracle: solOraclePda,
+      priceUpdate: mockPriceFeed,
+      rateLimitConfig: rateLimitConfigPda,
+      tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+      tokenProgram: spl.TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      oracle: withOracle ? solOraclePda : null,
+      oraclePriceUpdate: withOracle ? mockPriceFeed : null,
+    });
+
+    it("Should accept a FUNDS deposit within the token's USD bounds", async () => {
+      const lamports = calculateSolAmount(50, solPrice);
+      const initialVaultBalance = await provider.connection.getBalance(vaultPda);
+
+      await program.methods
+        .sendUniversalTx(nativeFundsReq(lamports), withProtocolFee(lamports))
+        .accountsPartial(nativeFundsAccounts(true))
+        .signers([user1])
+        .rpc();
+
+      expect((await provider.connection.getBalance(vaultPda)) - initialVaultBalance).to.equal(lamports);
+    });
+
+    it("Should reject a FUNDS deposit above the token's USD max (AboveMaxCap)", async () => {
+      const lamports = calculateSolAmount(150, solPrice);
+      try {
+        await program.methods
+          .sendUniversalTx(nativeFundsReq(lamports), withProtocolFee(lamports))
+          .accountsPartial(nativeFundsAccounts(true))
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected a deposit above the USD max");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("AboveMaxCap");
+      }
+    });
+
+    it("Should reject a capped FUNDS deposit without the oracle accounts (InvalidAccount)", async () => {
+      const lamports = calculateSolAmount(50, solPrice);
+      try {
+        await program.methods
+          .sendUniversalTx(nativeFundsReq(lamports), withProtocolFee(lamports))
+          .accountsPartial(nativeFundsAccounts(false))
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have required the oracle accounts");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidAccount");
+      }
+    });
+  });
+
+  describe("SOL/USD price sources (fallback and median)", () => {
+    let priceSourcesPda: PublicKey;
+
+    const setPriceSources = async (secondaryFeeds: PublicKey[], enabled: boolean) => {
+      await program.methods
+        .setPriceSources(secondaryFeeds, { firstFresh: {} }, enabled)
+        .accountsPartial({
+          config: configPda,
+          priceSources: priceSourcesPda,
+          solOracle: solOraclePda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    };
+
+    const setFallbackPrice = async (priceUsd: number, expiresAt: number) => {
+      await program.methods
+        .setFallbackPrice(new anchor.BN(priceUsd), new anchor.BN(expiresAt))
+        .accountsPartial({
+          config: configPda,
+          priceSources: priceSourcesPda,
+          solOracle: solOraclePda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    };
+
+    const sendSourcedGas = (withSources: boolean, withPrimary = true) =>
+      sendGas({
+        user: user1,
+        nativeAmount: withProtocolFee(calculateSolAmount(2.5, solPrice)),
+        priceUpdate: withPrimary ? mockPriceFeed : null,
+        priceSources: withSources ? priceSourcesPda : null,
+      });
+
+    before(async () => {
+      [priceSourcesPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("price_sources")],
+        program.programId
+      );
+    });
+
+    after(async () => {
+      await setFallbackPrice(0, 0);
+      await setPriceSources([PublicKey.default, PublicKey.default], false);
+    });
+
+    it("Should reject the primary feed as a secondary source", async () => {
+      try {
+        await setPriceSources([mockPriceFeed, PublicKey.default], true);
+        expect.fail("Should have rejected the primary feed as a secondary");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidInput");
+      }
+    });
+
+    it("Should require the PriceSources account on the GAS route once enabled", async () => {
+      await setPriceSources([PublicKey.default, PublicKey.default], true);
+
+      try {
+        await sendSourcedGas(false);
+        expect.fail("Should have required the PriceSources account");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidAccount");
+      }
+
+      // Fresh primary feed is used first.
+      await sendSourcedGas(true);
+    });
+
+    it("Should require the primary feed even while an admin fallback price is set", async () => {
+      await setPriceSources([PublicKey.default, PublicKey.default], true);
+      const now = Math.floor(Date.now() / 1000);
+      await setFallbackPrice(15_025_000_000, now + 600);
+
+      try {
+        // Dropping the fresh primary must not select the fallback price.
+        await sendSourcedGas(true, false);
+        expect.fail("Should have required the primary price account");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidAccount");
+      } finally {
+        await setFallbackPrice(0, 0);
+      }
+    });
+
+    it("Should fall back to the admin price while the primary feed is stale", async () => {
+      await setPriceSources([PublicKey.default, PublicKey.default], true);
+      // The mock feed is published once during setup, so a 1 second max age makes it stale.
+      await program.methods
+        .setPythPricePolicy(new anchor.BN(1), 0)
+        .accountsPartial({ admin: admin.publicKey, config: configPda })
+        .signers([admin])
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      try {
+        try {
+          await sendSourcedGas(true);
+          expect.fail("Should have rejected with StalePrice");
+        } catch (error: any) {
+          const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+          expect(errorCode).to.equal("StalePrice");
+        }
+
+        const now = Math.floor(Date.now() / 1000);
+        // $150.25 per SOL in 8 decimals
+        await setFallbackPrice(15_025_000_000, now + 600);
+        const sources = await program.account.priceSources.fetch(priceSourcesPda);
+        expect(sources.fallbackPriceUsd.toString()).to.equal("15025000000");
+
+        await sendSourcedGas(true);
+      } finally {
+        await program.methods
+          .setPythPricePolicy(new anchor.BN(0), 0)
+          .accountsPartial({ admin: admin.publicKey, config: configPda })
+          .signers([admin])
+          .rpc();
+      }
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject when paused", async () => {
+      await program.methods
+        .pause()
+        .accountsPartial({ pauser: pauser.publicKey, config: configPda })
+        .signers([pauser])
+        .rpc();
+
+      const gasAmount = calculateSolAmount(2.5, solPrice);
+      const nativeSolTokenRateLimitPda = getTokenRateLimitPda(
+        PublicKey.default
+      );
+
+      const req = {
+        recipient: Array.from(Buffer.alloc(20, 0)),
+        token: PublicKey.default,
+        amount: new anchor.BN(0),
+        payload: Buffer.from([]),
+        revertRecipient: user1.publicKey,
+        signatureData: Buffer.from("sig"),
+      };
+
+      try {
+        await program.methods
+          .sendUniversalTx(req, new anchor.BN(gasAmount))
+          .accountsPartial({
+            config: configPda,
+            vault: vaultPda,
+            feeVault: feeVaultPda,
+            userTokenAccount: null, 
+            gatewayTokenAccount: null, 
+            user: user1.publicKey,
+            solOracle: solOraclePda,
+            priceUpdate: mockPriceFeed,
+            rateLimitConfig: rateLimitConfigPda,
+            tokenRateLimit: nativeSolTokenRateLimitPda,
+            tokenProgram: spl.TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        expect.fail("Should reject when paused");
+      } catch (error: any) {
+        expect(error).to.exist;
+        expect(error.error?.errorCode?.code || error.code).to.equal("Paused");
+      }
+
+      await program.methods
+        .unpause()
+        .accountsPartial({ pauser: pauser.publicKey, config: configPda })
+        .signers([pauser])
+        .rpc();
+    });
+
+    it("Should reject GAS route amounts outside USD cap bounds", async () => {
+      // Snapshot current caps — other test files may leave config at different values.
+      // We pin to known bounds, test, then restore the exact prior state in `finally`.
+      const configBefore = await program.account.config.fetch(configPda);
+      const prevMin = configBefore.minCapUniversalTxUsd;
+      const prevMax = configBefore.maxCapUniversalTxUsd;
+
+      await program.methods
+        .setCapsUsd(new anchor.BN(100_000_000), new anchor.BN(1_000_000_000)) // $1 min / $10 max
+        .accountsPartial({ admin: admin.publicKey, config: configPda })
+        .signers([admin])
+        .rpc();
+
+      const nativeSolTokenRateLimitPda = getTokenRateLimitPda(PublicKey.default);
+      const cases = [
+        { label: "BelowMinCap", usd: 0.5,  expectedError: "BelowMinCap" },  // $0.50 < $1 min
+        { label: "AboveMaxCap", usd: 15.0, expectedError: "AboveMaxCap" },  // $15 > $10 max
+      ];
+
+      try {
+        for (const { label, usd, expectedError } of cases) {
+          const gasAmount = calculateSolAmount(usd, solPrice);
+          const req = {
+            recipient: Array.from(Buffer.alloc(20, 0)),
+            token: PublicKey.default,
+            amount: new anchor.BN(0),
+            payload: Buffer.from([]),
+            revertRecipient: user1.publicKey,
+            signatureData: Buffer.from(label),
+          };
+          try {
+            await program.methods
+              .sendUniversalTx(req, withProtocolFee(gasAmount))
+              .accountsPartial({
+                config: configPda,
+                vault: vaultPda,
+                feeVault: feeVaultPda,
+                userTokenAccount: null,
+                gatewayTokenAccount: null,
+                user: user1.publicKey,
+                solOracle: solOraclePda,
+                priceUpdate: mockPriceFeed,
+                rateLimitConfig: rateLimitConfigPda,
+                tokenRateLimit: nativeSolTokenRateLimitPda,
+                tokenProgram: spl.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+              })
+              .signers([user1])
+              .rpc();
+            expect.fail(`${label}: Should have rejected with ${expectedError}`);
+          } catch (error: any) {
+            const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+            expect(errorCode).to.equal(expectedError, `${label}: wrong error code`);
+          }
+        }
+      } finally {
+        await program.methods
+          .setCapsUsd(prevMin, prevMax)
+          .accountsPartial({ admin: admin.publicKey, config: configPda })
+          .signers([admin])
+          .rpc();
+      }
+    });
+
+    it("Should reject invalid parameter combinations (no gas or funds)", async () => {
+      const nativeSolTokenRateLimitPda = getTokenRateLimitPda(
+        PublicKey.default
+      );
+
+      const req = {
+        recipient: Array.from(Buffer.alloc(20, 0)),
+        token: PublicKey.default,
+        amount: new anchor.BN(0),
+        payload: Buffer.from([]),
+        revertRecipient: user1.publicKey,
+        signatureData: Buffer.from("sig"),
+      };
+
+      try {
+        await program.methods
+          .sendUniversalTx(req, withProtocolFee(0))
+          .accountsPartial({
+            config: configPda,
+            vault: vaultPda,
+            feeVault: feeVaultPda,
+            userTokenAccount: null,
+            gatewayTokenAccount: null,
+            user: user1.publicKey,
+            solOracle: solOraclePda,
+            priceUpdate: mockPriceFeed,
+            rateLimitConfig: rateLimitConfigPda,
+            tokenRateLimit: nativeSolTokenRateLimitPda,
+            tokenProgram: spl.TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        expect.fail("Should reject parameter set without gas or funds");
+      } catch (error: any) {
+        expect(error).to.exist;
+        const errorCode =
+          error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidInput");
       }
     });
   });
@@ -1118,6 +2715,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
         userTokenAccount: null,
         gatewayTokenAccount: null,
         user: user1.publicKey,
+        solOracle: solOraclePda,
         priceUpdate: mockPriceFeed,
         rateLimitConfig: rateLimitConfigPda,
         tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -1182,6 +2780,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
             userTokenAccount: null,
             gatewayTokenAccount: null,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
             tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -1199,9 +2798,276 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
       }
     });
 
-    it("Should reject GAS route when oracle confidence exceeds threshold (InvalidPrice)", async () => {
+    it("Should charge a USD-priced protocol fee converted at the SOL/USD price, within the lamport clamps", async () => {
+      const setProtocolFeeUsd = async (feeUsd: number, minLamports: number, maxLamports: number) => {
+        await program.methods
+          .setProtocolFeeUsd(new anchor.BN(feeUsd), new anchor.BN(minLamports), new anchor.BN(maxLamports))
+          .accountsPartial({
+            config: configPda,
+            feeVault: feeVaultPda,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      };
+
+      try {
+        await setProtocolFeeUsd(50_000_000, 2_000_000, 1_000_000);
+        expect.fail("Should have rejected min > max");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidInput");
+      }
+
+      const gasAmount = calculateSolAmount(2.5, solPrice);
+      const sendGasWithFee = (feeLamports: number) =>
+        sendGas({
+          user: user1,
+          nativeAmount: new anchor.BN(gasAmount + feeLamports),
+          commitment: "confirmed",
+        });
+
+      try {
+        // $0.50 at $150.25/SOL = 3_327_787.02 lamports, rounded up.
+        const usdFeeLamports = 3_327_788;
+        await setProtocolFeeUsd(50_000_000, 0, 0);
+        let feeVaultBefore = await provider.connection.getBalance(feeVaultPda);
+        const tx = await sendGasWithFee(usdFeeLamports);
+        let feeVaultAfter = await provider.connection.getBalance(feeVaultPda);
+        expect(feeVaultAfter - feeVaultBefore).to.equal(usdFeeLamports);
+
+        const feeEvent = (await getTxEvents(provider.connection, program, tx))
+          .find((e) => e?.name === "protocolFeeCollected");
+        expect(feeEvent, "ProtocolFeeCollected event not found").to.exist;
+        expect(feeEvent.data.amountLamports.toNumber()).to.equal(usdFeeLamports);
+        // 3_327_788 lamports * $150.25 = $0.50000014, truncated to 8 decimals
+        expect(feeEvent.data.amountUsd.toString()).to.equal("50000014");
+
+        // Upper clamp wins over the converted amount.
+        await setProtocolFeeUsd(50_000_000, 0, 1_000_000);
+        feeVaultBefore = await provider.connection.getBalance(feeVaultPda);
+        await sendGasWithFee(1_000_000);
+        feeVaultAfter = await provider.connection.getBalance(feeVaultPda);
+        expect(feeVaultAfter - feeVaultBefore).to.equal(1_000_000);
+
+        // A FUNDS deposit without a price account pays the lamport ceiling.
+        const fundsAmount = 0.1 * LAMPORTS_PER_SOL;
+        const sendFundsWithoutPrice = async (feeLamports: number) =>
+          program.methods
+            .sendUniversalTx(
+              {
+                recipient: Array.from(Buffer.alloc(20, 1)),
+                token: PublicKey.default,
+                amount: new anchor.BN(fundsAmount),
+                payload: Buffer.from([]),
+                revertRecipient: user1.publicKey,
+                signatureData: Buffer.from([]),
+              },
+              new anchor.BN(fundsAmount + feeLamports)
+            )
+            .accountsPartial({
+              config: configPda,
+              vault: vaultPda,
+              feeVault: feeVaultPda,
+              userTokenAccount: null,
+              gatewayTokenAccount: null,
+              user: user1.publicKey,
+              priceUpdate: null,
+              rateLimitConfig: rateLimitConfigPda,
+              tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+              tokenProgram: spl.TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([user1])
+            .rpc();
+
+        await setProtocolFeeUsd(50_000_000, 400_000, 1_000_000);
+        feeVaultBefore = await provider.connection.getBalance(feeVaultPda);
+        await sendFundsWithoutPrice(1_000_000);
+        feeVaultAfter = await provider.connection.getBalance(feeVaultPda);
+        expect(feeVaultAfter - feeVaultBefore).to.equal(1_000_000);
+
+        // Quoting the floor instead of the ceiling is rejected.
+        try {
+          await sendFundsWithoutPrice(400_000);
+          expect.fail("Should have charged the ceiling without a price account");
+        } catch (error: any) {
+          const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+          expect(errorCode).to.equal("InvalidAmount");
+        }
+
+        // With no ceiling, the price account is required.
+        await setProtocolFeeUsd(50_000_000, 400_000, 0);
+        try {
+          await sendFundsWithoutPrice(400_000);
+          expect.fail("Should have required the price account");
+        } catch (error: any) {
+          const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+          expect(errorCode).to.equal("InvalidAccount");
+        }
+      } finally {
+        await setProtocolFeeUsd(0, 0, 0);
+      }
+    });
+
+    it("Should charge per-route fees and waive them for an exempt sender", async () => {
+      const setRouteFees = async (enabled: boolean, gas: number, funds: number, payload: number) => {
+        await program.methods
+          .setRouteFees(enabled, new anchor.BN(gas), new anchor.BN(funds), new anchor.BN(payload))
+          .accountsPartial({
+            config: configPda,
+            feeVault: feeVaultPda,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      };
+      const [feeExemptionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_exemption"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      const setFeeExemption = async (exempt: boolean) => {
+        await program.methods
+          .setFeeExemption(exempt)
+          .accountsPartial({
+            config: configPda,
+            feeExemption: feeExemptionPda,
+            sender: user1.publicKey,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      };
+
+      const gasAmount = calculateSolAmount(2.5, solPrice);
+      const sendGasWithFee = (payload: Buffer, feeLamports: number, feeExemption: PublicKey | null = null) =>
+        sendGas({ user: user1, nativeAmount: new anchor.BN(gasAmount + feeLamports), payload, feeExemption });
+      const feeVaultDelta = async (send: () => Promise<string>) => {
+        const before = await provider.connection.getBalance(feeVaultPda);
+        await send();
+        return (await provider.connection.getBalance(feeVaultPda)) - before;
+      };
+
+      try {
+        await setRouteFees(true, 20_000, 80_000, 30_000);
+
+        // GAS: gas fee only; GAS_AND_PAYLOAD: gas fee plus payload fee.
+        expect(await feeVaultDelta(() => sendGasWithFee(Buffer.from([]), 20_000))).to.equal(20_000);
+        expect(await feeVaultDelta(() => sendGasWithFee(Buffer.from([1, 2, 3]), 50_000))).to.equal(50_000);
+
+        // An allowlisted sender pays no protocol fee.
+        await setFeeExemption(true);
+        expect(await feeVaultDelta(() => sendGasWithFee(Buffer.from([]), 0, feeExemptionPda))).to.equal(0);
+
+        // A revoked exemption charges the route fee again.
+        await setFeeExemption(false);
+        expect(await feeVaultDelta(() => sendGasWithFee(Buffer.from([]), 20_000, feeExemptionPda))).to.equal(20_000);
+      } finally {
+        await setRouteFees(false, 0, 0, 0);
+      }
+    });
+
+    it("Should let the admin withdraw lamport and SPL protocol fees to a treasury", async () => {
+      const treasury = Keypair.generate();
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: user1.publicKey,
+            toPubkey: treasury.publicKey,
+            lamports: LAMPORTS_PER_SOL,
+          })
+        ),
+        [user1]
+      );
+      const feeVaultUsdt = await mockUSDT.createTokenAccount(feeVaultPda, true);
+      const treasuryUsdt = await mockUSDT.createTokenAccount(treasury.publicKey);
+      // Stands in for collected bps fees.
+      await mockUSDT.mintTo(feeVaultUsdt, 5);
+
+      const withdrawFees = (amount: anchor.BN, tokenAccounts: PublicKey[] | null, signer = admin) =>
+        program.methods
+          .withdrawProtocolFees(amount)
+          .accountsPartial({
+            config: configPda,
+            feeVault: feeVaultPda,
+            admin: signer.publicKey,
+            recipient: treasury.publicKey,
+            feeTokenAccount: tokenAccounts ? tokenAccounts[0] : null,
+            recipientTokenAccount: tokenAccounts ? tokenAccounts[1] : null,
+            tokenProgram: tokenAccounts ? spl.TOKEN_PROGRAM_ID : null,
+          })
+          .signers([signer])
+          .rpc();
+      const expectError = async (promise: Promise<any>, code: string) => {
+        try {
+          await promise;
+          expect.fail(`Should have failed with ${code}`);
+        } catch (error: any) {
+          const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+          expect(errorCode).to.equal(code);
+        }
+      };
+
+      // Lamports: at most the balance above the fee vault's rent-exempt minimum.
+      await withdrawFees(new anchor.BN(DEFAULT_PROTOCOL_FEE_LAMPORTS), null);
+      expect(await provider.connection.getBalance(treasury.publicKey)).to.equal(
+        LAMPORTS_PER_SOL + DEFAULT_PROTOCOL_FEE_LAMPORTS
+      );
+      const feeVaultInfo = await provider.connection.getAccountInfo(feeVaultPda);
+      const rentExempt = await provider.connection.getMinimumBalanceForRentExemption(
+        feeVaultInfo!.data.length
+      );
+      await expectError(
+        withdrawFees(new anchor.BN(feeVaultInfo!.lamports - rentExempt + 1), null),
+        "InsufficientFeePool"
+      );
+
+      // SPL: from the fee vault's token account to the treasury's.
+      const feeVaultUsdtBefore = await mockUSDT.getBalance(feeVaultUsdt);
+      await withdrawFees(new anchor.BN(5 * 10 ** mockUSDT.config.decimals), [
+        feeVaultUsdt,
+        treasuryUsdt,
+      ]);
+      expect(await mockUSDT.getBalance(treasuryUsdt)).to.equal(5);
+      expect(feeVaultUsdtBefore - (await mockUSDT.getBalance(feeVaultUsdt))).to.equal(5);
+
+      await expectError(withdrawFees(new anchor.BN(1), null, user1), "Unauthorized");
+    });
+
+    it("Should reject invalid setTokenFee parameters", async () => {
+      const setNativeTokenFee = (fundsFeeBps: number) =>
+        program.methods
+          .setTokenFee(false, new anchor.BN(0), fundsFeeBps)
+          .accountsPartial({
+            config: configPda,
+            tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+            tokenMint: PublicKey.default,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+      for (const [fundsFeeBps, expected] of [
+        [10_000, "InvalidInput"],
+        [25, "InvalidMint"],
+      ] as const) {
+        try {
+          await setNativeTokenFee(fundsFeeBps);
+          expect.fail(`setTokenFee(${fundsFeeBps} bps) on native SOL should have failed`);
+        } catch (error: any) {
+          const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+          expect(errorCode).to.equal(expected);
+        }
+      }
+    });
+
+    it("Should reject GAS route when oracle confidence exceeds threshold (LowPriceConfidence)", async () => {
       // Set a very tight confidence threshold (1 lamport) so the real mock feed's
-      // confidence value exceeds it, triggering InvalidPrice on the next deposit.
+      // confidence value exceeds it, triggering LowPriceConfidence on the next deposit.
       const nativeSolTokenRateLimitPda = getTokenRateLimitPda(PublicKey.default);
       const configBefore = await program.account.config.fetch(configPda);
       const prevThreshold = configBefore.pythConfidenceThreshold;
@@ -1236,6 +3102,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
             userTokenAccount: null,
             gatewayTokenAccount: null,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
             tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -1244,10 +3111,10 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
           })
           .signers([user1])
           .rpc();
-        expect.fail("Should have rejected with InvalidPrice");
+        expect.fail("Should have rejected with LowPriceConfidence");
       } catch (error: any) {
         const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
-        expect(errorCode).to.equal("InvalidPrice");
+        expect(errorCode).to.equal("LowPriceConfidence");
       } finally {
         // Setter rejects 0; fallback keeps restore valid on legacy-zero configs.
         await program.methods
@@ -1258,6 +3125,166 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
       }
     });
 
+    it("Should reject GAS route when the price is older than the configured max age (StalePrice)", async () => {
+      // The mock feed is published once during setup, so a 1 second max age makes it stale.
+      await program.methods
+        .setPythPricePolicy(new anchor.BN(1), 0)
+        .accountsPartial({ admin: admin.publicKey, config: configPda })
+        .signers([admin])
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      const gasAmount = calculateSolAmount(2.5, solPrice);
+      try {
+        await program.methods
+          .sendUniversalTx(
+            {
+              recipient: Array.from(Buffer.alloc(20, 0)),
+              token: PublicKey.default,
+              amount: new anchor.BN(0),
+              payload: Buffer.from([]),
+              revertRecipient: user1.publicKey,
+              signatureData: Buffer.from([]),
+            },
+            withProtocolFee(gasAmount)
+          )
+          .accountsPartial({
+            config: configPda,
+            vault: vaultPda,
+            feeVault: feeVaultPda,
+            userTokenAccount: null,
+            gatewayTokenAccount: null,
+            user: user1.publicKey,
+            solOracle: solOraclePda,
+            priceUpdate: mockPriceFeed,
+            rateLimitConfig: rateLimitConfigPda,
+            tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+            tokenProgram: spl.TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        expect.fail("Should have rejected with StalePrice");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("StalePrice");
+      } finally {
+        await program.methods
+          .setPythPricePolicy(new anchor.BN(0), 0)
+          .accountsPartial({ admin: admin.publicKey, config: configPda })
+          .signers([admin])
+          .rpc();
+      }
+    });
+
+    it("Should enforce the relative (bps) confidence bound independently of the price exponent", async () => {
+      try {
+        await program.methods
+          .setPythPriceMode(10_001, false)
+          .accountsPartial({ admin: admin.publicKey, config: configPda })
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have rejected a bound above 100%");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidInput");
+      }
+
+      // The mock feed's confidence is a tiny fraction of its price, so a 1 bps bound still passes.
+      await program.methods
+        .setPythPriceMode(1, false)
+        .accountsPartial({ admin: admin.publicKey, config: configPda })
+        .signers([admin])
+        .rpc();
+      const config = await program.account.config.fetch(configPda);
+      expect(config.pythConfidenceBps).to.equal(1);
+      expect(config.pythUseEma).to.be.false;
+
+      const gasAmount = calculateSolAmount(2.5, solPrice);
+      try {
+        await program.methods
+          .sendUniversalTx(
+            {
+              recipient: Array.from(Buffer.alloc(20, 0)),
+              token: PublicKey.default,
+              amount: new anchor.BN(0),
+              payload: Buffer.from([]),
+              revertRecipient: user1.publicKey,
+              signatureData: Buffer.from([]),
+            },
+            withProtocolFee(gasAmount)
+          )
+          .accountsPartial({
+            config: configPda,
+            vault: vaultPda,
+            feeVault: feeVaultPda,
+            userTokenAccount: null,
+            gatewayTokenAccount: null,
+            user: user1.publicKey,
+            solOracle: solOraclePda,
+            priceUpdate: mockPriceFeed,
+            rateLimitConfig: rateLimitConfigPda,
+            tokenRateLimit: getTokenRateLimitPda(PublicKey.default),
+            tokenProgram: spl.TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+      } finally {
+        await program.methods
+          .setPythPriceMode(0, false)
+          .accountsPartial({ admin: admin.publicKey, config: configPda })
+          .signers([admin])
+          .rpc();
+      }
+    });
+
+    it("Should record the accepted SOL/USD price when the deviation guard is enabled", async () => {
+      try {
+        await program.methods
+          .setPriceDeviationGuard(500, new anchor.BN(0))
+          .accountsPartial({ admin: admin.publicKey, config: configPda })
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have rejected an enabled guard without a window");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.error?.errorCode || error.code;
+        expect(errorCode).to.equal("InvalidInput");
+      }
+
+      await program.methods
+        .setPriceDeviationGuard(500, new anchor.BN(300))
+        .accountsPartial({ admin: admin.publicKey, config: configPda })
+        .signers([admin])
+        .rpc();
+
+      const sendDefaultGas = () =>
+        sendGas({ user: user1, nativeAmount: withProtocolFee(calculateSolAmount(2.5, solPrice)) });
+
+      try {
+        await sendDefaultGas();
+        let config = await program.account.config.fetch(configPda);
+        // $150.25 per SOL in 8 decimals
+        expect(config.lastSolPriceUsd.toString()).to.equal("15025000000");
+        expect(config.lastSolPublishTime.toNumber()).to.be.greaterThan(0);
+
+        // Same price again is within the 5% bound
+        await sendDefaultGas();
+        config = await program.account.config.fetch(configPda);
+        expect(config.lastSolPriceUsd.toString()).to.equal("15025000000");
+      } finally {
+        await program.methods
+          .setPriceDeviationGuard(0, new anchor.BN(0))
+          .accountsPartial({ admin: admin.publicKey, config: configPda })
+          .signers([admin])
+          .rpc();
+      }
+
+      const config = await program.account.config.fetch(configPda);
+      expect(config.maxPriceDeviationBps).to.equal(0);
+      expect(config.lastSolPriceUsd.toNumber()).to.equal(0);
+    });
+
     it("Should support fee-off mode for legacy SPL FUNDS call shape", async () => {
       const usdtTokenRateLimitPda = getTokenRateLimitPda(
         mockUSDT.mint.publicKey
@@ -1296,6 +3323,7 @@ describe("Universal Gateway - send_universal_tx Tests", () => {
             userTokenAccount: userTokenAccount,
             gatewayTokenAccount: gatewayTokenAccount,
             user: user1.publicKey,
+            solOracle: solOraclePda,
             priceUpdate: mockPriceFeed,
             rateLimitConfig: rateLimitConfigPda,
             tokenRateLimit: usdtTokenRateLimitPda,
@@ -4,15 +4,16 @@ import { UniversalGateway } from "../target/types/universal_gateway";
 import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
 import { expect } from "chai";
 import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import * as spl from "@solana/spl-token";
 import * as sharedState from "./shared-state";
-import { signTssMessage, TssInstruction, generateUniversalTxId, buildWithdrawAdditionalData } from "./helpers/tss";
+import { signTssMessage, TssInstruction, generateUniversalTxId, buildWithdrawAdditionalData, NonceMode, FinalizeFlags } from "./helpers/tss";
 import { ensureTestSetup } from "./helpers/test-setup";
 import {
     USDT_DECIMALS, TOKEN_MULTIPLIER,
     asLamports, asTokenAmount,
     makeTxIdGenerator, generateSender,
     getExecutedTxPda as _getExecutedTxPda, getCeaAuthorityPda as _getCeaAuthorityPda,
-    getTokenRateLimitPda as _getTokenRateLimitPda,
+    getTokenRateLimitPda as _getTokenRateLimitPda, getCeaStatePda as _getCeaStatePda,
 } from "./helpers/test-utils";
 import { makeFinalizeUniversalTxBuilder, FinalizeUniversalTxArgs } from "./helpers/builders";
 
@@ -48,6 +49,7 @@ describe("Universal Gateway - Withdraw Tests", () => {
     let feeVaultPda: PublicKey;
     let tssPda: PublicKey;
     let rateLimitConfigPda: PublicKey;
+    let solOraclePda: PublicKey;
     let mockPriceFeed: PublicKey;
 
     let mockUSDT: any;
@@ -63,6 +65,8 @@ describe("Universal Gateway - Withdraw Tests", () => {
     const getExecutedTxPda = (subTxId: number[]) => _getExecutedTxPda(subTxId, program.programId);
     const getCeaAuthorityPda = (pushAccount: number[]) => _getCeaAuthorityPda(pushAccount, program.programId);
     const getTokenRateLimitPda = (tokenMint: PublicKey) => _getTokenRateLimitPda(tokenMint, program.programId);
+    const getCeaStatePda = (pushAccount: number[], ceaIndex: number = 0) =>
+        _getCeaStatePda(pushAccount, program.programId, ceaIndex);
 
     const signTssMessageWithChainId = async (params: {
         instruction: TssInstruction;
@@ -125,6 +129,9 @@ describe("Universal Gateway - Withdraw Tests", () => {
         [feeVaultPda] = PublicKey.findProgramAddressSync([Buffer.from("fee_vault")], program.programId);
         [tssPda] = PublicKey.findProgramAddressSync([Buffer.from("tsspda_v2")], program.programId);
         [rateLimitConfigPda] = PublicKey.findProgramAddressSync([Buffer.from("rate_limit_config")], program.programId);
+        [solOraclePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("oracle"), PublicKey.default.toBuffer()], program.programId
+        );
 
         // Ensure protocol fee is disabled for deterministic seeding in this suite.
         await program.methods
@@ -203,6 +210,7 @@ describe("Universal Gateway - Withdraw Tests", () => {
                     userTokenAccount: null, 
                     gatewayTokenAccount: null, 
                     user: user1.publicKey,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: nativeSolTokenRateLimitPda,
@@ -268,6 +276,7 @@ describe("Universal Gateway - Withdraw Tests", () => {
                     user: user1.publicKey,
                     userTokenAccount: user1UsdtAccount,
                     gatewayTokenAccount: vaultUsdtAccount,
+                    solOracle: solOraclePda,
                     priceUpdate: mockPriceFeed,
                     rateLimitConfig: rateLimitConfigPda,
                     tokenRateLimit: splTokenRateLimitPda,
@@ -491,6 +500,58 @@ describe("Universal Gateway - Withdraw Tests", () => {
                 "custom program error"
             );
         });
+
+        it("delivers SOL as wSOL when the wrap flag is signed", async () => {
+            const withdrawLamports = anchor.web3.LAMPORTS_PER_SOL / 10;
+
+            // Fresh wallet with no lamports — a plain SOL transfer below rent-exempt minimum would fail
+            const freshRecipient = Keypair.generate();
+            const recipientWsolAta = getAssociatedTokenAddressSync(spl.NATIVE_MINT, freshRecipient.publicKey);
+
+            const subTxId = generateTxId();
+            const universalTxId = generateUniversalTxId();
+            const pushAccount = generatePushAccount();
+
+            const signature = await signTssMessageWithChainId({
+                instruction: TssInstruction.Withdraw,
+                amount: BigInt(withdrawLamports),
+                additional: buildWithdrawAdditionalData(
+                    new Uint8Array(universalTxId),
+                    new Uint8Array(subTxId),
+                    new Uint8Array(pushAccount),
+                    PublicKey.default,           // token stays native in the signed message
+                    freshRecipient.publicKey,
+                    DEFAULT_GAS_FEE,
+                    BigInt(0),
+                    NonceMode.Unordered,
+                    FinalizeFlags.WrapNative
+                ),
+            });
+
+            await finalizeUniversalTx({
+                instructionId: 1,
+                subTxId,
+                universalTxId,
+                amount: new anchor.BN(withdrawLamports),
+                pushAccount,
+                gasFee: new anchor.BN(Number(DEFAULT_GAS_FEE)),
+                flags: FinalizeFlags.WrapNative,
+                sig: signature,
+                caller: relayer.publicKey,
+                recipient: freshRecipient.publicKey,
+                mint: spl.NATIVE_MINT,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                recipientAta: recipientWsolAta,
+            })
+                .signers([relayer])
+                .rpc();
+
+            const wsolAccount = await spl.getAccount(provider.connection, recipientWsolAta);
+            expect(Number(wsolAccount.amount)).to.equal(withdrawLamports);
+            expect(wsolAccount.owner.toBase58()).to.equal(freshRecipient.publicKey.toBase58());
+        });
     });
 
     describe("withdraw SPL tokens", () => {
@@ -643,6 +704,179 @@ describe("Universal Gateway - Withdraw Tests", () => {
                 "TssAuthFailed"
             );
         });
+
+        it("creates the recipient ATA when it does not exist yet", async () => {
+            const withdrawTokens = 10;
+            const withdrawRaw = BigInt(withdrawTokens) * TOKEN_MULTIPLIER;
+
+            const freshRecipient = Keypair.generate();
+            const freshRecipientAta = getAssociatedTokenAddressSync(mockUSDT.mint.publicKey, freshRecipient.publicKey);
+            expect(await provider.connection.getAccountInfo(freshRecipientAta)).to.be.null;
+
+            const subTxId = generateTxId();
+            const universalTxId = generateUniversalTxId();
+            const pushAccount = generatePushAccount();
+            const ceaAta = getAssociatedTokenAddressSync(mockUSDT.mint.publicKey, getCeaAuthorityPda(pushAccount), true);
+
+            const signature = await signTssMessageWithChainId({
+                instruction: TssInstruction.Withdraw,
+                amount: withdrawRaw,
+                additional: buildWithdrawAdditionalData(
+                    new Uint8Array(universalTxId),
+                    new Uint8Array(subTxId),
+                    new Uint8Array(pushAccount),
+                    mockUSDT.mint.publicKey,
+                    freshRecipient.publicKey,
+                    DEFAULT_GAS_FEE
+                ),
+            });
+
+            await finalizeUniversalTx({
+                instructionId: 1,
+                subTxId,
+                universalTxId,
+                amount: new anchor.BN(Number(withdrawRaw)),
+                pushAccount,
+                gasFee: new anchor.BN(Number(DEFAULT_GAS_FEE)),
+                sig: signature,
+                caller: relayer.publicKey,
+                recipient: freshRecipient.publicKey,
+                vaultAta: vaultUsdtAccount,
+                ceaAta,
+                mint: mockUSDT.mint.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                recipientAta: freshRecipientAta,
+            })
+                .signers([relayer])
+                .rpc();
+
+            expect(await mockUSDT.getBalance(freshRecipientAta)).to.equal(withdrawTokens);
+        });
+
+        it("transfers SPL tokens into a signed non-ATA token account", async () => {
+            const withdrawTokens = 10;
+            const withdrawRaw = BigInt(withdrawTokens) * TOKEN_MULTIPLIER;
+
+            // Keypair-addressed token account (not the owner's ATA), e.g. a custodian deposit account
+            const custodyAccount = await spl.createAccount(
+                provider.connection,
+                admin,
+                mockUSDT.mint.publicKey,
+                recipient.publicKey,
+                Keypair.generate()
+            );
+
+            const subTxId = generateTxId();
+            const universalTxId = generateUniversalTxId();
+            const pushAccount = generatePushAccount();
+            const ceaAta = getAssociatedTokenAddressSync(mockUSDT.mint.publicKey, getCeaAuthorityPda(pushAccount), true);
+
+            const signature = await signTssMessageWithChainId({
+                instruction: TssInstruction.Withdraw,
+                amount: withdrawRaw,
+                additional: buildWithdrawAdditionalData(
+                    new Uint8Array(universalTxId),
+                    new Uint8Array(subTxId),
+                    new Uint8Array(pushAccount),
+                    mockUSDT.mint.publicKey,
+                    custodyAccount,              // target = destination token account
+                    DEFAULT_GAS_FEE,
+                    BigInt(0),
+                    NonceMode.Unordered,
+                    FinalizeFlags.TokenAccountRecipient
+                ),
+            });
+
+            await finalizeUniversalTx({
+                instructionId: 1,
+                subTxId,
+                universalTxId,
+                amount: new anchor.BN(Number(withdrawRaw)),
+                pushAccount,
+                gasFee: new anchor.BN(Number(DEFAULT_GAS_FEE)),
+                flags: FinalizeFlags.TokenAccountRecipient,
+                sig: signature,
+                caller: relayer.publicKey,
+                recipient: custodyAccount,
+                vaultAta: vaultUsdtAccount,
+                ceaAta,
+                mint: mockUSDT.mint.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            })
+                .signers([relayer])
+                .rpc();
+
+            expect(await mockUSDT.getBalance(custodyAccount)).to.equal(withdrawTokens);
+        });
+
+        it("rejects a frozen destination token account", async () => {
+            const withdrawRaw = BigInt(1) * TOKEN_MULTIPLIER;
+
+            const frozenAccount = await spl.createAccount(
+                provider.connection,
+                admin,
+                mockUSDT.mint.publicKey,
+                recipient.publicKey,
+                Keypair.generate()
+            );
+            // Mock mints use the mint authority as freeze authority
+            await spl.freezeAccount(
+                provider.connection,
+                admin,
+                frozenAccount,
+                mockUSDT.mint.publicKey,
+                mockUSDT.mintAuthority
+            );
+
+            const subTxId = generateTxId();
+            const universalTxId = generateUniversalTxId();
+            const pushAccount = generatePushAccount();
+            const ceaAta = getAssociatedTokenAddressSync(mockUSDT.mint.publicKey, getCeaAuthorityPda(pushAccount), true);
+
+            const signature = await signTssMessageWithChainId({
+                instruction: TssInstruction.Withdraw,
+                amount: withdrawRaw,
+                additional: buildWithdrawAdditionalData(
+                    new Uint8Array(universalTxId),
+                    new Uint8Array(subTxId),
+                    new Uint8Array(pushAccount),
+                    mockUSDT.mint.publicKey,
+                    frozenAccount,
+                    DEFAULT_GAS_FEE,
+                    BigInt(0),
+                    NonceMode.Unordered,
+                    FinalizeFlags.TokenAccountRecipient
+                ),
+            });
+
+            await expectRejection(
+                finalizeUniversalTx({
+                    instructionId: 1,
+                    subTxId,
+                    universalTxId,
+                    amount: new anchor.BN(Number(withdrawRaw)),
+                    pushAccount,
+                    gasFee: new anchor.BN(Number(DEFAULT_GAS_FEE)),
+                    flags: FinalizeFlags.TokenAccountRecipient,
+                    sig: signature,
+                    caller: relayer.publicKey,
+                    recipient: frozenAccount,
+                    vaultAta: vaultUsdtAccount,
+                    ceaAta,
+                    mint: mockUSDT.mint.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                })
+                    .signers([relayer])
+                    .rpc(),
+                "TokenAccountFrozen"
+            );
+        });
     });
 
     describe("revert withdrawals", () => {
@@ -1665,4 +1899,249 @@ describe("Universal Gateway - Withdraw Tests", () => {
             }
         });
     });
+
+    describe("outbound nonce ordering", () => {
+        const withdrawLamports = anchor.web3.LAMPORTS_PER_SOL / 100;
+
+        const signOrderedWithdraw = async (
+            pushAccount: number[],
+            subTxId: number[],
+            universalTxId: number[],
+            nonce: bigint,
+            nonceMode: NonceMode,
+            ceaIndex: number = 0
+        ) =>
+            signTssMessageWithChainId({
+                instruction: TssInstruction.Withdraw,
+                amount: BigInt(withdrawLamports),
+                additional: buildWithdrawAdditionalData(
+                    new Uint8Array(universalTxId),
+                    new Uint8Array(subTxId),
+                    new Uint8Array(pushAccount),
+                    PublicKey.default,
+                    recipient.publicKey,
+                    DEFAULT_GAS_FEE,
+                    nonce,
+                    nonceMode,
+                    0,
+                    ceaIndex
+                ),
+            });
+
+        const withdrawWithNonce = async (
+            pushAccount: number[],
+            nonce: bigint,
+            nonceMode: NonceMode,
+            ceaIndex: number = 0
+        ) => {
+            const subTxId = generateTxId();
+            const universalTxId = generateUniversalTxId();
+            const sig = await signOrderedWithdraw(pushAccount, subTxId, universalTxId, nonce, nonceMode, ceaIndex);
+            return finalizeUniversalTx({
+                instructionId: 1,
+                subTxId,
+                universalTxId,
+                amount: new anchor.BN(withdrawLamports),
+                pushAccount,
+                gasFee: new anchor.BN(Number(DEFAULT_GAS_FEE)),
+                nonce: new anchor.BN(nonce.toString()),
+                nonceMode,
+                ceaIndex,
+                sig,
+                caller: relayer.publicKey,
+                recipient: recipient.publicKey,
+            })
+                .signers([relayer])
+                .rpc();
+        };
+
+        it("accepts strict nonces in sequence and rejects gaps and replays", async () => {
+            const pushAccount = generatePushAccount();
+
+            await withdrawWithNonce(pushAccount, BigInt(1), NonceMode.Strict);
+            await withdrawWithNonce(pushAccount, BigInt(2), NonceMode.Strict);
+
+            const state = await program.account.ceaState.fetch(getCeaStatePda(pushAccount));
+            expect(state.lastNonce.toNumber()).to.equal(2);
+            expect(Array.from(state.pushAccount)).to.deep.equal(pushAccount);
+
+            await expectRejection(withdrawWithNonce(pushAccount, BigInt(4), NonceMode.Strict), "InvalidNonce");
+            await expectRejection(withdrawWithNonce(pushAccount, BigInt(2), NonceMode.Strict), "InvalidNonce");
+        });
+
+        it("accepts loose nonces with gaps but never going backwards", async () => {
+            const pushAccount = generatePushAccount();
+
+            await withdrawWithNonce(pushAccount, BigInt(5), NonceMode.Loose);
+            await withdrawWithNonce(pushAccount, BigInt(9), NonceMode.Loose);
+            await expectRejection(withdrawWithNonce(pushAccount, BigInt(7), NonceMode.Loose), "InvalidNonce");
+
+            const state = await program.account.ceaState.fetch(getCeaStatePda(pushAccount));
+            expect(state.lastNonce.toNumber()).to.equal(9);
+        });
+
+        it("leaves the nonce untouched for unordered messages", async () => {
+            const pushAccount = generatePushAccount();
+
+            await withdrawWithNonce(pushAccount, BigInt(1), NonceMode.Strict);
+            await withdrawWithNonce(pushAccount, BigInt(0), NonceMode.Unordered);
+            await withdrawWithNonce(pushAccount, BigInt(2), NonceMode.Strict);
+
+            const state = await program.account.ceaState.fetch(getCeaStatePda(pushAccount));
+            expect(state.lastNonce.toNumber()).to.equal(2);
+        });
+
+        it("creates no nonce state for unordered messages and rejects one passed anyway", async () => {
+            const pushAccount = generatePushAccount();
+
+            await withdrawWithNonce(pushAccount, BigInt(0), NonceMode.Unordered);
+            expect(await provider.connection.getAccountInfo(getCeaStatePda(pushAccount))).to.be.null;
+
+            const subTxId = generateTxId();
+            const universalTxId = generateUniversalTxId();
+            const sig = await signOrderedWithdraw(pushAccount, subTxId, universalTxId, BigInt(0), NonceMode.Unordered);
+            await expectRejection(
+                finalizeUniversalTx({
+                    instructionId: 1,
+                    subTxId,
+                    universalTxId,
+                    amount: new anchor.BN(withdrawLamports),
+                    pushAccount,
+                    gasFee: new anchor.BN(Number(DEFAULT_GAS_FEE)),
+                    sig,
+                    caller: relayer.publicKey,
+                    recipient: recipient.publicKey,
+                })
+                    .accountsPartial({ ceaState: getCeaStatePda(pushAccount) })
+                    .signers([relayer])
+                    .rpc(),
+                "InvalidAccount"
+            );
+        });
+
+        it("keeps an independent nonce per cea index", async () => {
+            const pushAccount = generatePushAccount();
+
+            await withdrawWithNonce(pushAccount, BigInt(1), NonceMode.Strict);
+            // Index 1 starts its own sequence; nonce 1 is not a replay of the legacy CEA's
+            await withdrawWithNonce(pushAccount, BigInt(1), NonceMode.Strict, 1);
+            await withdrawWithNonce(pushAccount, BigInt(2), NonceMode.Strict, 1);
+
+            const legacy = await program.account.ceaState.fetch(getCeaStatePda(pushAccount));
+            const indexed = await program.account.ceaState.fetch(getCeaStatePda(pushAccount, 1));
+            expect(legacy.lastNonce.toNumber()).to.equal(1);
+            expect(indexed.lastNonce.toNumber()).to.equal(2);
+            expect(indexed.ceaIndex).to.equal(1);
+        });
+
+        it("rejects a relayer-substituted nonce mode", async () => {
+            const pushAccount = generatePushAccount();
+            const subTxId = generateTxId();
+            const universalTxId = generateUniversalTxId();
+            const sig = await signOrderedWithdraw(pushAccount, subTxId, universalTxId, BigInt(1), NonceMode.Strict);
+
+            await expectRejection(
+                finalizeUniversalTx({
+                    instructionId: 1,
+                    subTxId,
+                    universalTxId,
+                    amount: new anchor.BN(withdrawLamports),
+                    pushAccount,
+                    gasFee: new anchor.BN(Number(DEFAULT_GAS_FEE)),
+                    nonce: new anchor.BN(1),
+                    nonceMode: NonceMode.Unordered,
+                    sig,
+                    caller: relayer.publicKey,
+                    recipient: recipient.publicKey,
+                })
+                    .signers([relayer])
+                    .rpc(),
+                "MessageHashMismatch"
+            );
+        });
+    });
+
+    describe("CEA sub-accounts (cea_index)", () => {
+        const withdrawLamports = anchor.web3.LAMPORTS_PER_SOL / 100;
+
+        const signIndexedWithdraw = (
+            pushAccount: number[],
+            subTxId: number[],
+            universalTxId: number[],
+            target: PublicKey,
+            ceaIndex: number
+        ) =>
+            signTssMessageWithChainId({
+                instruction: TssInstruction.Withdraw,
+                amount: BigInt(withdrawLamports),
+                additional: buildWithdrawAdditionalData(
+                    new Uint8Array(universalTxId),
+                    new Uint8Array(subTxId),
+                    new Uint8Array(pushAccount),
+                    PublicKey.default,
+                    target,
+                    DEFAULT_GAS_FEE,
+                    BigInt(0),
+                    NonceMode.Unordered,
+                    0,
+                    ceaIndex
+                ),
+            });
+
+        it("routes funds through the indexed CEA and leaves the legacy CEA untouched", async () => {
+            const pushAccount = generatePushAccount();
+            const legacyCea = getCeaAuthorityPda(pushAccount);
+            const indexedCea = _getCeaAuthorityPda(pushAccount, program.programId, 1);
+            expect(indexedCea.equals(legacyCea)).to.be.false;
+            expect(_getCeaAuthorityPda(pushAccount, program.programId, 0).equals(legacyCea)).to.be.true;
+
+            const subTxId = generateTxId();
+            const universalTxId = generateUniversalTxId();
+            // recipient == CEA: funds stay in the (indexed) CEA
+            const sig = await signIndexedWithdraw(pushAccount, subTxId, universalTxId, indexedCea, 1);
+
+            await finalizeUniversalTx({
+                instructionId: 1,
+                subTxId,
+                universalTxId,
+                amount: new anchor.BN(withdrawLamports),
+                pushAccount,
+                gasFee: new anchor.BN(Number(DEFAULT_GAS_FEE)),
+                ceaIndex: 1,
+                sig,
+                caller: relayer.publicKey,
+                recipient: indexedCea,
+            })
+                .signers([relayer])
+                .rpc();
+
+            expect(await provider.connection.getBalance(indexedCea)).to.equal(withdrawLamports);
+            expect(await provider.connection.getBalance(legacyCea)).to.equal(0);
+        });
+
+        it("rejects a relayer-substituted cea index", async () => {
+            const pushAccount = generatePushAccount();
+            const subTxId = generateTxId();
+            const universalTxId = generateUniversalTxId();
+            const sig = await signIndexedWithdraw(pushAccount, subTxId, universalTxId, recipient.publicKey, 1);
+
+            await expectRejection(
+                finalizeUniversalTx({
+                    instructionId: 1,
+                    subTxId,
+                    universalTxId,
+                    amount: new anchor.BN(withdrawLamports),
+                    pushAccount,
+                    gasFee: new anchor.BN(Number(DEFAULT_GAS_FEE)),
+                    ceaIndex: 2,
+                    sig,
+                    caller: relayer.publicKey,
+                    recipient: recipient.publicKey,
+                })
+                    .signers([relayer])
+                    .rpc(),
+                "MessageHashMismatch"
+            );
+        });
+    });
 });